@@ -21,6 +21,21 @@ typedef struct iscc_FfiDataHasher iscc_FfiDataHasher;
  */
 typedef struct iscc_FfiInstanceHasher iscc_FfiInstanceHasher;
 
+/**
+ * Opaque FFI wrapper around `iscc_lib::SumHasher`.
+ *
+ * Enforces finalize-once semantics via `Option<Inner>`. Not `#[repr(C)]` —
+ * C callers interact only through function pointers.
+ */
+typedef struct iscc_FfiSumHasher iscc_FfiSumHasher;
+
+/**
+ * Opaque handle to an opened `iscc_lib::IndexFile`.
+ *
+ * Not `#[repr(C)]` — C callers interact only through function pointers.
+ */
+typedef struct iscc_FfiIndexFile iscc_FfiIndexFile;
+
 /**
  * Result of `gen_sum_code_v0` — composite Data+Instance ISCC-CODE with file metadata.
  *
@@ -120,6 +135,46 @@ typedef struct iscc_IsccByteBufferArray {
   uintptr_t count;
 } iscc_IsccByteBufferArray;
 
+/**
+ * One match of an index file query.
+ */
+typedef struct iscc_IsccIndexMatch {
+  /**
+   * External ID the unit was stored under.
+   */
+  uint64_t id;
+  /**
+   * Hamming distance to the query.
+   */
+  uint32_t distance;
+  /**
+   * Number of digest bits compared.
+   */
+  uint32_t bits;
+} iscc_IsccIndexMatch;
+
+/**
+ * Array of index matches sorted by distance, then ID.
+ *
+ * On success, `ok` is `true` and `matches` points to `count` elements
+ * (`NULL` if `count` is 0). On error, `ok` is `false`. Callers must free
+ * with `iscc_free_index_match_array()`.
+ */
+typedef struct iscc_IsccIndexMatchArray {
+  /**
+   * Whether the query succeeded.
+   */
+  bool ok;
+  /**
+   * Pointer to the matches.
+   */
+  struct iscc_IsccIndexMatch *matches;
+  /**
+   * Number of matches.
+   */
+  uintptr_t count;
+} iscc_IsccIndexMatchArray;
+
 /**
  * Maximum byte length for the name field after trimming.
  */
@@ -505,8 +560,8 @@ char *iscc_encode_component(uint8_t mtype,
 /**
  * Generate a composite ISCC-CODE from a file path (Data-Code + Instance-Code).
  *
- * Single-pass file I/O feeds both DataHasher and InstanceHasher, then composes
- * the ISCC-CODE internally.
+ * Single-pass file I/O feeds a SumHasher (DataHasher and InstanceHasher),
+ * then composes the ISCC-CODE internally.
  *
  * # Parameters
  *
@@ -531,13 +586,15 @@ struct iscc_IsccSumCodeResult iscc_gen_sum_code_v0(const char *path,
                                                    bool add_units);
 
 /**
- * Free an `IsccSumCodeResult` previously returned by `iscc_gen_sum_code_v0`.
+ * Free an `IsccSumCodeResult` previously returned by `iscc_gen_sum_code_v0`
+ * or `iscc_sum_hasher_finalize`.
  *
  * Releases the `iscc`, `datahash` strings, and `units` array. No-op for NULL pointers.
  *
  * # Safety
  *
- * `result` must be a value returned by `iscc_gen_sum_code_v0`.
+ * `result` must be a value returned by `iscc_gen_sum_code_v0` or
+ * `iscc_sum_hasher_finalize`.
  * Each result must only be freed once.
  */
  void iscc_free_sum_code_result(struct iscc_IsccSumCodeResult result);
@@ -818,6 +875,126 @@ bool iscc_instance_hasher_update(struct iscc_FfiInstanceHasher *hasher,
  */
  void iscc_instance_hasher_free(struct iscc_FfiInstanceHasher *hasher);
 
+/**
+ * Create a new streaming ISCC-SUM hasher.
+ *
+ * Returns an opaque pointer. The caller must eventually call
+ * `iscc_sum_hasher_free()` to release the memory.
+ */
+ struct iscc_FfiSumHasher *iscc_sum_hasher_new(void);
+
+/**
+ * Push data into a streaming SumHasher.
+ *
+ * Returns `true` on success, `false` on error (e.g., already finalized
+ * or NULL pointer). Check `iscc_last_error()` for the error message.
+ *
+ * # Safety
+ *
+ * - `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+ * - `data` must point to at least `data_len` valid bytes.
+ */
+
+bool iscc_sum_hasher_update(struct iscc_FfiSumHasher *hasher,
+                            const uint8_t *data,
+                            uintptr_t data_len);
+
+/**
+ * Finalize a streaming SumHasher and return an ISCC-SUM result.
+ *
+ * Consumes the inner hasher state. After this call, subsequent `update`
+ * or `finalize` calls will fail. The caller must still call
+ * `iscc_sum_hasher_free()` to release the wrapper, and
+ * `iscc_free_sum_code_result()` to release the returned result.
+ *
+ * # Safety
+ *
+ * `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+ */
+
+struct iscc_IsccSumCodeResult iscc_sum_hasher_finalize(struct iscc_FfiSumHasher *hasher,
+                                                       uint32_t bits,
+                                                       bool wide,
+                                                       bool add_units);
+
+/**
+ * Free a SumHasher previously created by `iscc_sum_hasher_new()`.
+ *
+ * NULL is a no-op. Each pointer must be freed exactly once.
+ *
+ * # Safety
+ *
+ * `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+ */
+ void iscc_sum_hasher_free(struct iscc_FfiSumHasher *hasher);
+
+/**
+ * Open an index file written by `iscc_lib` for querying.
+ *
+ * The file is memory-mapped where supported. Returns an opaque pointer,
+ * or `NULL` on error (check `iscc_last_error()`). The caller must
+ * eventually call `iscc_index_file_free()` to release it.
+ *
+ * # Safety
+ *
+ * `path` must point to a valid null-terminated UTF-8 string, or be null.
+ *
+ * The file is mapped, so it must not be truncated or modified in place
+ * until the handle is freed. Appending segments and compacting (which
+ * replaces the file via rename) are fine, except the first append after
+ * a crashed one, which truncates the torn tail.
+ */
+ struct iscc_FfiIndexFile *iscc_index_file_open(const char *path);
+
+/**
+ * Return the number of live units in an opened index file.
+ *
+ * Returns 0 for a NULL handle.
+ *
+ * # Safety
+ *
+ * `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+ */
+ uintptr_t iscc_index_file_len(const struct iscc_FfiIndexFile *index);
+
+/**
+ * Return the `k` units nearest to an ISCC-UNIT query.
+ *
+ * # Safety
+ *
+ * - `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+ * - `query` must point to a valid null-terminated UTF-8 string, or be null.
+ */
+
+struct iscc_IsccIndexMatchArray iscc_index_file_knn(const struct iscc_FfiIndexFile *index,
+                                                    const char *query,
+                                                    uintptr_t k);
+
+/**
+ * Return all units within Hamming distance `max_distance` of an ISCC-UNIT
+ * query.
+ *
+ * # Safety
+ *
+ * - `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+ * - `query` must point to a valid null-terminated UTF-8 string, or be null.
+ */
+
+struct iscc_IsccIndexMatchArray iscc_index_file_radius(const struct iscc_FfiIndexFile *index,
+                                                       const char *query,
+                                                       uint32_t max_distance);
+
+/**
+ * Free an index file previously opened by `iscc_index_file_open()`.
+ *
+ * NULL is a no-op. Each pointer must be freed exactly once.
+ *
+ * # Safety
+ *
+ * `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+ */
+ void iscc_index_file_free(struct iscc_FfiIndexFile *index);
+
 /**
  * Free a string previously returned by any `iscc_gen_*` function.
  *
@@ -868,6 +1045,19 @@ bool iscc_instance_hasher_update(struct iscc_FfiInstanceHasher *hasher,
  */
  void iscc_free_byte_buffer_array(struct iscc_IsccByteBufferArray arr);
 
+/**
+ * Free a match array returned by `iscc_index_file_knn` or
+ * `iscc_index_file_radius`.
+ *
+ * No-op if `arr.matches` is `NULL`.
+ *
+ * # Safety
+ *
+ * `arr` must be a value returned by an index file query.
+ * Each array must only be freed once.
+ */
+ void iscc_free_index_match_array(struct iscc_IsccIndexMatchArray arr);
+
 /**
  * Return the last error message from the current thread.
  *
@@ -879,4 +1069,15 @@ bool iscc_instance_hasher_update(struct iscc_FfiInstanceHasher *hasher,
  */
  const char *iscc_last_error(void);
 
+/**
+ * Return the stable code of the last error from the current thread.
+ *
+ * Codes match `IsccError::code()` in the Rust core (e.g. `"truncated"`,
+ * `"invalid_bits"`, `"io"`). Returns `NULL` if no error has occurred.
+ * Same lifetime rules as `iscc_last_error()`.
+ *
+ * The returned pointer must NOT be freed by the caller.
+ */
+ const char *iscc_last_error_code(void);
+
 #endif  /* ISCC_H */
@@ -12,7 +12,8 @@
 //! return `IsccByteBuffer` or `IsccByteBufferArray` — callers must free these
 //! with `iscc_free_byte_buffer()` or `iscc_free_byte_buffer_array()`. On
 //! error, functions return `NULL` and the caller retrieves the error message
-//! via `iscc_last_error()`.
+//! via `iscc_last_error()` and its stable code (e.g. `"truncated"`) via
+//! `iscc_last_error_code()`.
 //!
 //! ## Safety
 //!
@@ -27,16 +28,30 @@ use std::ptr;
 thread_local! {
     /// Thread-local storage for the last error message.
     static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+    /// Thread-local storage for the stable code of the last error.
+    static LAST_ERROR_CODE: RefCell<Option<CString>> = const { RefCell::new(None) };
 }
 
-/// Store an error message in thread-local storage.
-fn set_last_error(msg: &str) {
+/// Store an error code and message in thread-local storage.
+fn set_error(code: &str, msg: &str) {
     LAST_ERROR.with(|e| *e.borrow_mut() = CString::new(msg).ok());
+    LAST_ERROR_CODE.with(|c| *c.borrow_mut() = CString::new(code).ok());
+}
+
+/// Store a binding-level error message (code `invalid_input`).
+fn set_last_error(msg: &str) {
+    set_error("invalid_input", msg);
 }
 
-/// Clear the thread-local error message.
+/// Store a core `IsccError` with its stable code.
+fn set_iscc_error(e: &iscc_lib::IsccError) {
+    set_error(e.code(), &e.to_string());
+}
+
+/// Clear the thread-local error message and code.
 fn clear_last_error() {
     LAST_ERROR.with(|e| *e.borrow_mut() = None);
+    LAST_ERROR_CODE.with(|c| *c.borrow_mut() = None);
 }
 
 // --- Algorithm constants ---
@@ -125,7 +140,7 @@ fn result_to_c_string(result: Result<String, iscc_lib::IsccError>) -> *mut c_cha
             }
         },
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             ptr::null_mut()
         }
     }
@@ -815,8 +830,8 @@ fn null_sum_code_result() -> IsccSumCodeResult {
 
 /// Generate a composite ISCC-CODE from a file path (Data-Code + Instance-Code).
 ///
-/// Single-pass file I/O feeds both DataHasher and InstanceHasher, then composes
-/// the ISCC-CODE internally.
+/// Single-pass file I/O feeds a SumHasher (DataHasher and InstanceHasher),
+/// then composes the ISCC-CODE internally.
 ///
 /// # Parameters
 ///
@@ -844,7 +859,19 @@ pub unsafe extern "C" fn iscc_gen_sum_code_v0(
     let Some(path_str) = (unsafe { ptr_to_str(path, "path") }) else {
         return null_sum_code_result();
     };
-    match iscc_lib::gen_sum_code_v0(std::path::Path::new(path_str), bits, wide, add_units) {
+    sum_code_result_to_c(iscc_lib::gen_sum_code_v0(
+        std::path::Path::new(path_str),
+        bits,
+        wide,
+        add_units,
+    ))
+}
+
+/// Convert an ISCC-SUM result into its C representation, recording errors.
+fn sum_code_result_to_c(
+    result: iscc_lib::IsccResult<iscc_lib::SumCodeResult>,
+) -> IsccSumCodeResult {
+    match result {
         Ok(result) => {
             let iscc = string_to_c(result.iscc);
             if iscc.is_null() {
@@ -876,19 +903,21 @@ pub unsafe extern "C" fn iscc_gen_sum_code_v0(
             }
         }
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             null_sum_code_result()
         }
     }
 }
 
-/// Free an `IsccSumCodeResult` previously returned by `iscc_gen_sum_code_v0`.
+/// Free an `IsccSumCodeResult` previously returned by `iscc_gen_sum_code_v0`
+/// or `iscc_sum_hasher_finalize`.
 ///
 /// Releases the `iscc`, `datahash` strings, and `units` array. No-op for NULL pointers.
 ///
 /// # Safety
 ///
-/// `result` must be a value returned by `iscc_gen_sum_code_v0`.
+/// `result` must be a value returned by `iscc_gen_sum_code_v0` or
+/// `iscc_sum_hasher_finalize`.
 /// Each result must only be freed once.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn iscc_free_sum_code_result(result: IsccSumCodeResult) {
@@ -954,7 +983,7 @@ pub unsafe extern "C" fn iscc_decode(iscc: *const c_char) -> IsccDecodeResult {
             digest: vec_to_byte_buffer(digest),
         },
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             IsccDecodeResult {
                 ok: false,
                 maintype: 0,
@@ -1015,7 +1044,7 @@ pub unsafe extern "C" fn iscc_decompose(iscc_code: *const c_char) -> *mut *mut c
     match iscc_lib::iscc_decompose(iscc_code) {
         Ok(units) => vec_to_c_string_array(units),
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             ptr::null_mut()
         }
     }
@@ -1045,7 +1074,7 @@ pub unsafe extern "C" fn iscc_sliding_window(seq: *const c_char, width: u32) ->
     match iscc_lib::sliding_window(seq, width as usize) {
         Ok(v) => vec_to_c_string_array(v),
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             ptr::null_mut()
         }
     }
@@ -1086,7 +1115,7 @@ pub unsafe extern "C" fn iscc_alg_simhash(
         return match iscc_lib::alg_simhash(empty) {
             Ok(v) => vec_to_byte_buffer(v),
             Err(e) => {
-                set_last_error(&e.to_string());
+                set_iscc_error(&e);
                 null_byte_buffer()
             }
         };
@@ -1115,7 +1144,7 @@ pub unsafe extern "C" fn iscc_alg_simhash(
     match iscc_lib::alg_simhash(&slices) {
         Ok(v) => vec_to_byte_buffer(v),
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             null_byte_buffer()
         }
     }
@@ -1199,7 +1228,7 @@ pub unsafe extern "C" fn iscc_alg_cdc_chunks(
     let chunks = match iscc_lib::alg_cdc_chunks(slice, utf32, avg_chunk_size) {
         Ok(c) => c,
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             return null_byte_buffer_array();
         }
     };
@@ -1268,7 +1297,7 @@ pub unsafe extern "C" fn iscc_soft_hash_video_v0(
     match iscc_lib::soft_hash_video_v0(&frames, bits) {
         Ok(result) => vec_to_byte_buffer(result),
         Err(e) => {
-            set_last_error(&e.to_string());
+            set_iscc_error(&e);
             null_byte_buffer()
         }
     }
@@ -1292,6 +1321,14 @@ pub struct FfiInstanceHasher {
     inner: Option<iscc_lib::InstanceHasher>,
 }
 
+/// Opaque FFI wrapper around `iscc_lib::SumHasher`.
+///
+/// Enforces finalize-once semantics via `Option<Inner>`. Not `#[repr(C)]` —
+/// C callers interact only through function pointers.
+pub struct FfiSumHasher {
+    inner: Option<iscc_lib::SumHasher>,
+}
+
 /// Create a new streaming Data-Code hasher.
 ///
 /// Returns an opaque pointer. The caller must eventually call
@@ -1486,6 +1523,295 @@ pub unsafe extern "C" fn iscc_instance_hasher_free(hasher: *mut FfiInstanceHashe
     }
 }
 
+/// Create a new streaming ISCC-SUM hasher.
+///
+/// Returns an opaque pointer. The caller must eventually call
+/// `iscc_sum_hasher_free()` to release the memory.
+#[unsafe(no_mangle)]
+pub extern "C" fn iscc_sum_hasher_new() -> *mut FfiSumHasher {
+    clear_last_error();
+    Box::into_raw(Box::new(FfiSumHasher {
+        inner: Some(iscc_lib::SumHasher::new()),
+    }))
+}
+
+/// Push data into a streaming SumHasher.
+///
+/// Returns `true` on success, `false` on error (e.g., already finalized
+/// or NULL pointer). Check `iscc_last_error()` for the error message.
+///
+/// # Safety
+///
+/// - `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+/// - `data` must point to at least `data_len` valid bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_sum_hasher_update(
+    hasher: *mut FfiSumHasher,
+    data: *const u8,
+    data_len: usize,
+) -> bool {
+    clear_last_error();
+    if hasher.is_null() {
+        set_last_error("hasher must not be NULL");
+        return false;
+    }
+    // SAFETY: caller guarantees hasher is a valid pointer from iscc_sum_hasher_new()
+    let wrapper = unsafe { &mut *hasher };
+    let Some(inner) = wrapper.inner.as_mut() else {
+        set_last_error("SumHasher already finalized");
+        return false;
+    };
+    // SAFETY: caller guarantees data is valid for data_len bytes
+    let slice = if data_len == 0 {
+        &[]
+    } else {
+        if data.is_null() {
+            set_last_error("data must not be NULL");
+            return false;
+        }
+        unsafe { std::slice::from_raw_parts(data, data_len) }
+    };
+    inner.update(slice);
+    true
+}
+
+/// Finalize a streaming SumHasher and return an ISCC-SUM result.
+///
+/// Consumes the inner hasher state. After this call, subsequent `update`
+/// or `finalize` calls will fail. The caller must still call
+/// `iscc_sum_hasher_free()` to release the wrapper, and
+/// `iscc_free_sum_code_result()` to release the returned result.
+///
+/// # Safety
+///
+/// `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_sum_hasher_finalize(
+    hasher: *mut FfiSumHasher,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+) -> IsccSumCodeResult {
+    clear_last_error();
+    if hasher.is_null() {
+        set_last_error("hasher must not be NULL");
+        return null_sum_code_result();
+    }
+    // SAFETY: caller guarantees hasher is a valid pointer from iscc_sum_hasher_new()
+    let wrapper = unsafe { &mut *hasher };
+    let Some(inner) = wrapper.inner.take() else {
+        set_last_error("SumHasher already finalized");
+        return null_sum_code_result();
+    };
+    sum_code_result_to_c(inner.finalize(bits, wide, add_units))
+}
+
+/// Free a SumHasher previously created by `iscc_sum_hasher_new()`.
+///
+/// NULL is a no-op. Each pointer must be freed exactly once.
+///
+/// # Safety
+///
+/// `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_sum_hasher_free(hasher: *mut FfiSumHasher) {
+    clear_last_error();
+    if !hasher.is_null() {
+        // SAFETY: hasher was produced by Box::into_raw() in iscc_sum_hasher_new()
+        drop(unsafe { Box::from_raw(hasher) });
+    }
+}
+
+// ── Index files ─────────────────────────────────────────────────────────────
+
+/// Opaque handle to an opened `iscc_lib::IndexFile`.
+///
+/// Not `#[repr(C)]` — C callers interact only through function pointers.
+pub struct FfiIndexFile {
+    inner: iscc_lib::IndexFile,
+}
+
+/// One match of an index file query.
+#[repr(C)]
+pub struct IsccIndexMatch {
+    /// External ID the unit was stored under.
+    pub id: u64,
+    /// Hamming distance to the query.
+    pub distance: u32,
+    /// Number of digest bits compared.
+    pub bits: u32,
+}
+
+/// Array of index matches sorted by distance, then ID.
+///
+/// On success, `ok` is `true` and `matches` points to `count` elements
+/// (`NULL` if `count` is 0). On error, `ok` is `false`. Callers must free
+/// with `iscc_free_index_match_array()`.
+#[repr(C)]
+pub struct IsccIndexMatchArray {
+    /// Whether the query succeeded.
+    pub ok: bool,
+    /// Pointer to the matches.
+    pub matches: *mut IsccIndexMatch,
+    /// Number of matches.
+    pub count: usize,
+}
+
+/// Return an error `IsccIndexMatchArray`.
+fn null_index_match_array() -> IsccIndexMatchArray {
+    IsccIndexMatchArray {
+        ok: false,
+        matches: ptr::null_mut(),
+        count: 0,
+    }
+}
+
+/// Convert a query result into an `IsccIndexMatchArray`.
+fn index_matches_to_c(
+    result: Result<Vec<iscc_lib::IndexMatch>, iscc_lib::IsccError>,
+) -> IsccIndexMatchArray {
+    let matches = match result {
+        Ok(matches) => matches,
+        Err(e) => {
+            set_iscc_error(&e);
+            return null_index_match_array();
+        }
+    };
+    let count = matches.len();
+    if count == 0 {
+        return IsccIndexMatchArray {
+            ok: true,
+            matches: ptr::null_mut(),
+            count: 0,
+        };
+    }
+    let boxed: Box<[IsccIndexMatch]> = matches
+        .into_iter()
+        .map(|m| IsccIndexMatch {
+            id: m.id,
+            distance: m.distance,
+            bits: m.bits,
+        })
+        .collect();
+    IsccIndexMatchArray {
+        ok: true,
+        matches: Box::into_raw(boxed) as *mut IsccIndexMatch,
+        count,
+    }
+}
+
+/// Open an index file written by `iscc_lib` for querying.
+///
+/// The file is memory-mapped where supported. Returns an opaque pointer,
+/// or `NULL` on error (check `iscc_last_error()`). The caller must
+/// eventually call `iscc_index_file_free()` to release it.
+///
+/// # Safety
+///
+/// `path` must point to a valid null-terminated UTF-8 string, or be null.
+///
+/// The file is mapped, so it must not be truncated or modified in place
+/// until the handle is freed. Appending segments and compacting (which
+/// replaces the file via rename) are fine, except the first append after
+/// a crashed one, which truncates the torn tail.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_index_file_open(path: *const c_char) -> *mut FfiIndexFile {
+    clear_last_error();
+    let Some(path_str) = (unsafe { ptr_to_str(path, "path") }) else {
+        return ptr::null_mut();
+    };
+    // SAFETY: the caller guarantees the file is not modified while mapped.
+    match unsafe { iscc_lib::IndexFile::open_mmap(std::path::Path::new(path_str)) } {
+        Ok(inner) => Box::into_raw(Box::new(FfiIndexFile { inner })),
+        Err(e) => {
+            set_iscc_error(&e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Return the number of live units in an opened index file.
+///
+/// Returns 0 for a NULL handle.
+///
+/// # Safety
+///
+/// `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_index_file_len(index: *const FfiIndexFile) -> usize {
+    clear_last_error();
+    if index.is_null() {
+        return 0;
+    }
+    // SAFETY: caller guarantees index is a valid pointer from iscc_index_file_open()
+    unsafe { &*index }.inner.len()
+}
+
+/// Return the `k` units nearest to an ISCC-UNIT query.
+///
+/// # Safety
+///
+/// - `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+/// - `query` must point to a valid null-terminated UTF-8 string, or be null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_index_file_knn(
+    index: *const FfiIndexFile,
+    query: *const c_char,
+    k: usize,
+) -> IsccIndexMatchArray {
+    clear_last_error();
+    if index.is_null() {
+        set_last_error("index must not be NULL");
+        return null_index_match_array();
+    }
+    let Some(query) = (unsafe { ptr_to_str(query, "query") }) else {
+        return null_index_match_array();
+    };
+    // SAFETY: caller guarantees index is a valid pointer from iscc_index_file_open()
+    index_matches_to_c(unsafe { &*index }.inner.knn(query, k))
+}
+
+/// Return all units within Hamming distance `max_distance` of an ISCC-UNIT
+/// query.
+///
+/// # Safety
+///
+/// - `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+/// - `query` must point to a valid null-terminated UTF-8 string, or be null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_index_file_radius(
+    index: *const FfiIndexFile,
+    query: *const c_char,
+    max_distance: u32,
+) -> IsccIndexMatchArray {
+    clear_last_error();
+    if index.is_null() {
+        set_last_error("index must not be NULL");
+        return null_index_match_array();
+    }
+    let Some(query) = (unsafe { ptr_to_str(query, "query") }) else {
+        return null_index_match_array();
+    };
+    // SAFETY: caller guarantees index is a valid pointer from iscc_index_file_open()
+    index_matches_to_c(unsafe { &*index }.inner.radius(query, max_distance))
+}
+
+/// Free an index file previously opened by `iscc_index_file_open()`.
+///
+/// NULL is a no-op. Each pointer must be freed exactly once.
+///
+/// # Safety
+///
+/// `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_index_file_free(index: *mut FfiIndexFile) {
+    clear_last_error();
+    if !index.is_null() {
+        // SAFETY: index was produced by Box::into_raw() in iscc_index_file_open()
+        drop(unsafe { Box::from_raw(index) });
+    }
+}
+
 // ── Memory management ───────────────────────────────────────────────────────
 
 /// Free a string previously returned by any `iscc_gen_*` function.
@@ -1572,6 +1898,23 @@ pub unsafe extern "C" fn iscc_free_byte_buffer_array(arr: IsccByteBufferArray) {
     }
 }
 
+/// Free a match array returned by `iscc_index_file_knn` or
+/// `iscc_index_file_radius`.
+///
+/// No-op if `arr.matches` is `NULL`.
+///
+/// # Safety
+///
+/// `arr` must be a value returned by an index file query.
+/// Each array must only be freed once.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iscc_free_index_match_array(arr: IsccIndexMatchArray) {
+    if !arr.matches.is_null() {
+        // SAFETY: matches was produced by Box::into_raw() on a boxed slice of count elements
+        drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(arr.matches, arr.count)) });
+    }
+}
+
 /// Return the last error message from the current thread.
 ///
 /// Returns a pointer to a null-terminated string valid until the next
@@ -1590,6 +1933,24 @@ pub extern "C" fn iscc_last_error() -> *const c_char {
     })
 }
 
+/// Return the stable code of the last error from the current thread.
+///
+/// Codes match `IsccError::code()` in the Rust core (e.g. `"truncated"`,
+/// `"invalid_bits"`, `"io"`). Returns `NULL` if no error has occurred.
+/// Same lifetime rules as `iscc_last_error()`.
+///
+/// The returned pointer must NOT be freed by the caller.
+#[unsafe(no_mangle)]
+pub extern "C" fn iscc_last_error_code() -> *const c_char {
+    LAST_ERROR_CODE.with(|c| {
+        let borrow = c.borrow();
+        match borrow.as_ref() {
+            Some(cs) => cs.as_ptr(),
+            None => ptr::null(),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1779,9 +2140,24 @@ mod tests {
         let result = unsafe { iscc_gen_text_code_v0(text.as_ptr(), 64) };
         assert!(!result.is_null());
         assert!(iscc_last_error().is_null());
+        assert!(iscc_last_error_code().is_null());
         unsafe { iscc_free_string(result) };
     }
 
+    #[test]
+    fn test_last_error_code_from_core_error() {
+        let json = CString::new("{not json").unwrap();
+        let result = unsafe { iscc_json_to_data_url(json.as_ptr()) };
+        assert!(result.is_null());
+        let code = unsafe { CStr::from_ptr(iscc_last_error_code()) };
+        assert_eq!(code.to_str().unwrap(), "invalid_json");
+
+        let result = unsafe { iscc_gen_text_code_v0(ptr::null(), 64) };
+        assert!(result.is_null());
+        let code = unsafe { CStr::from_ptr(iscc_last_error_code()) };
+        assert_eq!(code.to_str().unwrap(), "invalid_input");
+    }
+
     // ── text_clean tests ────────────────────────────────────────────────────
 
     #[test]
@@ -2231,6 +2607,37 @@ mod tests {
         unsafe { iscc_instance_hasher_free(ptr::null_mut()) };
     }
 
+    // ── SumHasher streaming tests ────────────────────────────────────────
+
+    #[test]
+    fn test_sum_hasher_matches_gen() {
+        let data = b"Hello World";
+        let path = write_ffi_temp_file("sum_hasher", data);
+        let path_c = CString::new(path.to_str().unwrap()).unwrap();
+        let oneshot = unsafe { iscc_gen_sum_code_v0(path_c.as_ptr(), 64, false, true) };
+        assert!(oneshot.ok);
+
+        let hasher = iscc_sum_hasher_new();
+        assert!(unsafe { iscc_sum_hasher_update(hasher, data.as_ptr(), 5) });
+        assert!(unsafe { iscc_sum_hasher_update(hasher, data[5..].as_ptr(), data.len() - 5) });
+        let result = unsafe { iscc_sum_hasher_finalize(hasher, 64, false, true) };
+        assert!(result.ok);
+        assert_eq!(result.filesize, 11);
+        assert!(!result.units.is_null());
+        let (a, b) = unsafe { (CStr::from_ptr(result.iscc), CStr::from_ptr(oneshot.iscc)) };
+        assert_eq!(a, b);
+
+        // Finalize-once semantics
+        let again = unsafe { iscc_sum_hasher_finalize(hasher, 64, false, false) };
+        assert!(!again.ok);
+        assert!(!unsafe { iscc_sum_hasher_update(hasher, data.as_ptr(), data.len()) });
+
+        unsafe { iscc_free_sum_code_result(result) };
+        unsafe { iscc_free_sum_code_result(oneshot) };
+        unsafe { iscc_sum_hasher_free(hasher) };
+        let _ = std::fs::remove_file(&path);
+    }
+
     // ── Algorithm constants tests ────────────────────────────────────────
 
     #[test]
@@ -2542,4 +2949,62 @@ mod tests {
         unsafe { iscc_free_sum_code_result(result) };
         let _ = std::fs::remove_file(&path);
     }
+
+    #[test]
+    fn test_index_file_query() {
+        let path = std::env::temp_dir().join(format!("iscc_ffi_index_{}.idx", std::process::id()));
+        let units: Vec<String> = [0u64, 1, 0xFF]
+            .iter()
+            .map(|v| {
+                iscc_lib::gen_data_code_v0(&v.to_be_bytes(), 64)
+                    .unwrap()
+                    .iscc
+            })
+            .collect();
+        let mut index = iscc_lib::IsccIndex::new();
+        for (id, unit) in units.iter().enumerate() {
+            index.insert(id as u64, unit).unwrap();
+        }
+        index.save(&path).unwrap();
+
+        let path_c = CString::new(path.to_str().unwrap()).unwrap();
+        let handle = unsafe { iscc_index_file_open(path_c.as_ptr()) };
+        assert!(!handle.is_null());
+        assert_eq!(unsafe { iscc_index_file_len(handle) }, 3);
+
+        let query = CString::new(units[1].as_str()).unwrap();
+        let arr = unsafe { iscc_index_file_knn(handle, query.as_ptr(), 2) };
+        assert!(arr.ok);
+        assert_eq!(arr.count, 2);
+        let got = unsafe { std::slice::from_raw_parts(arr.matches, arr.count) };
+        let expected = index.knn(&units[1], 2).unwrap();
+        for (m, e) in got.iter().zip(&expected) {
+            assert_eq!((m.id, m.distance, m.bits), (e.id, e.distance, e.bits));
+        }
+        unsafe { iscc_free_index_match_array(arr) };
+
+        let arr = unsafe { iscc_index_file_radius(handle, query.as_ptr(), 0) };
+        assert!(arr.ok);
+        assert_eq!(arr.count, 1);
+        unsafe { iscc_free_index_match_array(arr) };
+
+        let bad = CString::new("not an iscc").unwrap();
+        let arr = unsafe { iscc_index_file_knn(handle, bad.as_ptr(), 2) };
+        assert!(!arr.ok);
+        assert!(!iscc_last_error().is_null());
+        unsafe { iscc_free_index_match_array(arr) };
+
+        unsafe { iscc_index_file_free(handle) };
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_index_file_open_missing() {
+        let path = CString::new("/nonexistent/iscc.idx").unwrap();
+        let handle = unsafe { iscc_index_file_open(path.as_ptr()) };
+        assert!(handle.is_null());
+        let code = unsafe { CStr::from_ptr(iscc_last_error_code()) };
+        assert_eq!(code.to_str().unwrap(), "io");
+        unsafe { iscc_index_file_free(ptr::null_mut()) };
+    }
 }
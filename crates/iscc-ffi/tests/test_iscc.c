@@ -119,6 +119,8 @@ int main(void) {
     {
         const char *err = iscc_last_error();
         ASSERT_NOT_NULL(err, "iscc_last_error() non-NULL after error");
+        const char *code = iscc_last_error_code();
+        ASSERT_STR_EQ(code, "invalid_input", "iscc_last_error_code() after NULL argument");
     }
 
     /* 8. Error cleared on success */
@@ -453,6 +455,67 @@ int main(void) {
         }
     }
 
+    /* 29. index file — missing file and empty index */
+    {
+        struct iscc_FfiIndexFile *missing = iscc_index_file_open("/nonexistent/iscc_c_test.idx");
+        ASSERT_NULL(missing, "index_file_open(missing) returns NULL");
+        ASSERT_STR_EQ(iscc_last_error_code(), "io", "index_file_open(missing) error code");
+
+        const char *tmppath = "/tmp/iscc_c_test_empty.idx";
+        FILE *fp = fopen(tmppath, "wb");
+        if (fp != NULL) {
+            /* File header only: magic, version 1, 48-byte records */
+            const uint8_t header[16] = {'I', 'S', 'C', 'C', '-', 'I', 'D', 'X',
+                                        1, 0, 0, 0, 48, 0, 0, 0};
+            fwrite(header, 1, sizeof(header), fp);
+            fclose(fp);
+
+            struct iscc_FfiIndexFile *index = iscc_index_file_open(tmppath);
+            ASSERT_NOT_NULL(index, "index_file_open(empty) returns handle");
+            ASSERT_EQ(iscc_index_file_len(index), 0, "index_file_len(empty) == 0");
+            struct iscc_IsccIndexMatchArray arr = iscc_index_file_knn(index, "GAAQAAAAAAAAAAAA", 5);
+            if (arr.ok && arr.count == 0) {
+                printf("PASS: index_file_knn(empty) ok with no matches\n");
+                tests_passed++;
+            } else {
+                printf("FAIL: index_file_knn(empty) should succeed with no matches\n");
+                tests_failed++;
+            }
+            iscc_free_index_match_array(arr);
+            iscc_index_file_free(index);
+            remove(tmppath);
+        } else {
+            printf("FAIL: index file — could not create temp file\n");
+            tests_failed++;
+        }
+    }
+
+    /* 30. SumHasher — streamed bytes match gen_sum_code_v0 */
+    {
+        const char *tmppath = "/tmp/iscc_c_test_sum_hasher.bin";
+        FILE *fp = fopen(tmppath, "wb");
+        if (fp != NULL) {
+            fwrite("Hello World", 1, 11, fp);
+            fclose(fp);
+
+            struct iscc_IsccSumCodeResult expected = iscc_gen_sum_code_v0(tmppath, 64, false, false);
+            struct iscc_FfiSumHasher *sh = iscc_sum_hasher_new();
+            ASSERT_NOT_NULL(sh, "sum_hasher_new returns non-NULL");
+            iscc_sum_hasher_update(sh, (const uint8_t *)"Hello", 5);
+            iscc_sum_hasher_update(sh, (const uint8_t *)" World", 6);
+            struct iscc_IsccSumCodeResult sr = iscc_sum_hasher_finalize(sh, 64, false, false);
+            ASSERT_STR_EQ(sr.iscc, expected.iscc, "sum_hasher_finalize matches gen_sum_code_v0");
+            ASSERT_EQ(sr.filesize, 11, "sum_hasher_finalize filesize == 11");
+            iscc_free_sum_code_result(sr);
+            iscc_free_sum_code_result(expected);
+            iscc_sum_hasher_free(sh);
+            remove(tmppath);
+        } else {
+            printf("FAIL: SumHasher — could not create temp file\n");
+            tests_failed++;
+        }
+    }
+
     /* Summary */
     printf("\n%d passed, %d failed\n", tests_passed, tests_failed);
     return tests_failed > 0 ? 1 : 0;
@@ -0,0 +1,37 @@
+package io.iscc.iscc_lib;
+
+/**
+ * Exception thrown when the native ISCC library reports an error.
+ *
+ * <p>Extends {@link IllegalArgumentException} for compatibility with callers
+ * that already catch it. {@link #getCode()} returns a stable machine-readable
+ * code (e.g., {@code "truncated"}, {@code "invalid_bits"}, {@code "io"}) that
+ * matches {@code IsccError::code()} in the Rust core.
+ */
+public class IsccException extends IllegalArgumentException {
+
+    private static final long serialVersionUID = 1L;
+
+    /** Stable error code. */
+    private final String code;
+
+    /**
+     * Construct an exception with a stable code and a human-readable message.
+     *
+     * @param code    stable error code
+     * @param message human-readable error message
+     */
+    public IsccException(String code, String message) {
+        super(message);
+        this.code = code;
+    }
+
+    /**
+     * Return the stable error code.
+     *
+     * @return error code such as {@code "truncated"} or {@code "io"}
+     */
+    public String getCode() {
+        return code;
+    }
+}
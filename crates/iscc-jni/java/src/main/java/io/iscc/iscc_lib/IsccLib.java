@@ -160,6 +160,29 @@ public class IsccLib {
      */
     public static native SumCodeResult genSumCodeV0(String path, int bits, boolean wide, boolean addUnits);
 
+    /**
+     * Generate an ISCC-SUM code from a file path, reporting progress.
+     *
+     * <p>Same result as {@link #genSumCodeV0}. The listener is called on the
+     * calling thread after each read block, and the token is checked before
+     * each one, so another thread can stop hashing via
+     * {@link #cancelTokenCancel(long)}.
+     *
+     * @param path        file path to process
+     * @param bits        hash bit length (32, 64, 96, 128, 160, 192, 224, 256)
+     * @param wide        if {@code true}, use 256-bit combination (requires bits >= 128)
+     * @param addUnits    if {@code true}, include individual Data-Code and Instance-Code
+     *                    ISCC strings in the result's {@code units} field
+     * @param listener    progress listener, or {@code null}
+     * @param cancelToken handle from {@link #cancelTokenNew()}, or {@code 0}
+     * @return SumCodeResult with iscc, datahash, filesize, and optionally units
+     * @throws IsccException with code {@code "cancelled"} if the token was cancelled,
+     *                       or on invalid input or file I/O error
+     */
+    public static native SumCodeResult genSumCodeV0WithProgress(
+            String path, int bits, boolean wide, boolean addUnits,
+            ProgressListener listener, long cancelToken);
+
     // ── Text utilities ──────────────────────────────────────────────────────
 
     /**
@@ -398,4 +421,89 @@ public class IsccLib {
      * @param ptr opaque handle from {@link #instanceHasherNew()}
      */
     public static native void instanceHasherFree(long ptr);
+
+    /**
+     * Create a new streaming ISCC-SUM hasher.
+     *
+     * <p>Returns an opaque handle. The caller must eventually call
+     * {@link #sumHasherFree(long)} to release the memory.
+     *
+     * @return opaque handle to the hasher
+     */
+    public static native long sumHasherNew();
+
+    /**
+     * Push data into a streaming SumHasher.
+     *
+     * @param ptr  opaque handle from {@link #sumHasherNew()}
+     * @param data byte data to feed into the hasher
+     * @throws IllegalStateException if the hasher has been finalized
+     */
+    public static native void sumHasherUpdate(long ptr, byte[] data);
+
+    /**
+     * Finalize a streaming SumHasher and return an ISCC-SUM result.
+     *
+     * <p>Produces the same result as {@link #genSumCodeV0} for a file with the
+     * same contents. Consumes the inner hasher state; the caller must still
+     * call {@link #sumHasherFree(long)} to release the wrapper.
+     *
+     * @param ptr      opaque handle from {@link #sumHasherNew()}
+     * @param bits     hash bit length (32, 64, 96, 128, 160, 192, 224, 256)
+     * @param wide     if {@code true}, use 256-bit combination (requires bits >= 128)
+     * @param addUnits if {@code true}, include individual Data-Code and Instance-Code
+     *                 ISCC strings in the result's {@code units} field
+     * @return SumCodeResult with iscc, datahash, filesize, and optionally units
+     * @throws IllegalStateException if already finalized
+     */
+    public static native SumCodeResult sumHasherFinalize(long ptr, int bits, boolean wide, boolean addUnits);
+
+    /**
+     * Free a SumHasher previously created by {@link #sumHasherNew()}.
+     *
+     * <p>Zero/null handle is a no-op. Each handle must be freed exactly once.
+     *
+     * @param ptr opaque handle from {@link #sumHasherNew()}
+     */
+    public static native void sumHasherFree(long ptr);
+
+    // ── Cancellation ────────────────────────────────────────────────────────
+
+    /**
+     * Create a new cancellation token for {@link #genSumCodeV0WithProgress}.
+     *
+     * <p>Returns an opaque handle. The caller must eventually call
+     * {@link #cancelTokenFree(long)} to release the memory.
+     *
+     * @return opaque handle to the token
+     */
+    public static native long cancelTokenNew();
+
+    /**
+     * Cancel every operation using the token. Safe to call from any thread.
+     *
+     * <p>Zero/null handle is a no-op.
+     *
+     * @param ptr opaque handle from {@link #cancelTokenNew()}
+     */
+    public static native void cancelTokenCancel(long ptr);
+
+    /**
+     * Check whether the token has been cancelled.
+     *
+     * @param ptr opaque handle from {@link #cancelTokenNew()}
+     * @return {@code true} once {@link #cancelTokenCancel(long)} has been called;
+     *     {@code false} for a zero/null handle
+     */
+    public static native boolean cancelTokenIsCancelled(long ptr);
+
+    /**
+     * Free a token previously created by {@link #cancelTokenNew()}.
+     *
+     * <p>Zero/null handle is a no-op. Each handle must be freed exactly once,
+     * and not while an operation is still using it.
+     *
+     * @param ptr opaque handle from {@link #cancelTokenNew()}
+     */
+    public static native void cancelTokenFree(long ptr);
 }
@@ -0,0 +1,16 @@
+package io.iscc.iscc_lib;
+
+/**
+ * Receives progress updates from {@link IsccLib#genSumCodeV0WithProgress}.
+ */
+@FunctionalInterface
+public interface ProgressListener {
+
+    /**
+     * Called after each block of the file has been hashed.
+     *
+     * @param done  bytes processed so far
+     * @param total file size in bytes, or {@code 0} if unknown
+     */
+    void onProgress(long done, long total);
+}
@@ -9,6 +9,7 @@ package io.iscc.iscc_lib;
 
 import static org.junit.jupiter.api.Assertions.assertArrayEquals;
 import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
 import static org.junit.jupiter.api.Assertions.assertNotNull;
 import static org.junit.jupiter.api.Assertions.assertNull;
 import static org.junit.jupiter.api.Assertions.assertThrows;
@@ -23,6 +24,7 @@ import java.nio.file.Path;
 import java.util.ArrayList;
 import java.util.Collection;
 import java.util.HexFormat;
+import java.util.List;
 import java.util.Map;
 import java.util.TreeMap;
 import org.junit.jupiter.api.BeforeAll;
@@ -453,6 +455,31 @@ class IsccLibTest {
                 "units[1] should be an Instance-Code (starts with ISCC:IA)");
     }
 
+    /** Verify SumHasher streaming matches genSumCodeV0 on the same bytes. */
+    @Test
+    void sumHasherMatchesGenSumCodeV0() throws Exception {
+        byte[] content = "SumHasher streaming verification".getBytes(java.nio.charset.StandardCharsets.UTF_8);
+        java.io.File tmp = java.io.File.createTempFile("iscc-jni-sum-hasher-", ".bin");
+        tmp.deleteOnExit();
+        java.nio.file.Files.write(tmp.toPath(), content);
+        SumCodeResult expected = IsccLib.genSumCodeV0(tmp.getAbsolutePath(), 64, false, true);
+
+        long ptr = IsccLib.sumHasherNew();
+        try {
+            IsccLib.sumHasherUpdate(ptr, java.util.Arrays.copyOfRange(content, 0, 9));
+            IsccLib.sumHasherUpdate(ptr, java.util.Arrays.copyOfRange(content, 9, content.length));
+            SumCodeResult result = IsccLib.sumHasherFinalize(ptr, 64, false, true);
+            assertEquals(expected.iscc, result.iscc);
+            assertEquals(expected.datahash, result.datahash);
+            assertEquals(expected.filesize, result.filesize);
+            assertArrayEquals(expected.units, result.units);
+            assertThrows(IllegalStateException.class,
+                    () -> IsccLib.sumHasherFinalize(ptr, 64, false, false));
+        } finally {
+            IsccLib.sumHasherFree(ptr);
+        }
+    }
+
     // ── Negative jint validation ─────────────────────────────────────────────
 
     /** Verify textTrim throws IllegalArgumentException for negative nbytes. */
@@ -562,12 +589,69 @@ class IsccLibTest {
         assertEquals(8, result.digest.length, "digest should be 8 bytes for 64-bit");
     }
 
+    /** Verify genSumCodeV0WithProgress matches genSumCodeV0 and reports the final size. */
+    @Test
+    void genSumCodeV0WithProgressReportsProgress() throws Exception {
+        byte[] content = "Progress reporting test".getBytes(java.nio.charset.StandardCharsets.UTF_8);
+        java.io.File tmp = java.io.File.createTempFile("iscc-jni-sum-progress-", ".bin");
+        tmp.deleteOnExit();
+        java.nio.file.Files.write(tmp.toPath(), content);
+        SumCodeResult expected = IsccLib.genSumCodeV0(tmp.getAbsolutePath(), 64, false, false);
+
+        List<long[]> calls = new ArrayList<>();
+        SumCodeResult result = IsccLib.genSumCodeV0WithProgress(
+                tmp.getAbsolutePath(), 64, false, false,
+                (done, total) -> calls.add(new long[] {done, total}), 0);
+
+        assertEquals(expected.iscc, result.iscc);
+        assertEquals(1, calls.size());
+        assertArrayEquals(new long[] {content.length, content.length}, calls.get(0));
+    }
+
+    /** Verify a cancelled token aborts with code "cancelled" and listener errors propagate. */
+    @Test
+    void genSumCodeV0WithProgressCancellation() throws Exception {
+        java.io.File tmp = java.io.File.createTempFile("iscc-jni-sum-cancel-", ".bin");
+        tmp.deleteOnExit();
+        java.nio.file.Files.write(tmp.toPath(), new byte[1000]);
+        String path = tmp.getAbsolutePath();
+
+        long token = IsccLib.cancelTokenNew();
+        try {
+            RuntimeException boom = assertThrows(RuntimeException.class,
+                    () -> IsccLib.genSumCodeV0WithProgress(path, 64, false, false,
+                            (done, total) -> {
+                                throw new RuntimeException("stop");
+                            }, token));
+            assertEquals("stop", boom.getMessage());
+            assertFalse(IsccLib.cancelTokenIsCancelled(token));
+
+            IsccLib.cancelTokenCancel(token);
+            assertTrue(IsccLib.cancelTokenIsCancelled(token));
+            IsccException ex = assertThrows(IsccException.class,
+                    () -> IsccLib.genSumCodeV0WithProgress(path, 64, false, false, null, token));
+            assertEquals("cancelled", ex.getCode());
+        } finally {
+            IsccLib.cancelTokenFree(token);
+        }
+        IsccLib.cancelTokenCancel(0);
+        assertFalse(IsccLib.cancelTokenIsCancelled(0));
+    }
+
     /** Verify isccDecode throws IllegalArgumentException on invalid input. */
     @Test
     void testIsccDecodeInvalid() {
         assertThrows(IllegalArgumentException.class, () -> IsccLib.isccDecode("INVALID"));
     }
 
+    /** Verify core errors surface as IsccException with a stable code. */
+    @Test
+    void testIsccExceptionCode() {
+        IsccException ex = assertThrows(
+            IsccException.class, () -> IsccLib.jsonToDataUrl("{not json"));
+        assertEquals("invalid_json", ex.getCode());
+    }
+
     /** Verify roundtrip: encodeComponent -> isccDecode -> fields match inputs. */
     @Test
     void testEncodeDecodeRoundtrip() {
@@ -12,18 +12,23 @@
 //!
 //! ## Error handling
 //!
-//! For fallible functions, errors are propagated to Java by throwing
-//! `IllegalArgumentException` via `env.throw_new()` and returning a
-//! type-appropriate default value using `throw_and_default`.
+//! For fallible functions, errors are propagated to Java by throwing and
+//! returning a type-appropriate default value. Core `IsccError`s become
+//! `io.iscc.iscc_lib.IsccException` (a subclass of `IllegalArgumentException`
+//! exposing the stable code via `getCode()`) through `throw_iscc_error`;
+//! binding-level failures use `IllegalArgumentException` via
+//! `throw_and_default`.
 //!
 //! ## Streaming hashers
 //!
-//! `DataHasher` and `InstanceHasher` use the opaque-pointer-as-jlong pattern:
+//! `DataHasher`, `InstanceHasher`, and `SumHasher` use the opaque-pointer-as-jlong pattern:
 //! `new()` allocates via `Box::into_raw()` and returns the pointer as `jlong`,
 //! `update()`/`finalize()` cast back, and `free()` reclaims via `Box::from_raw()`.
 
 use jni::JNIEnv;
-use jni::objects::{JByteArray, JClass, JIntArray, JObject, JObjectArray, JString};
+use jni::objects::{
+    JByteArray, JClass, JIntArray, JObject, JObjectArray, JString, JThrowable, JValue,
+};
 use jni::sys::{jboolean, jbyteArray, jint, jintArray, jlong, jobject, jobjectArray, jstring};
 
 /// Throw `IllegalArgumentException` in Java and return a type-appropriate default.
@@ -35,6 +40,31 @@ fn throw_and_default<T: Default>(env: &mut JNIEnv, msg: &str) -> T {
     T::default()
 }
 
+/// Throw `IsccException` carrying the stable error code and return a
+/// type-appropriate default.
+///
+/// `IsccException` extends `IllegalArgumentException`, so callers catching
+/// the latter keep working. Falls back to a plain `IllegalArgumentException`
+/// if the exception object cannot be constructed.
+fn throw_iscc_error<T: Default>(env: &mut JNIEnv, err: &iscc_lib::IsccError) -> T {
+    let msg = err.to_string();
+    let thrown = (|| -> jni::errors::Result<()> {
+        let code = env.new_string(err.code())?;
+        let message = env.new_string(&msg)?;
+        let exception = env.new_object(
+            "io/iscc/iscc_lib/IsccException",
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+            &[JValue::Object(&code), JValue::Object(&message)],
+        )?;
+        env.throw(JThrowable::from(exception))
+    })();
+    if thrown.is_err() {
+        let _ = env.exception_clear();
+        return throw_and_default(env, &msg);
+    }
+    T::default()
+}
+
 /// Throw `IllegalStateException` in Java and return a type-appropriate default.
 ///
 /// Used for operations invalid in the current object state (e.g., calling
@@ -198,7 +228,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genMetaCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -221,7 +251,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genTextCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -244,7 +274,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genImageCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -268,7 +298,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genAudioCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -292,7 +322,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genVideoCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -317,7 +347,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genMixedCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -340,7 +370,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genDataCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -363,7 +393,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genInstanceCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -388,7 +418,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genIsccCodeV0(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -412,37 +442,111 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genSumCodeV0(
         Ok(s) => s.into(),
         Err(e) => return throw_and_default(&mut env, &e.to_string()),
     };
-    let result = match iscc_lib::gen_sum_code_v0(
+    match iscc_lib::gen_sum_code_v0(
         std::path::Path::new(&path_str),
         bits as u32,
         wide != 0,
         add_units != 0,
     ) {
-        Ok(r) => r,
+        Ok(result) => build_sum_code_result(&mut env, result),
+        Err(e) => throw_iscc_error(&mut env, &e),
+    }
+}
+
+/// Generate an ISCC-SUM code from a file path with progress and cancellation.
+///
+/// Calls `listener.onProgress(done, total)` after each read block (`total` is
+/// 0 when the size is unknown) and checks the token behind `cancel_ptr`
+/// before each one. A null listener or zero handle disables that part.
+/// Throws `IsccException` with code `cancelled` if the token is cancelled.
+/// An exception thrown by the listener stops hashing and is rethrown.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genSumCodeV0WithProgress(
+    mut env: JNIEnv,
+    _class: JClass,
+    path: JString,
+    bits: jint,
+    wide: jboolean,
+    add_units: jboolean,
+    listener: JObject,
+    cancel_ptr: jlong,
+) -> jobject {
+    let path_str: String = match env.get_string(&path) {
+        Ok(s) => s.into(),
         Err(e) => return throw_and_default(&mut env, &e.to_string()),
     };
+    let caller = (cancel_ptr != 0).then(|| {
+        // SAFETY: cancel_ptr was produced by Box::into_raw() in cancelTokenNew
+        unsafe { &*(cancel_ptr as *const iscc_lib::CancelToken) }.clone()
+    });
+    // A separate token lets a failing listener stop hashing without
+    // cancelling the caller's token; the caller's cancellation is forwarded.
+    let token = iscc_lib::CancelToken::new();
+    let forward = |token: &iscc_lib::CancelToken| {
+        if caller
+            .as_ref()
+            .is_some_and(iscc_lib::CancelToken::is_cancelled)
+        {
+            token.cancel();
+        }
+    };
+    forward(&token);
+    let result = iscc_lib::gen_sum_code_with_progress(
+        std::path::Path::new(&path_str),
+        bits as u32,
+        wide != 0,
+        add_units != 0,
+        |done, total| {
+            forward(&token);
+            if listener.is_null() || token.is_cancelled() {
+                return;
+            }
+            let called = env.call_method(
+                &listener,
+                "onProgress",
+                "(JJ)V",
+                &[JValue::Long(done as jlong), JValue::Long(total as jlong)],
+            );
+            if called.is_err() {
+                token.cancel();
+            }
+        },
+        Some(&token),
+    );
+    // Leave the listener's exception pending rather than replacing it.
+    if env.exception_check().unwrap_or(false) {
+        return std::ptr::null_mut();
+    }
+    match result {
+        Ok(result) => build_sum_code_result(&mut env, result),
+        Err(e) => throw_iscc_error(&mut env, &e),
+    }
+}
+
+/// Build a Java `SumCodeResult` object from an ISCC-SUM result.
+fn build_sum_code_result(env: &mut JNIEnv, result: iscc_lib::SumCodeResult) -> jobject {
     let iscc_jstr = match env.new_string(&result.iscc) {
         Ok(s) => s,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_and_default(env, &e.to_string()),
     };
     let datahash_jstr = match env.new_string(&result.datahash) {
         Ok(s) => s,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_and_default(env, &e.to_string()),
     };
     // Convert units: Some(Vec<String>) → jobjectArray, None → null
     let units_obj = match result.units {
-        Some(units) => match build_string_array(&mut env, &units) {
+        Some(units) => match build_string_array(env, &units) {
             Ok(arr) => {
                 // SAFETY: arr is a valid jobjectArray from build_string_array
                 unsafe { JObject::from_raw(arr) }
             }
-            Err(e) => return throw_and_default(&mut env, &e),
+            Err(e) => return throw_and_default(env, &e),
         },
         None => JObject::null(),
     };
     let class = match env.find_class("io/iscc/iscc_lib/SumCodeResult") {
         Ok(c) => c,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_and_default(env, &e.to_string()),
     };
     match env.new_object(
         class,
@@ -455,7 +559,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_genSumCodeV0(
         ],
     ) {
         Ok(obj) => obj.into_raw(),
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_and_default(env, &e.to_string()),
     }
 }
 
@@ -592,7 +696,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_jsonToDataUrl(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -641,7 +745,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_encodeComponent(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -663,7 +767,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_isccDecode(
     };
     let (mt, st, vs, li, digest) = match iscc_lib::iscc_decode(&iscc_str) {
         Ok(result) => result,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_iscc_error(&mut env, &e),
     };
     // Build a jbyteArray from the digest Vec<u8>
     let byte_array = match env.byte_array_from_slice(&digest) {
@@ -707,7 +811,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_isccDecompose(
     };
     let units = match iscc_lib::iscc_decompose(&code_str) {
         Ok(u) => u,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_iscc_error(&mut env, &e),
     };
     match build_string_array(&mut env, &units) {
         Ok(arr) => arr,
@@ -737,7 +841,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_slidingWindow(
     };
     let ngrams = match iscc_lib::sliding_window(&seq_str, width as usize) {
         Ok(v) => v,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_iscc_error(&mut env, &e),
     };
     match build_string_array(&mut env, &ngrams) {
         Ok(arr) => arr,
@@ -785,7 +889,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_algSimhash(
             Ok(a) => a.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -836,7 +940,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_algCdcChunks(
     };
     let chunks = match iscc_lib::alg_cdc_chunks(&bytes, utf32 != 0, avg_chunk_size as u32) {
         Ok(c) => c,
-        Err(e) => return throw_and_default(&mut env, &e.to_string()),
+        Err(e) => return throw_iscc_error(&mut env, &e),
     };
     let byte_array_class = match env.find_class("[B") {
         Ok(c) => c,
@@ -884,7 +988,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_softHashVideoV0(
             Ok(a) => a.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -900,6 +1004,11 @@ struct JniInstanceHasher {
     inner: Option<iscc_lib::InstanceHasher>,
 }
 
+/// JNI wrapper around `iscc_lib::SumHasher` with finalize-once semantics.
+struct JniSumHasher {
+    inner: Option<iscc_lib::SumHasher>,
+}
+
 /// Create a new streaming Data-Code hasher.
 ///
 /// Returns an opaque `jlong` handle. The caller must eventually call
@@ -963,7 +1072,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_dataHasherFinalize(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -1045,7 +1154,7 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_instanceHasherFinalize(
             Ok(s) => s.into_raw(),
             Err(e) => throw_and_default(&mut env, &e.to_string()),
         },
-        Err(e) => throw_and_default(&mut env, &e.to_string()),
+        Err(e) => throw_iscc_error(&mut env, &e),
     }
 }
 
@@ -1063,3 +1172,145 @@ pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_instanceHasherFree(
         drop(unsafe { Box::from_raw(ptr as *mut JniInstanceHasher) });
     }
 }
+
+/// Create a new streaming ISCC-SUM hasher.
+///
+/// Returns an opaque `jlong` handle. The caller must eventually call
+/// `sumHasherFree` to release the memory.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_sumHasherNew(
+    _env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    let wrapper = Box::new(JniSumHasher {
+        inner: Some(iscc_lib::SumHasher::new()),
+    });
+    Box::into_raw(wrapper) as jlong
+}
+
+/// Push data into a streaming SumHasher.
+///
+/// Throws `IllegalStateException` if the hasher has already been finalized.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_sumHasherUpdate(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    data: jbyteArray,
+) {
+    let bytes = match extract_byte_array(&env, data) {
+        Ok(b) => b,
+        Err(e) => {
+            throw_and_default::<()>(&mut env, &e);
+            return;
+        }
+    };
+    // SAFETY: ptr was produced by Box::into_raw() in sumHasherNew
+    let wrapper = unsafe { &mut *(ptr as *mut JniSumHasher) };
+    let Some(inner) = wrapper.inner.as_mut() else {
+        throw_state_error::<()>(&mut env, "SumHasher already finalized");
+        return;
+    };
+    inner.update(&bytes);
+}
+
+/// Finalize a streaming SumHasher and return a `SumCodeResult`.
+///
+/// Consumes the inner hasher state. After this call, subsequent `update`
+/// or `finalize` calls will throw. The caller must still call
+/// `sumHasherFree` to release the wrapper.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_sumHasherFinalize(
+    mut env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+    bits: jint,
+    wide: jboolean,
+    add_units: jboolean,
+) -> jobject {
+    // SAFETY: ptr was produced by Box::into_raw() in sumHasherNew
+    let wrapper = unsafe { &mut *(ptr as *mut JniSumHasher) };
+    let Some(inner) = wrapper.inner.take() else {
+        return throw_state_error(&mut env, "SumHasher already finalized");
+    };
+    match inner.finalize(bits as u32, wide != 0, add_units != 0) {
+        Ok(result) => build_sum_code_result(&mut env, result),
+        Err(e) => throw_iscc_error(&mut env, &e),
+    }
+}
+
+/// Free a SumHasher previously created by `sumHasherNew`.
+///
+/// Zero/null handle is a no-op. Each handle must be freed exactly once.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_sumHasherFree(
+    _env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) {
+    if ptr != 0 {
+        // SAFETY: ptr was produced by Box::into_raw() in sumHasherNew
+        drop(unsafe { Box::from_raw(ptr as *mut JniSumHasher) });
+    }
+}
+
+// ── Cancellation ────────────────────────────────────────────────────────────
+
+/// Create a new cancellation token.
+///
+/// Returns an opaque `jlong` handle. The caller must eventually call
+/// `cancelTokenFree` to release the memory.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_cancelTokenNew(
+    _env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    Box::into_raw(Box::new(iscc_lib::CancelToken::new())) as jlong
+}
+
+/// Cancel every operation using the token. Safe to call from any thread.
+///
+/// Zero/null handle is a no-op.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_cancelTokenCancel(
+    _env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) {
+    if ptr != 0 {
+        // SAFETY: ptr was produced by Box::into_raw() in cancelTokenNew
+        unsafe { &*(ptr as *const iscc_lib::CancelToken) }.cancel();
+    }
+}
+
+/// Whether the token has been cancelled.
+///
+/// Returns false for a zero/null handle.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_cancelTokenIsCancelled(
+    _env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) -> jboolean {
+    if ptr == 0 {
+        return 0;
+    }
+    // SAFETY: ptr was produced by Box::into_raw() in cancelTokenNew
+    unsafe { &*(ptr as *const iscc_lib::CancelToken) }.is_cancelled() as jboolean
+}
+
+/// Free a token previously created by `cancelTokenNew`.
+///
+/// Zero/null handle is a no-op. Each handle must be freed exactly once, and
+/// not while an operation is still using it.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_io_iscc_iscc_1lib_IsccLib_cancelTokenFree(
+    _env: JNIEnv,
+    _class: JClass,
+    ptr: jlong,
+) {
+    if ptr != 0 {
+        // SAFETY: ptr was produced by Box::into_raw() in cancelTokenNew
+        drop(unsafe { Box::from_raw(ptr as *mut iscc_lib::CancelToken) });
+    }
+}
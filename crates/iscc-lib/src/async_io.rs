@@ -0,0 +1,168 @@
+//! Tokio integration for the streaming hashers (`tokio` feature).
+//!
+//! `DataHasher`, `InstanceHasher` and `SumHasher` implement `AsyncWrite` so
+//! they can be the target of `tokio::io::copy`. Their `poll_write` hashes on
+//! the calling task, which is fine for small writes. For whole streams,
+//! `gen_sum_code_from_async_reader` reads on the async task and hashes on a
+//! blocking thread, keeping the CPU-bound work off the executor.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+use tokio::sync::mpsc;
+
+use crate::streaming::{DataHasher, InstanceHasher, SumHasher};
+use crate::types::SumCodeResult;
+use crate::{IO_READ_SIZE, IsccError, IsccResult};
+
+macro_rules! impl_async_write {
+    ($hasher:ty) => {
+        impl AsyncWrite for $hasher {
+            /// Push `buf` into the hasher. Never fails and always consumes all bytes.
+            fn poll_write(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                self.get_mut().update(buf);
+                Poll::Ready(Ok(buf.len()))
+            }
+
+            fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+        }
+    };
+}
+
+impl_async_write!(DataHasher);
+impl_async_write!(InstanceHasher);
+impl_async_write!(SumHasher);
+
+/// Generate a composite ISCC-CODE from everything an async reader yields.
+///
+/// Reads `reader` to its end in `IO_READ_SIZE` buffers on the current task
+/// and hands each full buffer to a [`SumHasher`] on a blocking thread
+/// (`tokio::task::spawn_blocking`), so reading the next buffer overlaps with
+/// hashing the previous one. The result is identical to
+/// `gen_sum_code_from_reader` over the same bytes.
+///
+/// Must be called within a Tokio runtime.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if reading fails or the hashing task is
+/// cancelled by a runtime shutdown.
+pub async fn gen_sum_code_from_async_reader<R: AsyncRead + Unpin>(
+    mut reader: R,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+) -> IsccResult<SumCodeResult> {
+    // Filled buffers go to the hasher, hashed ones come back for reuse.
+    let (full_tx, mut full_rx) = mpsc::channel::<Vec<u8>>(1);
+    let (spare_tx, mut spare_rx) = mpsc::channel::<Vec<u8>>(2);
+
+    let worker = tokio::task::spawn_blocking(move || {
+        let mut hasher = SumHasher::new();
+        while let Some(buf) = full_rx.blocking_recv() {
+            hasher.update(&buf);
+            let _ = spare_tx.try_send(buf);
+        }
+        hasher.finalize(bits, wide, add_units)
+    });
+
+    loop {
+        let mut buf = spare_rx.try_recv().unwrap_or_default();
+        buf.resize(IO_READ_SIZE, 0);
+        let filled = fill_buf(&mut reader, &mut buf).await?;
+        if filled == 0 {
+            break;
+        }
+        buf.truncate(filled);
+        if full_tx.send(buf).await.is_err() {
+            break;
+        }
+    }
+    drop(full_tx);
+
+    match worker.await {
+        Ok(result) => result,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => Err(IsccError::Io(io::Error::other(err))),
+    }
+}
+
+/// Read into `buf` until it is full or the reader is exhausted.
+async fn fill_buf<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]).await {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen_sum_code_from_reader;
+
+    fn sample(len: u32) -> Vec<u8> {
+        (0..len).map(|i| (i.wrapping_mul(613) >> 5) as u8).collect()
+    }
+
+    #[tokio::test]
+    async fn test_gen_sum_code_from_async_reader_matches_sync() {
+        for len in [0, 1, 5000, IO_READ_SIZE as u32 + 4321] {
+            let data = sample(len);
+            let expected = gen_sum_code_from_reader(data.as_slice(), 64, false, true).unwrap();
+            let result = gen_sum_code_from_async_reader(data.as_slice(), 64, false, true)
+                .await
+                .unwrap();
+            assert_eq!(result.iscc, expected.iscc, "len={len}");
+            assert_eq!(result.datahash, expected.datahash);
+            assert_eq!(result.filesize, expected.filesize);
+            assert_eq!(result.units, expected.units);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gen_sum_code_from_async_reader_read_error() {
+        struct FailingReader;
+        impl AsyncRead for FailingReader {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                _buf: &mut tokio::io::ReadBuf<'_>,
+            ) -> Poll<io::Result<()>> {
+                Poll::Ready(Err(io::Error::other("connection reset")))
+            }
+        }
+        let result = gen_sum_code_from_async_reader(FailingReader, 64, false, false).await;
+        assert!(matches!(result, Err(IsccError::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn test_hashers_as_async_write() {
+        let data = sample(20_000);
+        let mut hasher = SumHasher::new();
+        let copied = tokio::io::copy(&mut data.as_slice(), &mut hasher)
+            .await
+            .unwrap();
+        assert_eq!(copied, data.len() as u64);
+        let result = hasher.finalize(64, false, false).unwrap();
+        let expected = gen_sum_code_from_reader(data.as_slice(), 64, false, false).unwrap();
+        assert_eq!(result.iscc, expected.iscc);
+    }
+}
@@ -220,6 +220,109 @@ pub(crate) fn alg_cdc_chunks_unchecked(
     chunks
 }
 
+/// Minimum input size in bytes for the parallel chunker to pay off.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_LEN: usize = 1 << 20;
+
+/// Segment length in bytes for speculative parallel chunking.
+#[cfg(feature = "parallel")]
+const PARALLEL_SEGMENT_LEN: usize = 1 << 16;
+
+/// Split data into Data-Code chunks (`DATA_AVG_CHUNK_SIZE`, no UTF-32).
+///
+/// With the `parallel` feature, large inputs are chunked with
+/// `alg_cdc_chunks_parallel`, which yields the same chunks.
+pub(crate) fn data_chunks(data: &[u8]) -> Vec<&[u8]> {
+    #[cfg(feature = "parallel")]
+    if data.len() >= PARALLEL_MIN_LEN {
+        return alg_cdc_chunks_parallel(data, DATA_AVG_CHUNK_SIZE, PARALLEL_SEGMENT_LEN);
+    }
+    alg_cdc_chunks_unchecked(data, false, DATA_AVG_CHUNK_SIZE)
+}
+
+/// Length of the first Data-Code chunk of `data`.
+pub(crate) fn data_cut(data: &[u8]) -> usize {
+    let (mi, ma, cs, mask_s, mask_l) = alg_cdc_params(DATA_AVG_CHUNK_SIZE);
+    alg_cdc_offset(data, mi, ma, cs, mask_s, mask_l)
+}
+
+/// Hash chunks into Data-Code features (xxh32, seed 0).
+///
+/// With the `parallel` feature, long chunk lists are hashed on the rayon
+/// thread pool.
+pub(crate) fn chunk_features(chunks: &[&[u8]]) -> Vec<u32> {
+    #[cfg(feature = "parallel")]
+    if chunks.len() >= PARALLEL_MIN_LEN / DATA_AVG_CHUNK_SIZE as usize {
+        use rayon::prelude::*;
+        return chunks
+            .par_iter()
+            .with_min_len(256)
+            .map(|chunk| xxhash_rust::xxh32::xxh32(chunk, 0))
+            .collect();
+    }
+    chunks
+        .iter()
+        .map(|chunk| xxhash_rust::xxh32::xxh32(chunk, 0))
+        .collect()
+}
+
+/// Split data into content-defined chunks on the rayon thread pool.
+///
+/// A cut point depends only on the bytes from the previous cut onwards, so
+/// each `segment_len` segment is chunked speculatively from its own start
+/// in parallel. The segments are then stitched in order: the true chunking
+/// is continued serially from the previous segment until it lands on one of
+/// the segment's speculative cut points, after which the two coincide. The
+/// result is identical to `alg_cdc_chunks_unchecked(data, false, ..)`.
+#[cfg(feature = "parallel")]
+pub(crate) fn alg_cdc_chunks_parallel(
+    data: &[u8],
+    avg_chunk_size: u32,
+    segment_len: usize,
+) -> Vec<&[u8]> {
+    use rayon::prelude::*;
+
+    if data.is_empty() {
+        return vec![&data[0..0]];
+    }
+    let (mi, ma, cs, mask_s, mask_l) = alg_cdc_params(avg_chunk_size);
+    let next_cut = |pos: usize| pos + alg_cdc_offset(&data[pos..], mi, ma, cs, mask_s, mask_l);
+
+    // Speculative cut positions per segment, starting with the segment start
+    // and running until the first cut at or past the segment end.
+    let segment_len = segment_len.max(1);
+    let speculative: Vec<Vec<usize>> = (0..data.len().div_ceil(segment_len))
+        .into_par_iter()
+        .map(|k| {
+            let end = ((k + 1) * segment_len).min(data.len());
+            let mut cuts = vec![k * segment_len];
+            let mut pos = k * segment_len;
+            while pos < end {
+                pos = next_cut(pos);
+                cuts.push(pos);
+            }
+            cuts
+        })
+        .collect();
+
+    let mut cuts = vec![0];
+    let mut pos = 0;
+    for segment in &speculative {
+        let end = *segment.last().unwrap_or(&pos);
+        while pos < end {
+            if let Ok(i) = segment.binary_search(&pos) {
+                cuts.extend_from_slice(&segment[i + 1..]);
+                pos = end;
+                break;
+            }
+            pos = next_cut(pos);
+            cuts.push(pos);
+        }
+    }
+
+    cuts.windows(2).map(|w| &data[w[0]..w[1]]).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -391,4 +494,33 @@ mod tests {
         let reassembled: Vec<u8> = chunks.iter().flat_map(|c| c.iter().copied()).collect();
         assert_eq!(reassembled, b"hello world");
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_alg_cdc_chunks_parallel_matches_serial() {
+        let mut state = 0x2545_f491_u32;
+        let mut data: Vec<u8> = (0..300_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+        // Runs of zeros force max-size chunks across segment borders.
+        data[100_000..140_000].fill(0);
+
+        for len in [0, 1, 5000, 65_536, 300_000] {
+            let serial = alg_cdc_chunks_unchecked(&data[..len], false, DATA_AVG_CHUNK_SIZE);
+            for segment_len in [1, 700, 4096, 65_536, 1 << 20] {
+                let parallel =
+                    alg_cdc_chunks_parallel(&data[..len], DATA_AVG_CHUNK_SIZE, segment_len);
+                assert_eq!(parallel, serial, "len={len} segment_len={segment_len}");
+            }
+        }
+        assert_eq!(
+            data_chunks(&data),
+            alg_cdc_chunks_unchecked(&data, false, DATA_AVG_CHUNK_SIZE)
+        );
+    }
 }
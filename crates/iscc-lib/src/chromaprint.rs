@@ -0,0 +1,287 @@
+//! Chromaprint fingerprint decoding.
+//!
+//! `fpcalc` and most Chromaprint bindings emit fingerprints in the compressed
+//! format: a 4-byte header (algorithm id and 24-bit big-endian item count)
+//! followed by the XOR deltas of consecutive items, each written as the gaps
+//! between its set bits. Gaps are packed as 3-bit values with a 0 ending each
+//! item; gaps of 7 or more are written as 7 and the remainder goes to a
+//! 5-bit packed exception section that follows. The whole is encoded as
+//! base64url without padding.
+
+use data_encoding::BASE64URL_NOPAD;
+
+use crate::{IsccError, IsccResult};
+
+/// Size of the algorithm id and item count header.
+const HEADER_SIZE: usize = 4;
+
+/// Highest Chromaprint algorithm id (`CHROMAPRINT_ALGORITHM_TEST5`).
+const MAX_ALGORITHM: u8 = 4;
+
+/// Largest gap stored in the 3-bit section; larger gaps carry an exception.
+const MAX_NORMAL_VALUE: u8 = 7;
+
+/// Decode a compressed base64 Chromaprint fingerprint into its raw values.
+///
+/// Accepts the string printed by `fpcalc` without `-raw` (trailing `=`
+/// padding is tolerated). The values are returned as the signed integers
+/// `gen_audio_code_v0` expects.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if the string is not valid base64url,
+/// names an unknown algorithm, or is truncated or corrupt.
+pub fn decode_chromaprint(fp: &str) -> IsccResult<Vec<i32>> {
+    let data = BASE64URL_NOPAD
+        .decode(fp.trim().trim_end_matches('=').as_bytes())
+        .map_err(|e| invalid(format!("bad base64: {e}")))?;
+    decode_compressed(&data)
+}
+
+/// Extract the raw values from any `fpcalc` output.
+///
+/// Handles the compressed fingerprint alone, a comma-separated list of
+/// integers (`-raw`), the `KEY=value` text output, and the `-json` output
+/// with either form of `fingerprint`.
+pub(crate) fn parse_fingerprint(input: &str) -> IsccResult<Vec<i32>> {
+    let input = input.trim();
+    if input.starts_with('{') {
+        let json: serde_json::Value = serde_json::from_str(input)?;
+        return match json.get("fingerprint") {
+            Some(serde_json::Value::String(fp)) => parse_value(fp),
+            Some(serde_json::Value::Array(items)) => items
+                .iter()
+                .map(|item| item.as_i64().ok_or(()).and_then(to_i32))
+                .collect::<Result<_, _>>()
+                .map_err(|_| invalid("fingerprint array must hold 32-bit integers")),
+            _ => Err(invalid("fpcalc JSON has no fingerprint")),
+        };
+    }
+    let value = input
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("FINGERPRINT="))
+        .unwrap_or(input);
+    parse_value(value)
+}
+
+/// Parse a fingerprint that is either raw integers or compressed base64.
+fn parse_value(value: &str) -> IsccResult<Vec<i32>> {
+    let is_raw = value
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == ',' || c == '-' || c.is_whitespace());
+    if !is_raw {
+        return decode_chromaprint(value);
+    }
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            item.parse::<i64>()
+                .map_err(|_| ())
+                .and_then(to_i32)
+                .map_err(|_| invalid(format!("bad raw value {item:?}")))
+        })
+        .collect()
+}
+
+/// Accept both the signed and the unsigned rendering of a 32-bit value.
+fn to_i32(value: i64) -> Result<i32, ()> {
+    if (i64::from(i32::MIN)..=i64::from(u32::MAX)).contains(&value) {
+        Ok(value as u32 as i32)
+    } else {
+        Err(())
+    }
+}
+
+/// Decode the binary compressed fingerprint.
+fn decode_compressed(data: &[u8]) -> IsccResult<Vec<i32>> {
+    if data.len() < HEADER_SIZE {
+        return Err(invalid("shorter than the 4-byte header"));
+    }
+    let algorithm = data[0];
+    if algorithm > MAX_ALGORITHM {
+        return Err(invalid(format!("unknown algorithm {algorithm}")));
+    }
+    let count = u32::from_be_bytes([0, data[1], data[2], data[3]]) as usize;
+    let packed = &data[HEADER_SIZE..];
+
+    // Read 3-bit gaps until `count` items have been terminated.
+    let mut gaps = Vec::new();
+    let mut items = 0;
+    let mut exceptions = 0usize;
+    if count > 0 {
+        for gap in unpack(packed, 3) {
+            gaps.push(gap);
+            if gap == 0 {
+                items += 1;
+                if items == count {
+                    break;
+                }
+            } else if gap == MAX_NORMAL_VALUE {
+                exceptions += 1;
+            }
+        }
+    }
+    if items != count {
+        return Err(invalid(format!("expected {count} items, found {items}")));
+    }
+
+    let rest = &packed[(gaps.len() * 3).div_ceil(8)..];
+    if rest.len() < (exceptions * 5).div_ceil(8) {
+        return Err(invalid("exception section is truncated"));
+    }
+    let mut extra = unpack(rest, 5);
+
+    let mut values = Vec::with_capacity(count);
+    let (mut prev, mut value, mut last_bit) = (0u32, 0u32, 0u32);
+    for gap in gaps {
+        if gap == 0 {
+            prev ^= value;
+            values.push(prev as i32);
+            value = 0;
+            last_bit = 0;
+            continue;
+        }
+        last_bit += u32::from(gap);
+        if gap == MAX_NORMAL_VALUE {
+            last_bit += u32::from(extra.next().unwrap_or(0));
+        }
+        if last_bit > 32 {
+            return Err(invalid("bit position beyond 32"));
+        }
+        value |= 1 << (last_bit - 1);
+    }
+    Ok(values)
+}
+
+/// Unpack `width`-bit values stored least significant bit first.
+fn unpack(data: &[u8], width: usize) -> impl Iterator<Item = u8> + '_ {
+    let mask = (1u16 << width) - 1;
+    (0..data.len() * 8 / width).map(move |i| {
+        let pos = i * width;
+        let lo = u16::from(data[pos / 8]);
+        let hi = u16::from(data.get(pos / 8 + 1).copied().unwrap_or(0));
+        (((hi << 8 | lo) >> (pos % 8)) & mask) as u8
+    })
+}
+
+fn invalid(msg: impl std::fmt::Display) -> IsccError {
+    IsccError::InvalidInput(format!("invalid Chromaprint fingerprint: {msg}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compress `values` the way Chromaprint's `FingerprintCompressor` does.
+    fn compress(algorithm: u8, values: &[i32]) -> String {
+        fn pack(values: &[u32], width: usize) -> Vec<u8> {
+            let mut out = vec![0u8; (values.len() * width).div_ceil(8)];
+            for (i, &v) in values.iter().enumerate() {
+                for b in 0..width {
+                    if v >> b & 1 == 1 {
+                        let pos = i * width + b;
+                        out[pos / 8] |= 1 << (pos % 8);
+                    }
+                }
+            }
+            out
+        }
+        let (mut normal, mut extra, mut prev) = (Vec::new(), Vec::new(), 0u32);
+        for &v in values {
+            let delta = v as u32 ^ prev;
+            prev = v as u32;
+            let mut last = 0;
+            for bit in (1..=32).filter(|bit| delta >> (bit - 1) & 1 == 1) {
+                let gap = bit - last;
+                last = bit;
+                normal.push(gap.min(7));
+                if gap >= 7 {
+                    extra.push(gap - 7);
+                }
+            }
+            normal.push(0);
+        }
+        let mut data = vec![algorithm];
+        data.extend_from_slice(&(values.len() as u32).to_be_bytes()[1..]);
+        data.extend(pack(&normal, 3));
+        data.extend(pack(&extra, 5));
+        BASE64URL_NOPAD.encode(&data)
+    }
+
+    fn decode_bytes(data: &[u8]) -> IsccResult<Vec<i32>> {
+        decode_chromaprint(&BASE64URL_NOPAD.encode(data))
+    }
+
+    #[test]
+    fn test_decode_chromaprint_reference_vectors() {
+        // Cases from Chromaprint's fingerprint_decompressor tests.
+        assert_eq!(decode_bytes(&[0, 0, 0, 1, 1]).unwrap(), vec![1]);
+        assert_eq!(decode_bytes(&[0, 0, 0, 1, 73, 0]).unwrap(), vec![7]);
+        assert_eq!(decode_bytes(&[0, 0, 0, 1, 7, 0]).unwrap(), vec![1 << 6]);
+        assert_eq!(decode_bytes(&[0, 0, 0, 1, 7, 2]).unwrap(), vec![1 << 8]);
+        assert_eq!(decode_bytes(&[0, 0, 0, 2, 65, 0]).unwrap(), vec![1, 0]);
+        assert_eq!(decode_bytes(&[0, 0, 0, 2, 1, 0]).unwrap(), vec![1, 1]);
+    }
+
+    #[test]
+    fn test_decode_chromaprint_roundtrip() {
+        let values: Vec<i32> = (0..300u32)
+            .map(|i| i.wrapping_mul(2_654_435_761).rotate_left(i % 32) as i32)
+            .chain([0, -1, i32::MIN, i32::MAX, i32::MIN >> 3])
+            .collect();
+        let fp = compress(1, &values);
+        assert_eq!(decode_chromaprint(&fp).unwrap(), values);
+        assert_eq!(decode_chromaprint(&format!("{fp}==\n")).unwrap(), values);
+        assert_eq!(
+            decode_chromaprint(&compress(1, &[])).unwrap(),
+            Vec::<i32>::new()
+        );
+    }
+
+    #[test]
+    fn test_decode_chromaprint_invalid() {
+        let fp = compress(1, &[-1, 12345, 1 << 30]);
+        for bad in [
+            "",
+            "AQA",
+            "not base64!",
+            &fp[..fp.len() - 3],
+            &compress(9, &[1]),
+        ] {
+            assert!(
+                matches!(decode_chromaprint(bad), Err(IsccError::InvalidInput(_))),
+                "{bad:?}"
+            );
+        }
+        // An exception pushing the bit position past 32.
+        assert!(decode_bytes(&[0, 0, 0, 1, 7, 31]).is_err());
+    }
+
+    #[test]
+    fn test_parse_fingerprint_fpcalc_outputs() {
+        let values = vec![-1, 0, 123_456, i32::MIN];
+        let fp = compress(1, &values);
+        let unsigned = "4294967295,0,123456,2147483648";
+        let signed = "-1, 0, 123456, -2147483648";
+        let inputs = [
+            fp.clone(),
+            format!("FILE=song.mp3\nDURATION=215\nFINGERPRINT={fp}\n"),
+            format!("DURATION=215\nFINGERPRINT={unsigned}"),
+            signed.to_string(),
+            format!(r#"{{"duration": 215.4, "fingerprint": "{fp}"}}"#),
+            format!(r#"{{"duration": 215.4, "fingerprint": [{signed}]}}"#),
+            format!(r#"{{"duration": 215.4, "fingerprint": [{unsigned}]}}"#),
+        ];
+        for input in &inputs {
+            assert_eq!(parse_fingerprint(input).unwrap(), values, "{input}");
+        }
+        assert!(parse_fingerprint("1,2,4294967296").is_err());
+        assert!(parse_fingerprint(r#"{"duration": 1}"#).is_err());
+        assert!(matches!(
+            parse_fingerprint("{not json"),
+            Err(IsccError::InvalidJson(_))
+        ));
+    }
+}
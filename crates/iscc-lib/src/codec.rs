@@ -4,6 +4,10 @@
 //! depend on to produce ISCC-encoded output strings. This is a Tier 2 module —
 //! available to Rust consumers but not exposed through FFI bindings.
 
+use std::fmt;
+use std::str::FromStr;
+
+use crate::validate::iscc_clean;
 use crate::{IsccError, IsccResult};
 
 // ---- Type Enums ----
@@ -12,7 +16,7 @@ use crate::{IsccError, IsccResult};
 ///
 /// Integer values match the `iscc-core` Python reference (MT enum).
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum MainType {
     Meta = 0,
     Semantic = 1,
@@ -37,9 +41,7 @@ impl TryFrom<u8> for MainType {
             5 => Ok(Self::Iscc),
             6 => Ok(Self::Id),
             7 => Ok(Self::Flake),
-            _ => Err(IsccError::InvalidInput(format!(
-                "invalid MainType: {value}"
-            ))),
+            _ => Err(IsccError::Header(format!("invalid MainType: {value}"))),
         }
     }
 }
@@ -50,7 +52,7 @@ impl TryFrom<u8> for MainType {
 /// The interpretation depends on the MainType context. Integer values
 /// match the `iscc-core` Python reference.
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SubType {
     /// No specific subtype (general) / Text content (ST_CC context).
     None = 0,
@@ -88,14 +90,14 @@ impl TryFrom<u8> for SubType {
             5 => Ok(Self::Sum),
             6 => Ok(Self::IsccNone),
             7 => Ok(Self::Wide),
-            _ => Err(IsccError::InvalidInput(format!("invalid SubType: {value}"))),
+            _ => Err(IsccError::Header(format!("invalid SubType: {value}"))),
         }
     }
 }
 
 /// ISCC version identifier.
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Version {
     V0 = 0,
 }
@@ -106,7 +108,35 @@ impl TryFrom<u8> for Version {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(Self::V0),
-            _ => Err(IsccError::InvalidInput(format!("invalid Version: {value}"))),
+            _ => Err(IsccError::Unsupported(format!("ISCC Version {value}"))),
+        }
+    }
+}
+
+/// ISCC-ID chain identifier.
+///
+/// Stored in the SubType field of `MainType::Id` headers. Integer values
+/// match the `iscc-core` Python reference (ST_ID enum).
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Chain {
+    /// Private or unregistered ledger.
+    Private = 0,
+    Bitcoin = 1,
+    Ethereum = 2,
+    Polygon = 3,
+}
+
+impl TryFrom<u8> for Chain {
+    type Error = IsccError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Private),
+            1 => Ok(Self::Bitcoin),
+            2 => Ok(Self::Ethereum),
+            3 => Ok(Self::Polygon),
+            _ => Err(IsccError::Header(format!("invalid Chain: {value}"))),
         }
     }
 }
@@ -192,7 +222,7 @@ fn encode_varnibble(value: u32) -> IsccResult<Vec<bool>> {
             bits.extend((0..12).rev().map(|i| (v >> i) & 1 == 1));
             Ok(bits)
         }
-        _ => Err(IsccError::InvalidInput(format!(
+        _ => Err(IsccError::Header(format!(
             "varnibble value out of range (0-4679): {value}"
         ))),
     }
@@ -206,9 +236,7 @@ fn encode_varnibble(value: u32) -> IsccResult<Vec<bool>> {
 fn decode_varnibble_from_bytes(data: &[u8], bit_pos: usize) -> IsccResult<(u32, usize)> {
     let available = data.len() * 8 - bit_pos;
     if available < 4 {
-        return Err(IsccError::InvalidInput(
-            "insufficient bits for varnibble".into(),
-        ));
+        return Err(IsccError::Header("insufficient bits for varnibble".into()));
     }
 
     if !get_bit(data, bit_pos) {
@@ -224,12 +252,56 @@ fn decode_varnibble_from_bytes(data: &[u8], bit_pos: usize) -> IsccResult<(u32,
         // 1110xxxxxxxxxxxx — 16 bits, values 584–4679
         Ok((extract_bits(data, bit_pos + 4, 12) + 584, 16))
     } else {
-        Err(IsccError::InvalidInput(
+        Err(IsccError::Header(
             "invalid varnibble prefix or insufficient bits".into(),
         ))
     }
 }
 
+// ---- Uvarint Encoding ----
+
+/// Encode an unsigned integer as a LEB128 varint (7 bits per byte, LSB group first).
+pub(crate) fn encode_uvarint(mut value: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Decode a LEB128 varint that must span the entire input exactly.
+///
+/// Rejects empty input, unterminated sequences, trailing bytes, and
+/// non-canonical encodings with redundant zero continuation groups.
+pub(crate) fn decode_uvarint(data: &[u8]) -> IsccResult<u64> {
+    let mut value = 0u64;
+    for (i, &byte) in data.iter().enumerate() {
+        if i >= 9 {
+            return Err(IsccError::InvalidInput("uvarint too long".into()));
+        }
+        value |= u64::from(byte & 0x7F) << (7 * i);
+        if byte & 0x80 == 0 {
+            if i + 1 != data.len() {
+                return Err(IsccError::InvalidInput(
+                    "trailing bytes after uvarint".into(),
+                ));
+            }
+            if i > 0 && byte == 0 {
+                return Err(IsccError::InvalidInput(
+                    "non-canonical uvarint encoding".into(),
+                ));
+            }
+            return Ok(value);
+        }
+    }
+    Err(IsccError::InvalidInput("truncated uvarint".into()))
+}
+
 // ---- Header Encoding ----
 
 /// Encode ISCC header fields into bytes.
@@ -319,16 +391,17 @@ pub fn encode_length(mtype: MainType, length: u32) -> IsccResult<u32> {
             if length >= 32 && length % 32 == 0 {
                 Ok(length / 32 - 1)
             } else {
-                Err(IsccError::InvalidInput(format!(
-                    "invalid length {length} for {mtype:?} (must be multiple of 32, >= 32)"
-                )))
+                Err(IsccError::InvalidBits {
+                    bits: length,
+                    expected: format!("a multiple of 32, >= 32 for {mtype:?}"),
+                })
             }
         }
         MainType::Iscc => {
             if length <= 7 {
                 Ok(length)
             } else {
-                Err(IsccError::InvalidInput(format!(
+                Err(IsccError::Header(format!(
                     "invalid length {length} for ISCC (must be 0-7)"
                 )))
             }
@@ -337,9 +410,10 @@ pub fn encode_length(mtype: MainType, length: u32) -> IsccResult<u32> {
             if (64..=96).contains(&length) && (length - 64) % 8 == 0 {
                 Ok((length - 64) / 8)
             } else {
-                Err(IsccError::InvalidInput(format!(
-                    "invalid length {length} for ID (must be 64-96, step 8)"
-                )))
+                Err(IsccError::InvalidBits {
+                    bits: length,
+                    expected: "64-96 in steps of 8 for ID".into(),
+                })
             }
         }
     }
@@ -404,7 +478,7 @@ pub fn encode_units(main_types: &[MainType]) -> IsccResult<u32> {
 /// automatically sorted.
 pub fn decode_units(unit_id: u32) -> IsccResult<Vec<MainType>> {
     if unit_id > 7 {
-        return Err(IsccError::InvalidInput(format!(
+        return Err(IsccError::Header(format!(
             "invalid unit_id: {unit_id} (must be 0-7)"
         )));
     }
@@ -431,9 +505,7 @@ pub fn encode_base32(data: &[u8]) -> String {
 /// Decode base32 string to bytes (case-insensitive, no padding expected).
 pub fn decode_base32(code: &str) -> IsccResult<Vec<u8>> {
     let upper = code.to_uppercase();
-    data_encoding::BASE32_NOPAD
-        .decode(upper.as_bytes())
-        .map_err(|e| IsccError::InvalidInput(format!("base32 decode error: {e}")))
+    Ok(data_encoding::BASE32_NOPAD.decode(upper.as_bytes())?)
 }
 
 // ---- Base64 Encoding ----
@@ -443,6 +515,195 @@ pub fn encode_base64(data: &[u8]) -> String {
     data_encoding::BASE64URL_NOPAD.encode(data)
 }
 
+// ---- Multiformats ----
+
+/// Multicodec prefix for ISCC codes (`0xcc01`), prepended to the raw ISCC bytes.
+pub const MULTICODEC_PREFIX: [u8; 2] = [0xCC, 0x01];
+
+/// Bitcoin base58 alphabet used by multibase `base58btc`.
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Multibase encodings supported for ISCC multiformat strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MultiBase {
+    /// Lowercase hexadecimal (prefix `f`).
+    Base16,
+    /// Lowercase RFC 4648 base32 without padding (prefix `b`).
+    Base32,
+    /// Lowercase RFC 4648 base32hex without padding (prefix `v`).
+    Base32Hex,
+    /// Bitcoin base58 (prefix `z`).
+    Base58Btc,
+    /// RFC 4648 base64url without padding (prefix `u`).
+    Base64Url,
+}
+
+impl MultiBase {
+    /// Multibase prefix character.
+    pub fn prefix(self) -> char {
+        match self {
+            Self::Base16 => 'f',
+            Self::Base32 => 'b',
+            Self::Base32Hex => 'v',
+            Self::Base58Btc => 'z',
+            Self::Base64Url => 'u',
+        }
+    }
+
+    /// Look up a multibase by its (lowercase) prefix character.
+    pub fn from_prefix(prefix: char) -> Option<Self> {
+        match prefix {
+            'f' => Some(Self::Base16),
+            'b' => Some(Self::Base32),
+            'v' => Some(Self::Base32Hex),
+            'z' => Some(Self::Base58Btc),
+            'u' => Some(Self::Base64Url),
+            _ => None,
+        }
+    }
+
+    fn encode(self, data: &[u8]) -> String {
+        match self {
+            Self::Base16 => data_encoding::HEXLOWER.encode(data),
+            Self::Base32 => data_encoding::BASE32_NOPAD.encode(data).to_lowercase(),
+            Self::Base32Hex => data_encoding::BASE32HEX_NOPAD.encode(data).to_lowercase(),
+            Self::Base58Btc => encode_base58(data),
+            Self::Base64Url => data_encoding::BASE64URL_NOPAD.encode(data),
+        }
+    }
+
+    pub(crate) fn decode(self, text: &str) -> IsccResult<Vec<u8>> {
+        let decoded = match self {
+            Self::Base16 => data_encoding::HEXLOWER_PERMISSIVE.decode(text.as_bytes()),
+            Self::Base32 => data_encoding::BASE32_NOPAD.decode(text.to_uppercase().as_bytes()),
+            Self::Base32Hex => {
+                data_encoding::BASE32HEX_NOPAD.decode(text.to_uppercase().as_bytes())
+            }
+            Self::Base58Btc => return decode_base58(text),
+            Self::Base64Url => data_encoding::BASE64URL_NOPAD.decode(text.as_bytes()),
+        };
+        decoded.map_err(|e| IsccError::InvalidInput(format!("{self:?} decode error: {e}")))
+    }
+}
+
+/// Encode bytes as base58 (Bitcoin alphabet), preserving leading zero bytes as `1`.
+fn encode_base58(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+    // Little-endian base58 digits of the big-endian input number
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for &byte in &data[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let mut out = "1".repeat(zeros);
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| BASE58_ALPHABET[d as usize] as char),
+    );
+    out
+}
+
+/// Decode a base58 (Bitcoin alphabet) string to bytes.
+fn decode_base58(text: &str) -> IsccResult<Vec<u8>> {
+    let zeros = text.bytes().take_while(|&b| b == b'1').count();
+    // Little-endian base256 bytes of the decoded number
+    let mut bytes: Vec<u8> = Vec::with_capacity(text.len());
+    for ch in text.bytes().skip(zeros) {
+        let value = BASE58_ALPHABET
+            .iter()
+            .position(|&a| a == ch)
+            .ok_or_else(|| {
+                IsccError::InvalidInput(format!("invalid base58 character: {:?}", ch as char))
+            })?;
+        let mut carry = value as u32;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xFF) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xFF) as u8);
+            carry >>= 8;
+        }
+    }
+    let mut out = vec![0u8; zeros];
+    out.extend(bytes.iter().rev());
+    Ok(out)
+}
+
+/// Ensure `raw` holds one or more well-formed ISCC headers and bodies.
+fn check_iscc_bytes(raw: &[u8]) -> IsccResult<()> {
+    if raw.is_empty() {
+        return Err(IsccError::InvalidInput("empty ISCC".into()));
+    }
+    decompose_bytes(raw).map(|_| ())
+}
+
+/// Encode an ISCC string as a multiformat string in the given multibase.
+///
+/// The result is the multibase prefix followed by the encoding of the
+/// `0xcc01` multicodec prefix and the raw ISCC bytes (header + body). Accepts
+/// any ISCC-UNIT, ISCC-CODE, or ISCC-ID with optional `"ISCC:"` prefix and
+/// dashes.
+///
+/// # Errors
+///
+/// Returns `IsccError::Base32`, `IsccError::Header`, or `IsccError::Truncated`
+/// if `iscc` is not a well-formed ISCC.
+pub fn to_multiformat(iscc: &str, base: MultiBase) -> IsccResult<String> {
+    let raw = decode_base32(&iscc_clean(iscc))?;
+    check_iscc_bytes(&raw)?;
+    let mut data = MULTICODEC_PREFIX.to_vec();
+    data.extend_from_slice(&raw);
+    let mut out = String::with_capacity(data.len() * 2 + 1);
+    out.push(base.prefix());
+    out.push_str(&base.encode(&data));
+    Ok(out)
+}
+
+/// Parse a multiformat or canonical ISCC string into canonical `ISCC:<base32>` form.
+///
+/// Accepts any [`MultiBase`] multiformat string carrying the `0xcc01`
+/// multicodec prefix, as well as canonical ISCC strings (uppercase base32,
+/// with or without `"ISCC:"` prefix and dashes). Canonical ISCCs always start
+/// with an uppercase letter, so they never clash with the lowercase
+/// multibase prefixes.
+///
+/// # Errors
+///
+/// Returns `IsccError::Unsupported` on an unknown multibase prefix, and
+/// `IsccError::InvalidInput` (or a decoding error) on invalid encoding, a
+/// missing multicodec prefix, or a malformed ISCC payload.
+pub fn from_multiformat(text: &str) -> IsccResult<String> {
+    let mut chars = text.chars();
+    let raw = match chars.next() {
+        Some(c) if c.is_ascii_uppercase() => decode_base32(&iscc_clean(text))?,
+        first => {
+            let base = first
+                .and_then(MultiBase::from_prefix)
+                .ok_or_else(|| IsccError::Unsupported(format!("multibase prefix in {text:?}")))?;
+            base.decode(chars.as_str())?
+                .strip_prefix(&MULTICODEC_PREFIX)
+                .ok_or_else(|| {
+                    IsccError::InvalidInput("missing ISCC multicodec prefix 0xcc01".into())
+                })?
+                .to_vec()
+        }
+    };
+    check_iscc_bytes(&raw)?;
+    Ok(format!("ISCC:{}", encode_base32(&raw)))
+}
+
 // ---- Component Encoding ----
 
 /// Encode an ISCC-UNIT with header and body as a base32 string.
@@ -479,11 +740,26 @@ pub fn encode_component(
 /// Decompose a composite ISCC-CODE or ISCC sequence into individual ISCC-UNITs.
 ///
 /// Accepts a normalized ISCC-CODE or a concatenated sequence of ISCC-UNITs.
-/// The optional "ISCC:" prefix is stripped before decoding. Returns a list
-/// of base32-encoded ISCC-UNIT strings (without "ISCC:" prefix).
+/// The input is cleaned with [`iscc_clean`] (whitespace, "ISCC:" prefix,
+/// dashes) before decoding. Returns a list of base32-encoded ISCC-UNIT
+/// strings (without "ISCC:" prefix).
 pub fn iscc_decompose(iscc_code: &str) -> IsccResult<Vec<String>> {
-    let clean = iscc_code.strip_prefix("ISCC:").unwrap_or(iscc_code);
-    let mut raw_code = decode_base32(clean)?;
+    Ok(decompose_units(iscc_code)?
+        .iter()
+        .map(IsccUnit::to_base32)
+        .collect())
+}
+
+/// Decompose an ISCC-CODE or ISCC sequence into typed [`IsccUnit`] values.
+///
+/// Same input handling as [`iscc_decompose`].
+pub(crate) fn decompose_units(iscc_code: &str) -> IsccResult<Vec<IsccUnit>> {
+    decompose_bytes(&decode_base32(&iscc_clean(iscc_code))?)
+}
+
+/// Decompose raw ISCC bytes (one or more concatenated headers + bodies) into units.
+fn decompose_bytes(data: &[u8]) -> IsccResult<Vec<IsccUnit>> {
+    let mut raw_code = data.to_vec();
     let mut components = Vec::new();
 
     while !raw_code.is_empty() {
@@ -494,77 +770,520 @@ pub fn iscc_decompose(iscc_code: &str) -> IsccResult<Vec<String>> {
             let ln_bits = decode_length(mt, ln, st);
             let nbytes = (ln_bits / 8) as usize;
             if body.len() < nbytes {
-                return Err(IsccError::InvalidInput(format!(
-                    "truncated ISCC body: expected {nbytes} bytes, got {}",
-                    body.len()
-                )));
+                return Err(IsccError::Truncated {
+                    expected: nbytes,
+                    actual: body.len(),
+                });
+            }
+            let unit = IsccUnit::new(mt, st, vs, ln_bits, &body[..nbytes])?;
+            if mt == MainType::Id {
+                IsccId::try_from(&unit)?;
             }
-            let code = encode_component(mt, st, vs, ln_bits, &body[..nbytes])?;
-            components.push(code);
+            components.push(unit);
             raw_code = body[nbytes..].to_vec();
             continue;
         }
 
         // ISCC-CODE: decode into constituent units
         let main_types = decode_units(ln)?;
+        components.extend(composite_units(st, vs, &main_types, &body)?);
+        break;
+    }
 
-        // Wide mode: 128-bit Data-Code + 128-bit Instance-Code
-        if st == SubType::Wide {
-            if body.len() < 32 {
-                return Err(IsccError::InvalidInput(format!(
-                    "truncated ISCC body: expected 32 bytes, got {}",
-                    body.len()
-                )));
-            }
-            let data_code = encode_component(MainType::Data, SubType::None, vs, 128, &body[..16])?;
-            let instance_code =
-                encode_component(MainType::Instance, SubType::None, vs, 128, &body[16..32])?;
-            components.push(data_code);
-            components.push(instance_code);
-            break;
+    Ok(components)
+}
+
+/// Rebuild the ISCC-UNITs contained in the body of a composite ISCC-CODE.
+///
+/// `main_types` are the optional units (Meta, Semantic, Content) encoded in the
+/// header length field. Data-Code and Instance-Code are always appended last.
+fn composite_units(
+    st: SubType,
+    vs: Version,
+    main_types: &[MainType],
+    body: &[u8],
+) -> IsccResult<Vec<IsccUnit>> {
+    // Wide mode: 128-bit Data-Code + 128-bit Instance-Code
+    if st == SubType::Wide {
+        if body.len() < 32 {
+            return Err(IsccError::Truncated {
+                expected: 32,
+                actual: body.len(),
+            });
         }
+        return Ok(vec![
+            IsccUnit::new(MainType::Data, SubType::None, vs, 128, &body[..16])?,
+            IsccUnit::new(MainType::Instance, SubType::None, vs, 128, &body[16..32])?,
+        ]);
+    }
+
+    // Non-wide ISCC-CODE: total body = dynamic units × 8 + Data 8 + Instance 8
+    let expected_body = main_types.len() * 8 + 16;
+    if body.len() < expected_body {
+        return Err(IsccError::Truncated {
+            expected: expected_body,
+            actual: body.len(),
+        });
+    }
+
+    // Rebuild dynamic units (Meta, Semantic, Content)
+    let mut components = Vec::with_capacity(main_types.len() + 2);
+    for (idx, &mtype) in main_types.iter().enumerate() {
+        let stype = if mtype == MainType::Meta {
+            SubType::None
+        } else {
+            st
+        };
+        components.push(IsccUnit::new(mtype, stype, vs, 64, &body[idx * 8..])?);
+    }
+
+    // Rebuild static units (Data-Code, Instance-Code)
+    components.push(IsccUnit::new(
+        MainType::Data,
+        SubType::None,
+        vs,
+        64,
+        &body[body.len() - 16..body.len() - 8],
+    )?);
+    components.push(IsccUnit::new(
+        MainType::Instance,
+        SubType::None,
+        vs,
+        64,
+        &body[body.len() - 8..],
+    )?);
+    Ok(components)
+}
+
+// ---- Typed ISCC Values ----
 
-        // Non-wide ISCC-CODE: total body = dynamic units × 8 + Data 8 + Instance 8
-        let expected_body = main_types.len() * 8 + 16;
-        if body.len() < expected_body {
+/// Require a decoded body to match the length implied by its header exactly.
+fn check_body_length(expected: usize, actual: usize) -> IsccResult<()> {
+    if actual < expected {
+        return Err(IsccError::Truncated { expected, actual });
+    }
+    if actual > expected {
+        return Err(IsccError::InvalidInput(format!(
+            "{} trailing bytes after {expected}-byte body",
+            actual - expected
+        )));
+    }
+    Ok(())
+}
+
+/// A single ISCC-UNIT: header fields plus digest.
+///
+/// Parses from any ISCC-UNIT string (with or without `"ISCC:"` prefix) via
+/// [`FromStr`] and displays in canonical `ISCC:<base32>` form. Implements
+/// `Eq`, `Ord`, and `Hash` so units can be used as map keys. Ordering is by
+/// `MainType`, `SubType`, `Version`, bit length, then digest bytes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsccUnit {
+    maintype: MainType,
+    subtype: SubType,
+    version: Version,
+    bit_length: u32,
+    digest: Vec<u8>,
+}
+
+impl IsccUnit {
+    /// Create a new ISCC-UNIT from header fields and a digest.
+    ///
+    /// The digest is truncated to `bit_length / 8` bytes, matching
+    /// [`encode_component`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidInput` if `maintype` is `MainType::Iscc`,
+    /// `IsccError::InvalidBits` if `bit_length` is invalid for the MainType, or
+    /// `IsccError::Truncated` if the digest is too short.
+    pub fn new(
+        maintype: MainType,
+        subtype: SubType,
+        version: Version,
+        bit_length: u32,
+        digest: &[u8],
+    ) -> IsccResult<Self> {
+        if maintype == MainType::Iscc {
+            return Err(IsccError::InvalidInput(
+                "ISCC MainType is not a unit; use IsccCode instead".into(),
+            ));
+        }
+        encode_length(maintype, bit_length)?;
+        let nbytes = (bit_length / 8) as usize;
+        if digest.len() < nbytes {
+            return Err(IsccError::Truncated {
+                expected: nbytes,
+                actual: digest.len(),
+            });
+        }
+        Ok(Self {
+            maintype,
+            subtype,
+            version,
+            bit_length,
+            digest: digest[..nbytes].to_vec(),
+        })
+    }
+
+    /// Decode an ISCC-UNIT from raw bytes (header + digest).
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::Header` on a malformed header,
+    /// `IsccError::InvalidInput` on a composite ISCC-CODE header, or
+    /// `IsccError::Truncated` / `IsccError::InvalidInput` if the body length
+    /// does not match the header.
+    pub fn from_bytes(data: &[u8]) -> IsccResult<Self> {
+        let (mt, st, vs, ln, body) = decode_header(data)?;
+        if mt == MainType::Iscc {
+            return Err(IsccError::InvalidInput(
+                "expected an ISCC-UNIT, got a composite ISCC-CODE".into(),
+            ));
+        }
+        let bit_length = decode_length(mt, ln, st);
+        let nbytes = (bit_length / 8) as usize;
+        check_body_length(nbytes, body.len())?;
+        Self::new(mt, st, vs, bit_length, &body)
+    }
+
+    /// MainType of the unit.
+    pub fn maintype(&self) -> MainType {
+        self.maintype
+    }
+
+    /// SubType of the unit.
+    pub fn subtype(&self) -> SubType {
+        self.subtype
+    }
+
+    /// Version of the unit.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Digest length in bits.
+    pub fn bit_length(&self) -> u32 {
+        self.bit_length
+    }
+
+    /// Raw digest bytes (`bit_length / 8` bytes).
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Encode as raw bytes (header + digest).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let length = encode_length(self.maintype, self.bit_length)
+            .expect("bit_length validated on construction");
+        let mut bytes = encode_header(self.maintype, self.subtype, self.version, length)
+            .expect("header fields validated on construction");
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+
+    /// Encode as base32 string without `"ISCC:"` prefix.
+    pub fn to_base32(&self) -> String {
+        encode_base32(&self.to_bytes())
+    }
+}
+
+impl fmt::Display for IsccUnit {
+    /// Format in canonical `ISCC:<base32>` form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ISCC:{}", self.to_base32())
+    }
+}
+
+impl FromStr for IsccUnit {
+    type Err = IsccError;
+
+    /// Parse an ISCC-UNIT string, ignoring an optional `"ISCC:"` prefix and dashes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(&decode_base32(&iscc_clean(s))?)
+    }
+}
+
+/// A composite ISCC-CODE (`MainType::Iscc`).
+///
+/// Holds the SubType, Version, the optional unit MainTypes (Meta, Semantic,
+/// Content) encoded in the header, and the concatenated unit digests. Parses
+/// via [`FromStr`], displays in canonical `ISCC:<base32>` form, and implements
+/// `Eq`, `Ord`, and `Hash`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsccCode {
+    subtype: SubType,
+    version: Version,
+    optional_units: Vec<MainType>,
+    digest: Vec<u8>,
+}
+
+impl IsccCode {
+    /// Create a new ISCC-CODE from header fields and the concatenated unit digests.
+    ///
+    /// `optional_units` lists the optional MainTypes present in the code (any of
+    /// Meta, Semantic, Content); they are stored in canonical MainType order.
+    /// The digest is truncated to the bit length implied by the header.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidInput` if `optional_units` contains a
+    /// mandatory or non-unit MainType or if wide mode is combined with optional
+    /// units, and `IsccError::Truncated` if the digest is too short.
+    pub fn new(
+        subtype: SubType,
+        version: Version,
+        optional_units: &[MainType],
+        digest: &[u8],
+    ) -> IsccResult<Self> {
+        let unit_id = encode_units(optional_units)?;
+        if subtype == SubType::Wide && unit_id != 0 {
+            return Err(IsccError::InvalidInput(
+                "wide ISCC-CODE cannot contain optional units".into(),
+            ));
+        }
+        let nbytes = (decode_length(MainType::Iscc, unit_id, subtype) / 8) as usize;
+        if digest.len() < nbytes {
+            return Err(IsccError::Truncated {
+                expected: nbytes,
+                actual: digest.len(),
+            });
+        }
+        Ok(Self {
+            subtype,
+            version,
+            optional_units: decode_units(unit_id)?,
+            digest: digest[..nbytes].to_vec(),
+        })
+    }
+
+    /// Decode an ISCC-CODE from raw bytes (header + body).
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::Header` on a malformed header,
+    /// `IsccError::InvalidInput` on a non-ISCC MainType, or
+    /// `IsccError::Truncated` / `IsccError::InvalidInput` if the body length
+    /// does not match the header.
+    pub fn from_bytes(data: &[u8]) -> IsccResult<Self> {
+        let (mt, st, vs, ln, body) = decode_header(data)?;
+        if mt != MainType::Iscc {
             return Err(IsccError::InvalidInput(format!(
-                "truncated ISCC body: expected {expected_body} bytes, got {}",
-                body.len()
+                "expected a composite ISCC-CODE, got {mt:?}"
             )));
         }
+        let nbytes = (decode_length(mt, ln, st) / 8) as usize;
+        check_body_length(nbytes, body.len())?;
+        Self::new(st, vs, &decode_units(ln)?, &body)
+    }
 
-        // Rebuild dynamic units (Meta, Semantic, Content)
-        for (idx, &mtype) in main_types.iter().enumerate() {
-            let stype = if mtype == MainType::Meta {
-                SubType::None
-            } else {
-                st
-            };
-            let code = encode_component(mtype, stype, vs, 64, &body[idx * 8..])?;
-            components.push(code);
+    /// MainType of the code (always `MainType::Iscc`).
+    pub fn maintype(&self) -> MainType {
+        MainType::Iscc
+    }
+
+    /// SubType of the code.
+    pub fn subtype(&self) -> SubType {
+        self.subtype
+    }
+
+    /// Version of the code.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Optional unit MainTypes (Meta, Semantic, Content) in MainType order.
+    pub fn optional_units(&self) -> &[MainType] {
+        &self.optional_units
+    }
+
+    /// Total digest length in bits.
+    pub fn bit_length(&self) -> u32 {
+        self.digest.len() as u32 * 8
+    }
+
+    /// Concatenated unit digests.
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Decompose into the constituent ISCC-UNITs.
+    pub fn units(&self) -> Vec<IsccUnit> {
+        composite_units(
+            self.subtype,
+            self.version,
+            &self.optional_units,
+            &self.digest,
+        )
+        .expect("digest length validated on construction")
+    }
+
+    /// Encode as raw bytes (header + digest).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let unit_id = encode_units(&self.optional_units).expect("units validated on construction");
+        let mut bytes = encode_header(MainType::Iscc, self.subtype, self.version, unit_id)
+            .expect("header fields validated on construction");
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+
+    /// Encode as base32 string without `"ISCC:"` prefix.
+    pub fn to_base32(&self) -> String {
+        encode_base32(&self.to_bytes())
+    }
+}
+
+impl fmt::Display for IsccCode {
+    /// Format in canonical `ISCC:<base32>` form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ISCC:{}", self.to_base32())
+    }
+}
+
+impl FromStr for IsccCode {
+    type Err = IsccError;
+
+    /// Parse an ISCC-CODE string, ignoring an optional `"ISCC:"` prefix and dashes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(&decode_base32(&iscc_clean(s))?)
+    }
+}
+
+/// A decoded ISCC-ID (`MainType::Id`, version 0).
+///
+/// Layout: header with the [`Chain`] as SubType, followed by a 64-bit
+/// similarity digest and an optional uniqueness counter encoded as a LEB128
+/// uvarint (1–4 bytes). A counter of `0` is omitted, giving a 64-bit body;
+/// larger counters extend the body up to 96 bits.
+///
+/// The version 1 layout (a timestamp and HUB-ID assigned by an ISCC-HUB at
+/// registration) is not derived from an ISCC-CODE and is not supported:
+/// parsing such an ID fails with `IsccError::Unsupported`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsccId {
+    chain: Chain,
+    version: Version,
+    digest: [u8; 8],
+    counter: u32,
+}
+
+impl IsccId {
+    /// Largest uniqueness counter that fits the 96-bit ISCC-ID body (4 uvarint bytes).
+    pub const MAX_COUNTER: u32 = (1 << 28) - 1;
+
+    /// Create a new ISCC-ID from its chain, 64-bit digest, and uniqueness counter.
+    ///
+    /// Only the first 8 bytes of `digest` are used.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::Truncated` if the digest is shorter than 8 bytes or
+    /// `IsccError::InvalidInput` if the counter exceeds [`IsccId::MAX_COUNTER`].
+    pub fn new(chain: Chain, digest: &[u8], counter: u32) -> IsccResult<Self> {
+        let digest: [u8; 8] =
+            digest
+                .get(..8)
+                .and_then(|d| d.try_into().ok())
+                .ok_or(IsccError::Truncated {
+                    expected: 8,
+                    actual: digest.len(),
+                })?;
+        if counter > Self::MAX_COUNTER {
+            return Err(IsccError::InvalidInput(format!(
+                "ISCC-ID counter {counter} exceeds maximum {}",
+                Self::MAX_COUNTER
+            )));
         }
+        Ok(Self {
+            chain,
+            version: Version::V0,
+            digest,
+            counter,
+        })
+    }
 
-        // Rebuild static units (Data-Code, Instance-Code)
-        let data_code = encode_component(
-            MainType::Data,
-            SubType::None,
-            vs,
-            64,
-            &body[body.len() - 16..body.len() - 8],
-        )?;
-        let instance_code = encode_component(
-            MainType::Instance,
-            SubType::None,
-            vs,
-            64,
-            &body[body.len() - 8..],
-        )?;
-        components.push(data_code);
-        components.push(instance_code);
-        break;
+    /// Chain identifier.
+    pub fn chain(&self) -> Chain {
+        self.chain
     }
 
-    Ok(components)
+    /// Version of the ISCC-ID.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// 64-bit similarity digest.
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Uniqueness counter (`0` if the ID carries no counter).
+    pub fn counter(&self) -> u32 {
+        self.counter
+    }
+
+    /// Convert to a generic [`IsccUnit`] (digest followed by the uvarint counter).
+    pub fn to_unit(&self) -> IsccUnit {
+        let mut body = self.digest.to_vec();
+        if self.counter > 0 {
+            body.extend(encode_uvarint(u64::from(self.counter)));
+        }
+        let subtype = SubType::try_from(self.chain as u8).expect("Chain values are valid SubTypes");
+        IsccUnit::new(
+            MainType::Id,
+            subtype,
+            self.version,
+            body.len() as u32 * 8,
+            &body,
+        )
+        .expect("ISCC-ID body is 64-96 bits")
+    }
+}
+
+impl TryFrom<&IsccUnit> for IsccId {
+    type Error = IsccError;
+
+    /// Interpret an ISCC-UNIT as an ISCC-ID, validating chain and counter encoding.
+    fn try_from(unit: &IsccUnit) -> Result<Self, Self::Error> {
+        if unit.maintype() != MainType::Id {
+            return Err(IsccError::InvalidInput(format!(
+                "expected an ISCC-ID, got {:?}",
+                unit.maintype()
+            )));
+        }
+        let chain = Chain::try_from(unit.subtype() as u8)?;
+        let (digest, tail) = unit.digest().split_at(8);
+        let counter = if tail.is_empty() {
+            0
+        } else {
+            let counter = decode_uvarint(tail)?;
+            if counter == 0 {
+                return Err(IsccError::InvalidInput(
+                    "ISCC-ID counter must be omitted when zero".into(),
+                ));
+            }
+            counter as u32
+        };
+        let mut id = Self::new(chain, digest, counter)?;
+        id.version = unit.version();
+        Ok(id)
+    }
+}
+
+impl fmt::Display for IsccId {
+    /// Format in canonical `ISCC:<base32>` form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_unit().fmt(f)
+    }
+}
+
+impl FromStr for IsccId {
+    type Err = IsccError;
+
+    /// Parse an ISCC-ID string, ignoring an optional `"ISCC:"` prefix and dashes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(&s.parse::<IsccUnit>()?)
+    }
 }
 
 #[cfg(test)]
@@ -959,6 +1678,69 @@ mod tests {
         }
     }
 
+    // ---- Multiformat tests ----
+
+    #[test]
+    fn test_base58_known_values() {
+        assert_eq!(encode_base58(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(encode_base58(&[0, 0, 1]), "112");
+        assert_eq!(encode_base58(&[]), "");
+        assert_eq!(decode_base58("2NEpo7TZRRrLZSi2U").unwrap(), b"Hello World!");
+        assert_eq!(decode_base58("112").unwrap(), vec![0, 0, 1]);
+        assert!(decode_base58("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_to_multiformat_base16() {
+        let raw = decode_base32("AAAZXZ6OU74YAZIM").unwrap();
+        let expected = format!("fcc01{}", data_encoding::HEXLOWER.encode(&raw));
+        assert_eq!(
+            to_multiformat("ISCC:AAAZXZ6OU74YAZIM", MultiBase::Base16).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_multiformat_roundtrip_all_bases() {
+        let bases = [
+            MultiBase::Base16,
+            MultiBase::Base32,
+            MultiBase::Base32Hex,
+            MultiBase::Base58Btc,
+            MultiBase::Base64Url,
+        ];
+        for iscc in [
+            "ISCC:AAAZXZ6OU74YAZIM",
+            "ISCC:KACYPXW445FTYNJ3CYSXHAFJMA2HUWULUNRFE3BLHRSCXYH2M5AEGQY",
+        ] {
+            for base in bases {
+                let mf = to_multiformat(iscc, base).unwrap();
+                assert!(mf.starts_with(base.prefix()));
+                assert_eq!(from_multiformat(&mf).unwrap(), iscc, "{base:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_multiformat_canonical_forms() {
+        let iscc = "ISCC:AAAZXZ6OU74YAZIM";
+        assert_eq!(from_multiformat(iscc).unwrap(), iscc);
+        assert_eq!(from_multiformat("ISCC:AAAZ-XZ6O-U74Y-AZIM").unwrap(), iscc);
+        assert_eq!(from_multiformat("AAAZXZ6OU74YAZIM").unwrap(), iscc);
+        assert!(from_multiformat("ISCC:").is_err());
+    }
+
+    #[test]
+    fn test_from_multiformat_rejects_invalid() {
+        assert!(from_multiformat("").is_err());
+        assert!(from_multiformat("xabc").is_err());
+        // Valid multibase, missing multicodec prefix
+        assert!(from_multiformat("f0000").is_err());
+        // Multicodec prefix with truncated ISCC payload
+        assert!(from_multiformat("fcc010000").is_err());
+        assert!(to_multiformat("ISCC:AAAZXZ6OU74YAZ", MultiBase::Base58Btc).is_err());
+    }
+
     // ---- encode_component tests ----
 
     #[test]
@@ -1425,10 +2207,10 @@ mod tests {
             result.is_err(),
             "expected error for truncated standard unit"
         );
-        let err = result.unwrap_err().to_string();
+        let err = result.unwrap_err();
         assert!(
-            err.contains("truncated ISCC body"),
-            "error should mention truncation: {err}"
+            matches!(err, IsccError::Truncated { .. }),
+            "error should report truncation: {err}"
         );
     }
 
@@ -1439,10 +2221,10 @@ mod tests {
         let iscc = make_truncated_iscc(MainType::Iscc, SubType::Wide, 0, 16);
         let result = iscc_decompose(&iscc);
         assert!(result.is_err(), "expected error for truncated wide mode");
-        let err = result.unwrap_err().to_string();
+        let err = result.unwrap_err();
         assert!(
-            err.contains("truncated ISCC body"),
-            "error should mention truncation: {err}"
+            matches!(err, IsccError::Truncated { .. }),
+            "error should report truncation: {err}"
         );
     }
 
@@ -1458,10 +2240,10 @@ mod tests {
             result.is_err(),
             "expected error for truncated dynamic units"
         );
-        let err = result.unwrap_err().to_string();
+        let err = result.unwrap_err();
         assert!(
-            err.contains("truncated ISCC body"),
-            "error should mention truncation: {err}"
+            matches!(err, IsccError::Truncated { .. }),
+            "error should report truncation: {err}"
         );
     }
 
@@ -1474,10 +2256,10 @@ mod tests {
         let iscc = make_truncated_iscc(MainType::Iscc, SubType::None, unit_id, 16);
         let result = iscc_decompose(&iscc);
         assert!(result.is_err(), "expected error for truncated static units");
-        let err = result.unwrap_err().to_string();
+        let err = result.unwrap_err();
         assert!(
-            err.contains("truncated ISCC body"),
-            "error should mention truncation: {err}"
+            matches!(err, IsccError::Truncated { .. }),
+            "error should report truncation: {err}"
         );
     }
 
@@ -1488,10 +2270,10 @@ mod tests {
         let iscc = make_truncated_iscc(MainType::Meta, SubType::None, length_field, 0);
         let result = iscc_decompose(&iscc);
         assert!(result.is_err(), "expected error for empty body");
-        let err = result.unwrap_err().to_string();
+        let err = result.unwrap_err();
         assert!(
-            err.contains("truncated ISCC body"),
-            "error should mention truncation: {err}"
+            matches!(err, IsccError::Truncated { .. }),
+            "error should report truncation: {err}"
         );
     }
 
@@ -1538,4 +2320,170 @@ mod tests {
         let units = result.unwrap();
         assert_eq!(units.len(), 4, "should decompose into 4 units");
     }
+
+    // ---- IsccUnit / IsccCode tests ----
+
+    #[test]
+    fn test_iscc_unit_parse_display_roundtrip() {
+        let unit: IsccUnit = "ISCC:AAAZXZ6OU74YAZIM".parse().unwrap();
+        assert_eq!(unit.maintype(), MainType::Meta);
+        assert_eq!(unit.subtype(), SubType::None);
+        assert_eq!(unit.version(), Version::V0);
+        assert_eq!(unit.bit_length(), 64);
+        assert_eq!(unit.digest().len(), 8);
+        assert_eq!(unit.to_string(), "ISCC:AAAZXZ6OU74YAZIM");
+        assert_eq!(unit.to_base32(), "AAAZXZ6OU74YAZIM");
+    }
+
+    #[test]
+    fn test_iscc_unit_parse_lenient_forms() {
+        let canonical: IsccUnit = "ISCC:AAAZXZ6OU74YAZIM".parse().unwrap();
+        for input in [
+            "AAAZXZ6OU74YAZIM",
+            "aaazxz6ou74yazim",
+            "ISCC:AAAZ-XZ6O-U74Y-AZIM",
+        ] {
+            let unit: IsccUnit = input.parse().unwrap();
+            assert_eq!(unit, canonical, "input {input}");
+        }
+    }
+
+    #[test]
+    fn test_iscc_unit_rejects_composite_and_trailing_data() {
+        let code = crate::gen_iscc_code_v0(
+            &[
+                "GABVVC5DMJJGYKZ4ZBYVNYABFFYXG",
+                "IADWIK7A7JTUAQ2D6QARX7OBEIK3OOUAM42LOBLCZ4ZOGDLRHMDL6TQ",
+            ],
+            false,
+        )
+        .unwrap();
+        assert!(code.iscc.parse::<IsccUnit>().is_err());
+        let sequence = format!("{}{}", "AAAZXZ6OU74YAZIM", "AAAZXZ6OU74YAZIM");
+        let raw = decode_base32(&sequence).unwrap();
+        assert!(IsccUnit::from_bytes(&raw).is_err());
+    }
+
+    #[test]
+    fn test_iscc_unit_new_validates() {
+        assert!(IsccUnit::new(MainType::Iscc, SubType::Sum, Version::V0, 64, &[0; 8]).is_err());
+        assert!(IsccUnit::new(MainType::Data, SubType::None, Version::V0, 48, &[0; 8]).is_err());
+        assert!(IsccUnit::new(MainType::Data, SubType::None, Version::V0, 128, &[0; 8]).is_err());
+        let unit =
+            IsccUnit::new(MainType::Data, SubType::None, Version::V0, 64, &[0xAB; 32]).unwrap();
+        assert_eq!(unit.digest(), &[0xAB; 8]);
+    }
+
+    #[test]
+    fn test_iscc_unit_ord_and_hash() {
+        use std::collections::{BTreeSet, HashMap};
+        let meta: IsccUnit = "AAAZXZ6OU74YAZIM".parse().unwrap();
+        let instance: IsccUnit = "IAA26E2JXH27TING".parse().unwrap();
+        let data: IsccUnit = "GAAXL2XYM5BQIAZ3".parse().unwrap();
+        let sorted: Vec<MainType> = [instance.clone(), data.clone(), meta.clone()]
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .iter()
+            .map(IsccUnit::maintype)
+            .collect();
+        assert_eq!(
+            sorted,
+            vec![MainType::Meta, MainType::Data, MainType::Instance]
+        );
+
+        let mut map = HashMap::new();
+        map.insert(meta.clone(), "meta");
+        assert_eq!(
+            map.get(&"ISCC:AAAZXZ6OU74YAZIM".parse().unwrap()),
+            Some(&"meta")
+        );
+    }
+
+    #[test]
+    fn test_iscc_code_parse_display_roundtrip() {
+        let json_str = include_str!("../tests/data.json");
+        let data: serde_json::Value = serde_json::from_str(json_str).unwrap();
+        for (name, tc) in data["gen_iscc_code_v0"].as_object().unwrap() {
+            let expected = tc["outputs"]["iscc"].as_str().unwrap();
+            let code: IsccCode = expected.parse().unwrap();
+            assert_eq!(code.maintype(), MainType::Iscc);
+            assert_eq!(code.to_string(), expected, "roundtrip failed in {name}");
+            let units: Vec<String> = code.units().iter().map(IsccUnit::to_base32).collect();
+            assert_eq!(units, iscc_decompose(expected).unwrap(), "units in {name}");
+        }
+    }
+
+    #[test]
+    fn test_iscc_code_rejects_unit() {
+        assert!("ISCC:AAAZXZ6OU74YAZIM".parse::<IsccCode>().is_err());
+    }
+
+    #[test]
+    fn test_iscc_code_new_validates() {
+        assert!(IsccCode::new(SubType::Sum, Version::V0, &[MainType::Data], &[0; 24]).is_err());
+        assert!(IsccCode::new(SubType::Wide, Version::V0, &[MainType::Meta], &[0; 40]).is_err());
+        assert!(IsccCode::new(SubType::None, Version::V0, &[MainType::Content], &[0; 16]).is_err());
+        let code =
+            IsccCode::new(SubType::None, Version::V0, &[MainType::Content], &[0; 24]).unwrap();
+        assert_eq!(code.bit_length(), 192);
+        assert_eq!(code.optional_units(), &[MainType::Content]);
+        assert_eq!(code.units().len(), 3);
+    }
+
+    #[test]
+    fn test_uvarint_roundtrip() {
+        for value in [1u64, 127, 128, 300, 16_383, 16_384, (1 << 28) - 1] {
+            let encoded = encode_uvarint(value);
+            assert_eq!(decode_uvarint(&encoded).unwrap(), value, "value {value}");
+        }
+        assert_eq!(encode_uvarint(300), vec![0xAC, 0x02]);
+    }
+
+    #[test]
+    fn test_uvarint_rejects_malformed() {
+        assert!(decode_uvarint(&[]).is_err());
+        assert!(decode_uvarint(&[0x80]).is_err());
+        assert!(decode_uvarint(&[0x01, 0x00]).is_err());
+        assert!(decode_uvarint(&[0x80, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_iscc_id_roundtrip() {
+        let digest = [0xA5; 8];
+        for counter in [0, 1, 127, 128, IsccId::MAX_COUNTER] {
+            let id = IsccId::new(Chain::Ethereum, &digest, counter).unwrap();
+            let parsed: IsccId = id.to_string().parse().unwrap();
+            assert_eq!(parsed, id);
+            assert_eq!(parsed.chain(), Chain::Ethereum);
+            assert_eq!(parsed.counter(), counter);
+            assert_eq!(parsed.digest(), &digest);
+        }
+        let id = IsccId::new(Chain::Bitcoin, &digest, 0).unwrap();
+        assert_eq!(id.to_unit().bit_length(), 64);
+        let id = IsccId::new(Chain::Bitcoin, &digest, IsccId::MAX_COUNTER).unwrap();
+        assert_eq!(id.to_unit().bit_length(), 96);
+    }
+
+    #[test]
+    fn test_iscc_id_rejects_invalid() {
+        assert!(IsccId::new(Chain::Private, &[0; 7], 0).is_err());
+        assert!(IsccId::new(Chain::Private, &[0; 8], IsccId::MAX_COUNTER + 1).is_err());
+        // Non-Id unit
+        assert!("ISCC:AAAZXZ6OU74YAZIM".parse::<IsccId>().is_err());
+        // Unknown chain (SubType 4)
+        let unit = IsccUnit::new(MainType::Id, SubType::Mixed, Version::V0, 64, &[0; 8]).unwrap();
+        assert!(IsccId::try_from(&unit).is_err());
+        // Explicit zero counter is non-canonical
+        let mut body = vec![0u8; 8];
+        body.push(0x00);
+        let unit = IsccUnit::new(MainType::Id, SubType::None, Version::V0, 72, &body).unwrap();
+        assert!(IsccId::try_from(&unit).is_err());
+        assert!(iscc_decompose(&unit.to_string()).is_err());
+        // Version 1 (timestamp and HUB-ID) layout
+        let v1 = encode_base32(&[0x60, 0x11, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(matches!(
+            v1.parse::<IsccId>(),
+            Err(IsccError::Unsupported(_))
+        ));
+    }
 }
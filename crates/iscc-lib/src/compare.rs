@@ -0,0 +1,318 @@
+//! Hamming-distance and similarity comparison of ISCC-UNITs and ISCC-CODEs.
+//!
+//! Ports `iscc_distance`, `iscc_similarity`, and `iscc_compare` from
+//! `iscc-core` `codec.py`. Composite ISCC-CODEs are decomposed with
+//! [`iscc_decompose`](crate::codec::iscc_decompose) and their units are aligned
+//! by `MainType`/`SubType` before comparison.
+
+use crate::codec::{self, IsccUnit, MainType, SubType};
+use crate::{IsccError, IsccResult};
+
+/// Distance between two aligned ISCC-UNITs of the same `MainType` and `SubType`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UnitDistance {
+    /// MainType shared by both units.
+    pub maintype: MainType,
+    /// SubType shared by both units.
+    pub subtype: SubType,
+    /// Number of digest bits compared (the shorter of both units).
+    pub bits: u32,
+    /// Hamming distance between the compared digest bits.
+    pub distance: u32,
+    /// Normalized similarity in `[0.0, 1.0]` (`1.0 - distance / bits`).
+    pub similarity: f64,
+}
+
+/// Result of [`iscc_compare`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CompareResult {
+    /// Per-unit distances for all similarity units (Meta, Semantic, Content, Data)
+    /// present in both inputs, in MainType order.
+    pub units: Vec<UnitDistance>,
+    /// Whether the Instance-Code digests are identical. `None` if either input
+    /// has no Instance-Code.
+    pub instance_match: Option<bool>,
+}
+
+/// Count differing bits in the first `bits` bits of two digests (MSB-first).
+///
+/// Callers must ensure both digests hold at least `bits` bits.
+pub(crate) fn hamming_distance(a: &[u8], b: &[u8], bits: u32) -> u32 {
+    let full = (bits / 8) as usize;
+    let mut distance: u32 = a[..full]
+        .iter()
+        .zip(&b[..full])
+        .map(|(x, y)| (x ^ y).count_ones())
+        .sum();
+    let rest = bits % 8;
+    if rest != 0 {
+        let mask = 0xFFu8 << (8 - rest);
+        distance += ((a[full] ^ b[full]) & mask).count_ones();
+    }
+    distance
+}
+
+/// Compare two decoded units, truncating to the shorter digest.
+fn unit_distance(a: &IsccUnit, b: &IsccUnit) -> UnitDistance {
+    let bits = a.bit_length().min(b.bit_length());
+    let distance = hamming_distance(a.digest(), b.digest(), bits);
+    UnitDistance {
+        maintype: a.maintype(),
+        subtype: a.subtype(),
+        bits,
+        distance,
+        similarity: 1.0 - f64::from(distance) / f64::from(bits),
+    }
+}
+
+/// Reject comparison of units with the same MainType but different SubTypes.
+fn check_compatible(a: &IsccUnit, b: &IsccUnit) -> IsccResult<()> {
+    if a.subtype() != b.subtype() {
+        return Err(IsccError::InvalidInput(format!(
+            "incompatible ISCC-UNITs: {:?}-{:?} vs {:?}-{:?}",
+            a.maintype(),
+            a.subtype(),
+            b.maintype(),
+            b.subtype()
+        )));
+    }
+    Ok(())
+}
+
+/// Decode an ISCC string that must contain exactly one ISCC-UNIT.
+fn decode_single_unit(iscc: &str) -> IsccResult<IsccUnit> {
+    let mut decoded = codec::decompose_units(iscc)?;
+    if decoded.len() != 1 {
+        return Err(IsccError::InvalidInput(format!(
+            "expected a single ISCC-UNIT, got {} units: {iscc}",
+            decoded.len()
+        )));
+    }
+    Ok(decoded.remove(0))
+}
+
+/// Decode two single ISCC-UNITs and verify they can be compared.
+fn decode_unit_pair(a: &str, b: &str) -> IsccResult<(IsccUnit, IsccUnit)> {
+    let ua = decode_single_unit(a)?;
+    let ub = decode_single_unit(b)?;
+    if ua.maintype() != ub.maintype() {
+        return Err(IsccError::InvalidInput(format!(
+            "incompatible ISCC-UNITs: {:?} vs {:?}",
+            ua.maintype(),
+            ub.maintype()
+        )));
+    }
+    check_compatible(&ua, &ub)?;
+    Ok((ua, ub))
+}
+
+/// Compute the Hamming distance between two ISCC-UNITs.
+///
+/// Both units must share the same `MainType` and `SubType`. Units of different
+/// bit lengths are compared on the length of the shorter one. The optional
+/// `"ISCC:"` prefix is accepted.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if either input is not a single valid
+/// ISCC-UNIT or if the units are incompatible.
+pub fn iscc_distance(a: &str, b: &str) -> IsccResult<u32> {
+    let (ua, ub) = decode_unit_pair(a, b)?;
+    Ok(unit_distance(&ua, &ub).distance)
+}
+
+/// Compute the normalized similarity (`0.0`–`1.0`) between two ISCC-UNITs.
+///
+/// Defined as `1.0 - distance / bits` where `bits` is the bit length of the
+/// shorter unit. Accepts the same inputs as [`iscc_distance`].
+pub fn iscc_similarity(a: &str, b: &str) -> IsccResult<f64> {
+    let (ua, ub) = decode_unit_pair(a, b)?;
+    Ok(unit_distance(&ua, &ub).similarity)
+}
+
+/// Compare two ISCC-UNITs or ISCC-CODEs unit by unit.
+///
+/// Decomposes both inputs, aligns their units by `MainType`, and returns the
+/// Hamming distance and similarity of every similarity unit present in both.
+/// Instance-Codes are compared for exact identity on their common prefix
+/// (composite ISCC-CODEs carry only 64 bits of the Instance-Code).
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` on malformed input or if units with the
+/// same `MainType` have different `SubType`s (e.g. a Text Content-Code against
+/// an Image Content-Code).
+pub fn iscc_compare(a: &str, b: &str) -> IsccResult<CompareResult> {
+    let units_a = codec::decompose_units(a)?;
+    let units_b = codec::decompose_units(b)?;
+
+    let mut units = Vec::new();
+    let mut instance_match = None;
+    for ua in &units_a {
+        let Some(ub) = units_b.iter().find(|ub| ub.maintype() == ua.maintype()) else {
+            continue;
+        };
+        check_compatible(ua, ub)?;
+        if ua.maintype() == MainType::Instance {
+            let nbytes = (ua.bit_length().min(ub.bit_length()) / 8) as usize;
+            instance_match = Some(ua.digest()[..nbytes] == ub.digest()[..nbytes]);
+        } else {
+            units.push(unit_distance(ua, ub));
+        }
+    }
+
+    Ok(CompareResult {
+        units,
+        instance_match,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::{Version, encode_component};
+
+    fn unit(mtype: MainType, stype: SubType, bits: u32, digest: &[u8]) -> String {
+        encode_component(mtype, stype, Version::V0, bits, digest).unwrap()
+    }
+
+    #[test]
+    fn test_hamming_distance_partial_byte() {
+        assert_eq!(hamming_distance(&[0xFF], &[0x00], 8), 8);
+        assert_eq!(hamming_distance(&[0xFF], &[0x00], 3), 3);
+        assert_eq!(hamming_distance(&[0xF0, 0x0F], &[0x00, 0x00], 12), 4);
+        assert_eq!(hamming_distance(&[0xAB, 0xCD], &[0xAB, 0xCD], 16), 0);
+    }
+
+    #[test]
+    fn test_iscc_distance_identical() {
+        let a = unit(MainType::Data, SubType::None, 64, &[0x5A; 8]);
+        assert_eq!(iscc_distance(&a, &format!("ISCC:{a}")).unwrap(), 0);
+        assert_eq!(iscc_similarity(&a, &a).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_iscc_distance_known_value() {
+        let a = unit(MainType::Content, SubType::TEXT, 64, &[0x00; 8]);
+        let mut digest = [0x00u8; 8];
+        digest[0] = 0b1000_0001;
+        digest[7] = 0xFF;
+        let b = unit(MainType::Content, SubType::TEXT, 64, &digest);
+        assert_eq!(iscc_distance(&a, &b).unwrap(), 10);
+        assert_eq!(iscc_similarity(&a, &b).unwrap(), 1.0 - 10.0 / 64.0);
+    }
+
+    #[test]
+    fn test_iscc_distance_truncates_to_shorter() {
+        let mut long = [0x00u8; 16];
+        long[12] = 0xFF; // beyond the first 64 bits
+        let a = unit(MainType::Meta, SubType::None, 64, &[0x00; 8]);
+        let b = unit(MainType::Meta, SubType::None, 128, &long);
+        assert_eq!(iscc_distance(&a, &b).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_iscc_distance_rejects_incompatible_subtypes() {
+        let text = unit(MainType::Content, SubType::TEXT, 64, &[0; 8]);
+        let image = unit(MainType::Content, SubType::Image, 64, &[0; 8]);
+        let err = iscc_distance(&text, &image).unwrap_err().to_string();
+        assert!(err.contains("incompatible"), "{err}");
+    }
+
+    #[test]
+    fn test_iscc_distance_rejects_different_maintypes() {
+        let meta = unit(MainType::Meta, SubType::None, 64, &[0; 8]);
+        let data = unit(MainType::Data, SubType::None, 64, &[0; 8]);
+        assert!(iscc_distance(&meta, &data).is_err());
+    }
+
+    #[test]
+    fn test_iscc_distance_rejects_composite() {
+        let data = unit(MainType::Data, SubType::None, 64, &[0; 8]);
+        let inst = unit(MainType::Instance, SubType::None, 64, &[0; 8]);
+        let code = crate::gen_iscc_code_v0(&[&data, &inst], false).unwrap();
+        assert!(iscc_distance(&code.iscc, &code.iscc).is_err());
+    }
+
+    #[test]
+    fn test_iscc_compare_composites() {
+        let meta_a = unit(MainType::Meta, SubType::None, 64, &[0x00; 8]);
+        let meta_b = unit(MainType::Meta, SubType::None, 64, &[0x01; 8]);
+        let text = unit(MainType::Content, SubType::TEXT, 64, &[0x33; 8]);
+        let data_a = unit(MainType::Data, SubType::None, 64, &[0xF0; 8]);
+        let data_b = unit(MainType::Data, SubType::None, 64, &[0xF1; 8]);
+        let inst = unit(MainType::Instance, SubType::None, 64, &[0x77; 8]);
+
+        let a = crate::gen_iscc_code_v0(&[&meta_a, &text, &data_a, &inst], false).unwrap();
+        let b = crate::gen_iscc_code_v0(&[&meta_b, &text, &data_b, &inst], false).unwrap();
+        let result = iscc_compare(&a.iscc, &b.iscc).unwrap();
+
+        let summary: Vec<(MainType, u32)> = result
+            .units
+            .iter()
+            .map(|u| (u.maintype, u.distance))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (MainType::Meta, 8),
+                (MainType::Content, 0),
+                (MainType::Data, 8)
+            ]
+        );
+        assert_eq!(result.instance_match, Some(true));
+    }
+
+    #[test]
+    fn test_iscc_compare_unit_against_composite() {
+        let data = unit(MainType::Data, SubType::None, 256, &[0xAA; 32]);
+        let inst = unit(MainType::Instance, SubType::None, 256, &[0x11; 32]);
+        let code = crate::gen_iscc_code_v0(&[&data, &inst], false).unwrap();
+
+        let result = iscc_compare(&inst, &code.iscc).unwrap();
+        assert!(result.units.is_empty());
+        assert_eq!(result.instance_match, Some(true));
+
+        let result = iscc_compare(&data, &code.iscc).unwrap();
+        assert_eq!(result.units.len(), 1);
+        assert_eq!(result.units[0].bits, 64);
+        assert_eq!(result.units[0].distance, 0);
+        assert_eq!(result.instance_match, None);
+    }
+
+    #[test]
+    fn test_iscc_compare_instance_mismatch() {
+        let a = unit(MainType::Instance, SubType::None, 64, &[0x00; 8]);
+        let b = unit(MainType::Instance, SubType::None, 64, &[0x01; 8]);
+        assert_eq!(iscc_compare(&a, &b).unwrap().instance_match, Some(false));
+    }
+
+    #[test]
+    fn test_iscc_compare_rejects_incompatible_content() {
+        let text = unit(MainType::Content, SubType::TEXT, 64, &[0; 8]);
+        let image = unit(MainType::Content, SubType::Image, 64, &[0; 8]);
+        let data = unit(MainType::Data, SubType::None, 64, &[0; 8]);
+        let inst = unit(MainType::Instance, SubType::None, 64, &[0; 8]);
+        let a = crate::gen_iscc_code_v0(&[&text, &data, &inst], false).unwrap();
+        let b = crate::gen_iscc_code_v0(&[&image, &data, &inst], false).unwrap();
+        assert!(iscc_compare(&a.iscc, &b.iscc).is_err());
+        assert!(iscc_compare(&text, &image).is_err());
+    }
+
+    #[test]
+    fn test_iscc_compare_conformance_self_similarity() {
+        let json_str = include_str!("../tests/data.json");
+        let data: serde_json::Value = serde_json::from_str(json_str).unwrap();
+        for (name, tc) in data["gen_iscc_code_v0"].as_object().unwrap() {
+            let iscc = tc["outputs"]["iscc"].as_str().unwrap();
+            let result = iscc_compare(iscc, iscc).unwrap();
+            assert!(
+                result.units.iter().all(|u| u.distance == 0),
+                "non-zero self distance in {name}"
+            );
+            assert_eq!(result.instance_match, Some(true), "{name}");
+        }
+    }
+}
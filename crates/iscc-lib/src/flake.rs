@@ -0,0 +1,226 @@
+//! Flake-Code generation: time-ordered unique identifiers in the ISCC namespace.
+//!
+//! A Flake-Code body starts with a 48-bit big-endian millisecond timestamp,
+//! followed by an 8-bit sequence counter and random bits filling the rest of
+//! the 64- or 128-bit body. Codes produced by one [`FlakeGenerator`] sort
+//! strictly ascending: calls within the same millisecond (or after the clock
+//! steps backwards) reuse the last timestamp and bump the counter, and a
+//! counter overflow advances the timestamp by one millisecond.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::codec::{self, IsccUnit};
+use crate::{FlakeCodeResult, IsccError, IsccResult};
+
+/// Number of bytes used by the millisecond timestamp.
+const TIMESTAMP_BYTES: usize = 6;
+
+/// Largest timestamp representable in 48 bits.
+const TIMESTAMP_MAX: u64 = (1 << 48) - 1;
+
+/// Clock source returning milliseconds since the Unix epoch.
+type Clock = Box<dyn FnMut() -> u64 + Send>;
+
+/// Randomness source filling the given buffer.
+type Rng = Box<dyn FnMut(&mut [u8]) + Send>;
+
+/// Stateful Flake-Code generator with injectable clock and randomness.
+///
+/// [`gen_flake_code_v0`](crate::gen_flake_code_v0) uses a process-wide
+/// instance; create your own to control the time and random sources.
+pub struct FlakeGenerator {
+    clock: Clock,
+    rng: Rng,
+    last_ms: u64,
+    counter: u8,
+}
+
+impl FlakeGenerator {
+    /// Create a generator backed by the system clock and OS-seeded randomness.
+    pub fn new() -> Self {
+        Self::with_sources(system_clock_ms, fill_random)
+    }
+
+    /// Create a generator with a custom clock (milliseconds since the Unix
+    /// epoch) and randomness source.
+    pub fn with_sources(
+        clock: impl FnMut() -> u64 + Send + 'static,
+        rng: impl FnMut(&mut [u8]) + Send + 'static,
+    ) -> Self {
+        Self {
+            clock: Box::new(clock),
+            rng: Box::new(rng),
+            last_ms: 0,
+            counter: 0,
+        }
+    }
+
+    /// Produce the next Flake-Code body of `bits` length (64 or 128).
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if `bits` is not 64 or 128, or
+    /// `IsccError::InvalidInput` if the timestamp no longer fits into 48 bits.
+    pub fn next_digest(&mut self, bits: u32) -> IsccResult<Vec<u8>> {
+        if bits != 64 && bits != 128 {
+            return Err(IsccError::InvalidBits {
+                bits,
+                expected: "64 or 128".into(),
+            });
+        }
+        self.advance()?;
+        let mut digest = vec![0u8; bits as usize / 8];
+        digest[..TIMESTAMP_BYTES].copy_from_slice(&self.last_ms.to_be_bytes()[2..]);
+        digest[TIMESTAMP_BYTES] = self.counter;
+        (self.rng)(&mut digest[TIMESTAMP_BYTES + 1..]);
+        Ok(digest)
+    }
+
+    /// Produce the next Flake-Code of `bits` length (64 or 128).
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`FlakeGenerator::next_digest`].
+    pub fn generate(&mut self, bits: u32) -> IsccResult<FlakeCodeResult> {
+        let digest = self.next_digest(bits)?;
+        let unit = IsccUnit::new(
+            codec::MainType::Flake,
+            codec::SubType::None,
+            codec::Version::V0,
+            bits,
+            &digest,
+        )?;
+        Ok(FlakeCodeResult {
+            iscc: unit.to_string(),
+            unit,
+        })
+    }
+
+    /// Move the (timestamp, counter) state strictly forward.
+    fn advance(&mut self) -> IsccResult<()> {
+        let now = (self.clock)();
+        if now > self.last_ms {
+            self.last_ms = now;
+            self.counter = 0;
+        } else if self.counter == u8::MAX {
+            self.last_ms += 1;
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+        }
+        if self.last_ms > TIMESTAMP_MAX {
+            return Err(IsccError::InvalidInput(format!(
+                "Flake-Code timestamp {} exceeds 48 bits",
+                self.last_ms
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for FlakeGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Current wall-clock time in milliseconds since the Unix epoch.
+fn system_clock_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Fill `buf` with non-cryptographic random bytes.
+///
+/// Each `RandomState` carries fresh OS-seeded SipHash keys, which is enough
+/// entropy to disambiguate Flake-Codes without pulling in an RNG dependency.
+fn fill_random(buf: &mut [u8]) {
+    for chunk in buf.chunks_mut(8) {
+        let value = RandomState::new().build_hasher().finish();
+        chunk.copy_from_slice(&value.to_be_bytes()[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// Generator with a shared, manually-advanced clock and constant randomness.
+    fn fixed_generator(start_ms: u64) -> (FlakeGenerator, Arc<AtomicU64>) {
+        let time = Arc::new(AtomicU64::new(start_ms));
+        let clock = Arc::clone(&time);
+        let generator = FlakeGenerator::with_sources(
+            move || clock.load(Ordering::SeqCst),
+            |buf: &mut [u8]| buf.fill(0xAB),
+        );
+        (generator, time)
+    }
+
+    #[test]
+    fn test_flake_layout() {
+        let (mut generator, _) = fixed_generator(0x0102_0304_0506);
+        let digest = generator.next_digest(64).unwrap();
+        assert_eq!(digest, vec![1, 2, 3, 4, 5, 6, 0, 0xAB]);
+        let digest = generator.next_digest(128).unwrap();
+        assert_eq!(&digest[..7], &[1, 2, 3, 4, 5, 6, 1]);
+        assert!(digest[7..].iter().all(|&b| b == 0xAB));
+    }
+
+    #[test]
+    fn test_flake_deterministic() {
+        let (mut a, _) = fixed_generator(1_700_000_000_000);
+        let (mut b, _) = fixed_generator(1_700_000_000_000);
+        assert_eq!(a.generate(64).unwrap(), b.generate(64).unwrap());
+    }
+
+    #[test]
+    fn test_flake_monotonic_same_ms_and_clock_skew() {
+        let (mut generator, time) = fixed_generator(1_000);
+        let mut previous = generator.next_digest(64).unwrap();
+        for step in 0..600 {
+            if step == 300 {
+                // Clock steps backwards: ordering must still hold
+                time.store(500, Ordering::SeqCst);
+            }
+            let next = generator.next_digest(64).unwrap();
+            assert!(next > previous, "not monotonic at step {step}");
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn test_flake_counter_resets_on_new_ms() {
+        let (mut generator, time) = fixed_generator(1_000);
+        generator.next_digest(64).unwrap();
+        assert_eq!(generator.next_digest(64).unwrap()[6], 1);
+        time.store(1_001, Ordering::SeqCst);
+        assert_eq!(generator.next_digest(64).unwrap()[6], 0);
+    }
+
+    #[test]
+    fn test_flake_rejects_invalid_bits() {
+        let (mut generator, _) = fixed_generator(1_000);
+        assert!(generator.next_digest(96).is_err());
+        assert!(generator.next_digest(256).is_err());
+    }
+
+    #[test]
+    fn test_flake_rejects_timestamp_overflow() {
+        let (mut generator, _) = fixed_generator(TIMESTAMP_MAX + 1);
+        assert!(generator.next_digest(64).is_err());
+    }
+
+    #[test]
+    fn test_flake_system_sources() {
+        let mut generator = FlakeGenerator::new();
+        let a = generator.next_digest(128).unwrap();
+        let b = generator.next_digest(128).unwrap();
+        assert!(b > a);
+        assert_ne!(a[7..], b[7..]);
+    }
+}
@@ -0,0 +1,352 @@
+//! Image normalization for Image-Code generation.
+//!
+//! Reproduces the iscc-sdk preprocessing that turns an arbitrary image into
+//! the 32×32 grayscale input of `gen_image_code_v0`. Each step ports the
+//! arithmetic of its Pillow counterpart: flatten transparency onto white
+//! (`Image.alpha_composite`), trim a uniform border (`ImageChops.difference`
+//! and `getbbox`), convert to grayscale (`convert("L")`) and resize with the
+//! bicubic filter (`resize((32, 32), BICUBIC)`). Tests pin only images whose
+//! iscc-sdk result follows directly from Pillow's definitions, so agreement
+//! on arbitrary images is intended rather than verified.
+
+use std::str::FromStr;
+
+use crate::IsccError;
+
+/// Memory layout of a raw 8-bit pixel buffer.
+///
+/// Pixels are row-major without padding, with interleaved channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PixelFormat {
+    /// One luma byte per pixel (Pillow mode `"L"`).
+    Gray,
+    /// Luma and alpha (Pillow mode `"LA"`).
+    GrayAlpha,
+    /// Red, green, blue (Pillow mode `"RGB"`).
+    Rgb,
+    /// Red, green, blue, alpha (Pillow mode `"RGBA"`, canvas `ImageData`).
+    Rgba,
+}
+
+impl PixelFormat {
+    /// Number of bytes per pixel.
+    pub fn channels(self) -> usize {
+        match self {
+            Self::Gray => 1,
+            Self::GrayAlpha => 2,
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+}
+
+impl FromStr for PixelFormat {
+    type Err = IsccError;
+
+    /// Parse a Pillow mode name (`"L"`, `"LA"`, `"RGB"`, `"RGBA"`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "L" => Ok(Self::Gray),
+            "LA" => Ok(Self::GrayAlpha),
+            "RGB" => Ok(Self::Rgb),
+            "RGBA" => Ok(Self::Rgba),
+            _ => Err(IsccError::InvalidInput(format!(
+                "unknown pixel format {s:?}, expected L, LA, RGB or RGBA"
+            ))),
+        }
+    }
+}
+
+/// Side length of the normalized image.
+const SIZE: usize = 32;
+
+/// Per-channel difference from the corner color above which a pixel counts
+/// as content when trimming borders.
+const TRIM_THRESHOLD: u8 = 100;
+
+/// Fixed-point precision of Pillow's 8-bit resampling coefficients.
+const PRECISION_BITS: u32 = 32 - 8 - 2;
+
+/// Support radius of the bicubic filter.
+const BICUBIC_SUPPORT: f64 = 2.0;
+
+/// Normalize an image to 1024 grayscale pixels (32×32, row-major).
+///
+/// `data` must hold `width * height * format.channels()` bytes with non-zero
+/// dimensions.
+pub(crate) fn normalize(width: usize, height: usize, format: PixelFormat, data: &[u8]) -> Vec<u8> {
+    let rgb = flatten_alpha(&to_rgba(format, data));
+    let (rgb, width, height) = trim_border(rgb, width, height);
+    let gray: Vec<u8> = rgb.chunks_exact(3).map(luma).collect();
+    resize_bicubic(&gray, width, height, SIZE, SIZE)
+}
+
+/// Expand pixels to RGBA8 like Pillow's `convert("RGBA")`.
+fn to_rgba(format: PixelFormat, data: &[u8]) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(data.len() / format.channels() * 4);
+    for px in data.chunks_exact(format.channels()) {
+        match format {
+            PixelFormat::Gray => rgba.extend_from_slice(&[px[0], px[0], px[0], 255]),
+            PixelFormat::GrayAlpha => rgba.extend_from_slice(&[px[0], px[0], px[0], px[1]]),
+            PixelFormat::Rgb => rgba.extend_from_slice(&[px[0], px[1], px[2], 255]),
+            PixelFormat::Rgba => rgba.extend_from_slice(px),
+        }
+    }
+    rgba
+}
+
+/// Composite RGBA pixels over an opaque white background, dropping alpha.
+///
+/// Integer arithmetic of Pillow's `ImagingAlphaComposite` with a white,
+/// fully opaque destination.
+fn flatten_alpha(rgba: &[u8]) -> Vec<u8> {
+    const PRECISION: u32 = 7;
+    let div255 = |a: u32| ((a >> 8) + a) >> 8;
+    let mut rgb = Vec::with_capacity(rgba.len() / 4 * 3);
+    for px in rgba.chunks_exact(4) {
+        let alpha = px[3] as u32;
+        if alpha == 0 {
+            rgb.extend_from_slice(&[255, 255, 255]);
+            continue;
+        }
+        let blend = 255 * (255 - alpha);
+        let outa255 = alpha * 255 + blend;
+        let coef1 = alpha * 255 * 255 * (1 << PRECISION) / outa255;
+        let coef2 = 255 * (1 << PRECISION) - coef1;
+        for &channel in &px[..3] {
+            let tmp = channel as u32 * coef1 + 255 * coef2;
+            rgb.push((div255(tmp + (0x80 << PRECISION)) >> PRECISION) as u8);
+        }
+    }
+    rgb
+}
+
+/// Crop away a border that matches the top-left pixel.
+///
+/// A pixel is content if any channel differs from the corner color by more
+/// than `TRIM_THRESHOLD`. Images without content are returned unchanged.
+fn trim_border(rgb: Vec<u8>, width: usize, height: usize) -> (Vec<u8>, usize, usize) {
+    let corner = [rgb[0], rgb[1], rgb[2]];
+    let (mut x0, mut y0, mut x1, mut y1) = (width, height, 0, 0);
+    for (i, px) in rgb.chunks_exact(3).enumerate() {
+        let is_content = px
+            .iter()
+            .zip(corner)
+            .any(|(&c, bg)| c.abs_diff(bg) > TRIM_THRESHOLD);
+        if is_content {
+            let (x, y) = (i % width, i / width);
+            x0 = x0.min(x);
+            y0 = y0.min(y);
+            x1 = x1.max(x + 1);
+            y1 = y1.max(y + 1);
+        }
+    }
+    if x1 == 0 || (x0, y0, x1, y1) == (0, 0, width, height) {
+        return (rgb, width, height);
+    }
+    let mut cropped = Vec::with_capacity((x1 - x0) * (y1 - y0) * 3);
+    for y in y0..y1 {
+        cropped.extend_from_slice(&rgb[(y * width + x0) * 3..(y * width + x1) * 3]);
+    }
+    (cropped, x1 - x0, y1 - y0)
+}
+
+/// ITU-R 601-2 luma of an RGB pixel, rounded as in Pillow's `rgb2l`.
+fn luma(px: &[u8]) -> u8 {
+    let l24 = px[0] as u32 * 19595 + px[1] as u32 * 38470 + px[2] as u32 * 7471 + 0x8000;
+    (l24 >> 16) as u8
+}
+
+/// Bicubic filter kernel with `a = -0.5`.
+fn bicubic(x: f64) -> f64 {
+    const A: f64 = -0.5;
+    let x = x.abs();
+    if x < 1.0 {
+        ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0
+    } else if x < 2.0 {
+        (((x - 5.0) * x + 8.0) * x - 4.0) * A
+    } else {
+        0.0
+    }
+}
+
+/// Resampling window of one output pixel: first input index and weights.
+struct Window {
+    start: usize,
+    weights: Vec<i32>,
+}
+
+/// Fixed-point filter windows for resampling `in_size` to `out_size`.
+///
+/// Mirrors Pillow's `precompute_coeffs` followed by `normalize_coeffs_8bpc`.
+fn windows(in_size: usize, out_size: usize) -> Vec<Window> {
+    let scale = in_size as f64 / out_size as f64;
+    let filterscale = scale.max(1.0);
+    let support = BICUBIC_SUPPORT * filterscale;
+    // Pillow multiplies by the reciprocal, which rounds differently from
+    // dividing by `filterscale`.
+    let ss = 1.0 / filterscale;
+    (0..out_size)
+        .map(|xx| {
+            let center = (xx as f64 + 0.5) * scale;
+            // Truncation toward zero matches the C casts.
+            let xmin = ((center - support + 0.5) as i64).max(0) as usize;
+            let xmax = ((center + support + 0.5) as i64).min(in_size as i64) as usize;
+            let raw: Vec<f64> = (xmin..xmax)
+                .map(|x| bicubic((x as f64 - center + 0.5) * ss))
+                .collect();
+            let total: f64 = raw.iter().sum();
+            let weights = raw
+                .into_iter()
+                .map(|w| {
+                    let w = if total != 0.0 { w / total } else { w };
+                    let fixed = w * (1u32 << PRECISION_BITS) as f64;
+                    if w < 0.0 {
+                        (fixed - 0.5) as i32
+                    } else {
+                        (fixed + 0.5) as i32
+                    }
+                })
+                .collect();
+            Window {
+                start: xmin,
+                weights,
+            }
+        })
+        .collect()
+}
+
+/// Weighted sum of `src[offset + i * stride]` over the window, clipped to 8 bits.
+fn convolve(src: &[u8], offset: usize, stride: usize, window: &Window) -> u8 {
+    let mut acc: i32 = 1 << (PRECISION_BITS - 1);
+    for (i, &w) in window.weights.iter().enumerate() {
+        acc += src[offset + i * stride] as i32 * w;
+    }
+    (acc >> PRECISION_BITS).clamp(0, 255) as u8
+}
+
+/// Resize a grayscale image like Pillow's `Image.resize(..., BICUBIC)`.
+///
+/// Horizontal pass first (over the rows the vertical pass needs), rounding
+/// to 8 bits in between, then the vertical pass. Axes whose size does not
+/// change are passed through.
+fn resize_bicubic(
+    gray: &[u8],
+    width: usize,
+    height: usize,
+    out_width: usize,
+    out_height: usize,
+) -> Vec<u8> {
+    let horiz = windows(width, out_width);
+    let vert = windows(height, out_height);
+
+    let (rows, row_offset) = if out_width != width {
+        let first = vert[0].start;
+        let last = vert.last().map_or(height, |w| w.start + w.weights.len());
+        let mut rows = Vec::with_capacity(out_width * (last - first));
+        for y in first..last {
+            rows.extend(
+                horiz
+                    .iter()
+                    .map(|w| convolve(gray, y * width + w.start, 1, w)),
+            );
+        }
+        (rows, first)
+    } else {
+        (gray.to_vec(), 0)
+    };
+
+    if out_height == height {
+        return rows;
+    }
+    let mut out = Vec::with_capacity(out_width * out_height);
+    for w in &vert {
+        let first = (w.start - row_offset) * out_width;
+        out.extend((0..out_width).map(|x| convolve(&rows, first + x, out_width, w)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixel_format_parse_and_expand() {
+        assert_eq!("rgba".parse::<PixelFormat>().unwrap(), PixelFormat::Rgba);
+        assert_eq!("L".parse::<PixelFormat>().unwrap(), PixelFormat::Gray);
+        assert!(matches!(
+            "CMYK".parse::<PixelFormat>(),
+            Err(IsccError::InvalidInput(_))
+        ));
+        assert_eq!(
+            to_rgba(PixelFormat::GrayAlpha, &[7, 9, 8, 0]),
+            [7, 7, 7, 9, 8, 8, 8, 0]
+        );
+        assert_eq!(to_rgba(PixelFormat::Rgb, &[1, 2, 3]), [1, 2, 3, 255]);
+    }
+
+    #[test]
+    fn test_flatten_alpha_matches_pillow_composite() {
+        let rgba = [10, 20, 30, 255, 0, 0, 0, 0, 0, 0, 0, 128, 200, 100, 50, 1];
+        // Opaque pixels keep their color, transparent ones become white and
+        // partial alpha blends towards white.
+        assert_eq!(
+            flatten_alpha(&rgba),
+            [10, 20, 30, 255, 255, 255, 127, 127, 127, 255, 254, 254]
+        );
+    }
+
+    #[test]
+    fn test_trim_border_crops_to_content() {
+        let (width, height) = (5, 4);
+        let mut rgb = vec![255u8; width * height * 3];
+        // Content pixels at (1, 1) and (3, 2); a faint pixel below threshold.
+        rgb[(width + 1) * 3] = 0;
+        rgb[(2 * width + 3) * 3 + 1] = 0;
+        rgb[(3 * width + 4) * 3] = 200;
+        let (cropped, w, h) = trim_border(rgb, width, height);
+        assert_eq!((w, h), (3, 2));
+        assert_eq!(cropped.len(), 3 * 2 * 3);
+        assert_eq!(cropped[0], 0);
+
+        let uniform = vec![7u8; 4 * 3];
+        assert_eq!(trim_border(uniform.clone(), 2, 2), (uniform, 2, 2));
+    }
+
+    #[test]
+    fn test_luma_weights() {
+        assert_eq!(luma(&[255, 255, 255]), 255);
+        assert_eq!(luma(&[0, 0, 0]), 0);
+        assert_eq!(luma(&[255, 0, 0]), 76);
+        assert_eq!(luma(&[0, 255, 0]), 150);
+        assert_eq!(luma(&[0, 0, 255]), 29);
+    }
+
+    #[test]
+    fn test_resize_bicubic() {
+        // Same size is a copy.
+        let gray: Vec<u8> = (0..=255).cycle().take(32 * 32).collect();
+        assert_eq!(resize_bicubic(&gray, 32, 32, 32, 32), gray);
+
+        // Uniform images stay uniform in both directions.
+        let flat = vec![93u8; 100 * 60];
+        assert!(
+            resize_bicubic(&flat, 100, 60, 32, 32)
+                .iter()
+                .all(|&p| p == 93)
+        );
+        let small = vec![200u8; 5 * 7];
+        assert!(
+            resize_bicubic(&small, 5, 7, 32, 32)
+                .iter()
+                .all(|&p| p == 200)
+        );
+
+        // A 2:1 horizontal gradient downsample keeps it monotonic.
+        let ramp: Vec<u8> = (0..64).map(|x| (x * 4) as u8).collect();
+        let out = resize_bicubic(&ramp, 64, 1, 32, 1);
+        assert!(out.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
@@ -0,0 +1,545 @@
+//! In-memory nearest-neighbour index over ISCC-UNITs in Hamming space.
+//!
+//! Units are grouped into separate spaces by the `MainType` and `SubType`
+//! from their header, so a query only ever meets units it can be compared
+//! with. Within a space, the first 64 digest bits are split into four 16-bit
+//! chunks and indexed with multi-index hashing: two units within Hamming
+//! distance `r` must agree on at least one chunk up to distance `r / 4`, so
+//! probing the chunk tables yields an exact candidate set that is then
+//! verified on the full digest. When probing would touch more buckets than
+//! the space holds entries, the query falls back to a linear scan.
+//!
+//! As with [`iscc_distance`](crate::iscc_distance), units of different bit
+//! lengths are compared on the length of the shorter one.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::codec::{self, IsccUnit, MainType, SubType};
+use crate::compare::hamming_distance;
+use crate::{IsccError, IsccResult};
+
+/// Number of 16-bit chunks covering the 64-bit indexed prefix.
+const CHUNKS: usize = 4;
+
+/// Minimum digest length (in bits) of indexable units.
+const MIN_BITS: u32 = 64;
+
+/// Maximum digest length (in bits) of stored units, the digest slot of an
+/// index file record.
+const MAX_BITS: u32 = 256;
+
+/// Key of a Hamming space: units are only compared within one space.
+type SpaceKey = (MainType, SubType);
+
+/// A single match returned by [`IsccIndex`] queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IndexMatch {
+    /// External ID the unit was inserted under.
+    pub id: u64,
+    /// Hamming distance to the query.
+    pub distance: u32,
+    /// Number of digest bits compared (the shorter of both units).
+    pub bits: u32,
+}
+
+/// One indexed unit.
+#[derive(Debug, Clone)]
+struct Entry {
+    id: u64,
+    unit: IsccUnit,
+}
+
+/// All units sharing one `MainType`/`SubType`.
+#[derive(Debug, Default)]
+struct Space {
+    /// Slot storage; removed slots are `None` and recycled via `free`.
+    entries: Vec<Option<Entry>>,
+    free: Vec<u32>,
+    /// Per-chunk tables mapping a 16-bit chunk value to slots.
+    tables: [HashMap<u16, Vec<u32>>; CHUNKS],
+    live: usize,
+}
+
+impl Space {
+    /// Store `entry` and index its prefix chunks, returning the slot.
+    fn insert(&mut self, entry: Entry) -> u32 {
+        let chunks = prefix_chunks(entry.unit.digest());
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.entries[slot as usize] = Some(entry);
+                slot
+            }
+            None => {
+                self.entries.push(Some(entry));
+                (self.entries.len() - 1) as u32
+            }
+        };
+        for (table, chunk) in self.tables.iter_mut().zip(chunks) {
+            table.entry(chunk).or_default().push(slot);
+        }
+        self.live += 1;
+        slot
+    }
+
+    /// Remove the entry at `slot` from storage and all chunk tables.
+    fn remove(&mut self, slot: u32) -> Option<Entry> {
+        let entry = self.entries.get_mut(slot as usize)?.take()?;
+        let chunks = prefix_chunks(entry.unit.digest());
+        for (table, chunk) in self.tables.iter_mut().zip(chunks) {
+            if let Some(bucket) = table.get_mut(&chunk) {
+                bucket.retain(|&s| s != slot);
+                if bucket.is_empty() {
+                    table.remove(&chunk);
+                }
+            }
+        }
+        self.free.push(slot);
+        self.live -= 1;
+        Some(entry)
+    }
+
+    /// Collect all entries within `max_distance` of `query`.
+    fn radius(&self, query: &IsccUnit, max_distance: u32) -> Vec<IndexMatch> {
+        let masks = chunk_masks(max_distance / CHUNKS as u32);
+        let mut matches = Vec::new();
+        if masks.len() * CHUNKS >= self.live {
+            for entry in self.entries.iter().flatten() {
+                push_if_within(&mut matches, entry, query, max_distance);
+            }
+            return matches;
+        }
+        let mut candidates = Vec::new();
+        for (table, chunk) in self.tables.iter().zip(prefix_chunks(query.digest())) {
+            for mask in &masks {
+                if let Some(bucket) = table.get(&(chunk ^ mask)) {
+                    candidates.extend_from_slice(bucket);
+                }
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        for slot in candidates {
+            if let Some(entry) = &self.entries[slot as usize] {
+                push_if_within(&mut matches, entry, query, max_distance);
+            }
+        }
+        matches
+    }
+}
+
+/// Append `entry` to `matches` if it lies within `max_distance` of `query`.
+fn push_if_within(
+    matches: &mut Vec<IndexMatch>,
+    entry: &Entry,
+    query: &IsccUnit,
+    max_distance: u32,
+) {
+    let bits = entry.unit.bit_length().min(query.bit_length());
+    let distance = hamming_distance(entry.unit.digest(), query.digest(), bits);
+    if distance <= max_distance {
+        matches.push(IndexMatch {
+            id: entry.id,
+            distance,
+            bits,
+        });
+    }
+}
+
+/// Split the first 64 digest bits into big-endian 16-bit chunks.
+fn prefix_chunks(digest: &[u8]) -> [u16; CHUNKS] {
+    std::array::from_fn(|i| u16::from_be_bytes([digest[2 * i], digest[2 * i + 1]]))
+}
+
+/// All 16-bit XOR masks with at most `max_bits` bits set.
+fn chunk_masks(max_bits: u32) -> Vec<u16> {
+    (0..=u16::MAX)
+        .filter(|m| m.count_ones() <= max_bits)
+        .collect()
+}
+
+/// Sort matches by distance, then by ID.
+pub(crate) fn sort_matches(matches: &mut [IndexMatch]) {
+    matches.sort_unstable_by_key(|m| (m.distance, m.id));
+}
+
+/// Hamming-space nearest-neighbour index over ISCC-UNITs.
+///
+/// Each unit is stored under a caller-provided `u64` ID. An ID holds at most
+/// one unit per `MainType`/`SubType` space, so all units of one ISCC-CODE can
+/// share an ID. Only units with at least 64 digest bits are indexable.
+#[derive(Debug, Default)]
+pub struct IsccIndex {
+    spaces: BTreeMap<SpaceKey, Space>,
+    /// Slot of every unit stored under an ID, per space.
+    slots: HashMap<u64, Vec<(SpaceKey, u32)>>,
+}
+
+impl IsccIndex {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of indexed units across all spaces.
+    pub fn len(&self) -> usize {
+        self.spaces.values().map(|space| space.live).sum()
+    }
+
+    /// Whether the index holds no units.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether any unit is stored under `id`.
+    pub fn contains(&self, id: u64) -> bool {
+        self.slots.contains_key(&id)
+    }
+
+    /// Index an ISCC-UNIT string, or every unit of a composite ISCC-CODE,
+    /// under `id`.
+    ///
+    /// Accepts the same inputs as [`iscc_decompose`](crate::iscc_decompose),
+    /// including the unit strings it returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `iscc` cannot be decoded, or
+    /// `IsccError::InvalidBits` if a unit has fewer than 64 or more than 256
+    /// digest bits. Nothing is inserted on error.
+    pub fn insert(&mut self, id: u64, iscc: &str) -> IsccResult<()> {
+        let units = codec::decompose_units(iscc)?;
+        for unit in &units {
+            check_insert_bits(unit)?;
+        }
+        for unit in units {
+            self.insert_unit(id, unit)?;
+        }
+        Ok(())
+    }
+
+    /// Index a decoded unit under `id`, replacing any unit `id` already holds
+    /// in the same `MainType`/`SubType` space.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if the unit has fewer than 64 or more
+    /// than 256 digest bits.
+    pub fn insert_unit(&mut self, id: u64, unit: IsccUnit) -> IsccResult<()> {
+        check_insert_bits(&unit)?;
+        let key = (unit.maintype(), unit.subtype());
+        let locations = self.slots.entry(id).or_default();
+        let space = self.spaces.entry(key).or_default();
+        if let Some(pos) = locations.iter().position(|(k, _)| *k == key) {
+            space.remove(locations.swap_remove(pos).1);
+        }
+        let slot = space.insert(Entry { id, unit });
+        locations.push((key, slot));
+        Ok(())
+    }
+
+    /// Remove all units stored under `id`. Returns `true` if any were removed.
+    pub fn remove(&mut self, id: u64) -> bool {
+        let Some(locations) = self.slots.remove(&id) else {
+            return false;
+        };
+        for (key, slot) in locations {
+            if let Some(space) = self.spaces.get_mut(&key) {
+                space.remove(slot);
+                if space.live == 0 {
+                    self.spaces.remove(&key);
+                }
+            }
+        }
+        true
+    }
+
+    /// Return all units within Hamming distance `max_distance` of the query
+    /// unit, sorted by distance and then ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `query` is not a single valid ISCC-UNIT with at
+    /// least 64 digest bits.
+    pub fn radius(&self, query: &str, max_distance: u32) -> IsccResult<Vec<IndexMatch>> {
+        self.radius_unit(&parse_query(query)?, max_distance)
+    }
+
+    /// Same as [`IsccIndex::radius`] for a decoded unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if the unit has fewer than 64 digest bits.
+    pub fn radius_unit(&self, query: &IsccUnit, max_distance: u32) -> IsccResult<Vec<IndexMatch>> {
+        check_bits(query)?;
+        let mut matches = match self.spaces.get(&(query.maintype(), query.subtype())) {
+            Some(space) => space.radius(query, max_distance),
+            None => Vec::new(),
+        };
+        sort_matches(&mut matches);
+        Ok(matches)
+    }
+
+    /// Return the `k` units nearest to the query unit, sorted by distance and
+    /// then ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `query` is not a single valid ISCC-UNIT with at
+    /// least 64 digest bits.
+    pub fn knn(&self, query: &str, k: usize) -> IsccResult<Vec<IndexMatch>> {
+        self.knn_unit(&parse_query(query)?, k)
+    }
+
+    /// Same as [`IsccIndex::knn`] for a decoded unit.
+    ///
+    /// Searches with a growing radius until at least `k` units are found, so
+    /// the result is exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if the unit has fewer than 64 digest bits.
+    pub fn knn_unit(&self, query: &IsccUnit, k: usize) -> IsccResult<Vec<IndexMatch>> {
+        check_bits(query)?;
+        let Some(space) = self.spaces.get(&(query.maintype(), query.subtype())) else {
+            return Ok(Vec::new());
+        };
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        let mut max_distance = 8;
+        let mut matches = loop {
+            let matches = space.radius(query, max_distance);
+            if matches.len() >= k || max_distance >= query.bit_length() {
+                break matches;
+            }
+            max_distance *= 2;
+        };
+        sort_matches(&mut matches);
+        matches.truncate(k);
+        Ok(matches)
+    }
+
+    /// Iterate over all indexed `(id, unit)` pairs in space order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &IsccUnit)> {
+        self.spaces
+            .values()
+            .flat_map(|space| space.entries.iter().flatten())
+            .map(|entry| (entry.id, &entry.unit))
+    }
+}
+
+/// Reject units too short for the 64-bit indexed prefix.
+pub(crate) fn check_bits(unit: &IsccUnit) -> IsccResult<()> {
+    if unit.bit_length() < MIN_BITS {
+        return Err(IsccError::InvalidBits {
+            bits: unit.bit_length(),
+            expected: ">= 64 for indexing".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Reject units that cannot be stored: too short for the indexed prefix or
+/// too long for the digest slot of an index file record.
+pub(crate) fn check_insert_bits(unit: &IsccUnit) -> IsccResult<()> {
+    check_bits(unit)?;
+    if unit.bit_length() > MAX_BITS {
+        return Err(IsccError::InvalidBits {
+            bits: unit.bit_length(),
+            expected: "<= 256 for indexing".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Decode a query string that must contain exactly one ISCC-UNIT.
+pub(crate) fn parse_query(query: &str) -> IsccResult<IsccUnit> {
+    let mut units = codec::decompose_units(query)?;
+    if units.len() != 1 {
+        return Err(IsccError::InvalidInput(format!(
+            "expected a single ISCC-UNIT query, got {} units",
+            units.len()
+        )));
+    }
+    Ok(units.remove(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::{Version, encode_component};
+
+    fn unit(mtype: MainType, stype: SubType, bits: u32, digest: &[u8]) -> String {
+        encode_component(mtype, stype, Version::V0, bits, digest).unwrap()
+    }
+
+    /// 64-bit Data-Code whose digest differs from all-zero in the lowest `flips` bits.
+    fn data_unit(flips: u32) -> String {
+        let value = if flips == 0 {
+            0
+        } else {
+            u64::MAX >> (64 - flips)
+        };
+        unit(MainType::Data, SubType::None, 64, &value.to_be_bytes())
+    }
+
+    #[test]
+    fn test_index_radius_and_knn() {
+        let mut index = IsccIndex::new();
+        for flips in [0, 1, 3, 10, 40] {
+            index.insert(u64::from(flips), &data_unit(flips)).unwrap();
+        }
+        assert_eq!(index.len(), 5);
+
+        let ids: Vec<u64> = index
+            .radius(&data_unit(0), 3)
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec![0, 1, 3]);
+
+        let knn = index.knn(&data_unit(2), 2).unwrap();
+        assert_eq!(knn.len(), 2);
+        assert_eq!((knn[0].id, knn[0].distance), (1, 1));
+        assert_eq!((knn[1].id, knn[1].distance), (3, 1));
+        assert_eq!(knn[0].bits, 64);
+
+        let all = index.knn(&data_unit(0), 10).unwrap();
+        assert_eq!(all.last().unwrap().distance, 40);
+        assert_eq!(all.len(), 5);
+    }
+
+    #[test]
+    fn test_index_matches_brute_force() {
+        // Pseudo-random digests: probe path and linear scan must agree with brute force
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut index = IsccIndex::new();
+        let mut units = Vec::new();
+        for id in 0..2000u64 {
+            let digest = next().to_be_bytes();
+            let code = unit(MainType::Content, SubType::Image, 64, &digest);
+            index.insert(id, &code).unwrap();
+            units.push(code);
+        }
+        let query = &units[7];
+        for max_distance in [0, 4, 12, 24] {
+            let expected: Vec<u64> = units
+                .iter()
+                .enumerate()
+                .filter(|(_, u)| crate::iscc_distance(query, u).unwrap() <= max_distance)
+                .map(|(id, _)| id as u64)
+                .collect();
+            let mut got: Vec<u64> = index
+                .radius(query, max_distance)
+                .unwrap()
+                .iter()
+                .map(|m| m.id)
+                .collect();
+            got.sort_unstable();
+            assert_eq!(got, expected, "radius {max_distance}");
+        }
+    }
+
+    #[test]
+    fn test_index_separate_spaces() {
+        let mut index = IsccIndex::new();
+        let text = unit(MainType::Content, SubType::TEXT, 64, &[0; 8]);
+        let image = unit(MainType::Content, SubType::Image, 64, &[0; 8]);
+        let data = unit(MainType::Data, SubType::None, 64, &[0; 8]);
+        index.insert(1, &text).unwrap();
+        index.insert(2, &image).unwrap();
+        index.insert(3, &data).unwrap();
+        let ids: Vec<u64> = index
+            .knn(&image, 10)
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec![2]);
+        let meta = unit(MainType::Meta, SubType::None, 64, &[0; 8]);
+        assert!(index.knn(&meta, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_index_mixed_bit_lengths() {
+        let mut index = IsccIndex::new();
+        let mut long = [0u8; 32];
+        long[20] = 0xFF; // beyond the first 64 bits
+        index
+            .insert(1, &unit(MainType::Meta, SubType::None, 256, &long))
+            .unwrap();
+        let short = unit(MainType::Meta, SubType::None, 64, &[0; 8]);
+        let matches = index.radius(&short, 0).unwrap();
+        assert_eq!(
+            matches,
+            vec![IndexMatch {
+                id: 1,
+                distance: 0,
+                bits: 64
+            }]
+        );
+        let full = unit(MainType::Meta, SubType::None, 256, &[0; 32]);
+        assert_eq!(index.knn(&full, 1).unwrap()[0].distance, 8);
+    }
+
+    #[test]
+    fn test_index_composite_and_decomposed_units() {
+        let data = unit(MainType::Data, SubType::None, 64, &[0xAA; 8]);
+        let inst = unit(MainType::Instance, SubType::None, 64, &[0xBB; 8]);
+        let code = crate::gen_iscc_code_v0(&[&data, &inst], false).unwrap();
+
+        let mut index = IsccIndex::new();
+        index.insert(7, &code.iscc).unwrap();
+        assert_eq!(index.len(), 2);
+        for part in crate::iscc_decompose(&code.iscc).unwrap() {
+            assert_eq!(index.knn(&part, 1).unwrap()[0].id, 7);
+        }
+        assert!(index.knn(&code.iscc, 1).is_err());
+    }
+
+    #[test]
+    fn test_index_replace_and_remove() {
+        let mut index = IsccIndex::new();
+        index.insert(1, &data_unit(0)).unwrap();
+        index.insert(1, &data_unit(5)).unwrap();
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.knn(&data_unit(0), 1).unwrap()[0].distance, 5);
+
+        index.insert(2, &data_unit(1)).unwrap();
+        assert!(index.remove(1));
+        assert!(!index.remove(1));
+        assert!(!index.contains(1));
+        let ids: Vec<u64> = index
+            .knn(&data_unit(0), 5)
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec![2]);
+
+        // Freed slots are reused without leaking stale matches
+        index.insert(3, &data_unit(0)).unwrap();
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_index_rejects_short_units() {
+        let mut index = IsccIndex::new();
+        let short = unit(MainType::Data, SubType::None, 32, &[0; 4]);
+        assert!(matches!(
+            index.insert(1, &short),
+            Err(IsccError::InvalidBits { bits: 32, .. })
+        ));
+        assert!(index.is_empty());
+        assert!(index.knn(&short, 1).is_err());
+    }
+}
@@ -0,0 +1,934 @@
+//! Persistent on-disk format for [`IsccIndex`] contents.
+//!
+//! An index file is a 16-byte file header followed by append-only segments.
+//! All integers are little-endian.
+//!
+//! ```text
+//! file header  magic "ISCC-IDX" | version: u32 | record size: u32
+//! segment      marker "ISEG" | checksum: u32 | record count: u64 | records
+//! record       id: u64 | maintype: u8 | subtype: u8 | version: u8 | kind: u8
+//!              | bit length: u16 | reserved: u16 | digest: [u8; 32]
+//! ```
+//!
+//! Records are fixed-size and 8-byte aligned, so a memory-mapped file can be
+//! scanned in place without decoding. The segment checksum is XXH32 over the
+//! record count and record bytes. A record of kind `0` stores a unit under an
+//! ID, replacing the ID's earlier unit in the same `MainType`/`SubType`
+//! space; kind `1` is a tombstone that removes all earlier units of the ID.
+//! Records are replayed in file order, mirroring [`IsccIndex::insert_unit`]
+//! and [`IsccIndex::remove`].
+//!
+//! New segments are only ever appended. [`IndexFile::compact`] rewrites a
+//! file with its live units in a single segment and atomically replaces the
+//! original, so readers holding the old file keep a consistent view. Writers
+//! are not locked against each other: callers must serialize appends and
+//! compaction of the same file.
+
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crate::codec::{self, IsccUnit, MainType, SubType, Version};
+use crate::compare::hamming_distance;
+use crate::index::{
+    IndexMatch, IsccIndex, check_bits, check_insert_bits, parse_query, sort_matches,
+};
+use crate::mmap::{self, FileBytes};
+use crate::{IsccError, IsccResult};
+
+/// Magic bytes at the start of every index file.
+const MAGIC: &[u8; 8] = b"ISCC-IDX";
+
+/// Current file format version.
+pub const INDEX_FILE_VERSION: u32 = 1;
+
+/// Marker at the start of every segment.
+const SEGMENT_MARKER: &[u8; 4] = b"ISEG";
+
+/// Size of the file header in bytes.
+const FILE_HEADER_SIZE: usize = 16;
+
+/// Size of a segment header in bytes.
+const SEGMENT_HEADER_SIZE: usize = 16;
+
+/// Size of one record in bytes.
+const RECORD_SIZE: usize = 48;
+
+/// Offset of the zero-padded digest within a record.
+const DIGEST_OFFSET: usize = 16;
+
+/// Record kind storing a unit.
+const KIND_UNIT: u8 = 0;
+
+/// Record kind removing all units of an ID.
+const KIND_TOMBSTONE: u8 = 1;
+
+type Record = [u8; RECORD_SIZE];
+
+/// Encode the file header.
+fn file_header() -> [u8; FILE_HEADER_SIZE] {
+    let mut header = [0u8; FILE_HEADER_SIZE];
+    header[..8].copy_from_slice(MAGIC);
+    header[8..12].copy_from_slice(&INDEX_FILE_VERSION.to_le_bytes());
+    header[12..].copy_from_slice(&(RECORD_SIZE as u32).to_le_bytes());
+    header
+}
+
+/// Validate the file header at the start of `data`.
+fn check_file_header(data: &[u8]) -> IsccResult<()> {
+    if data.len() < FILE_HEADER_SIZE {
+        return Err(IsccError::Truncated {
+            expected: FILE_HEADER_SIZE,
+            actual: data.len(),
+        });
+    }
+    if &data[..8] != MAGIC {
+        return Err(IsccError::InvalidInput("not an ISCC index file".into()));
+    }
+    let version = read_u32(data, 8);
+    let record_size = read_u32(data, 12);
+    if version != INDEX_FILE_VERSION || record_size != RECORD_SIZE as u32 {
+        return Err(IsccError::Unsupported(format!(
+            "index file version {version} with {record_size}-byte records"
+        )));
+    }
+    Ok(())
+}
+
+/// Checksum over a segment's record count and records.
+fn segment_checksum(count: u64, records: &[u8]) -> u32 {
+    let mut hasher = xxhash_rust::xxh32::Xxh32::new(0);
+    hasher.update(&count.to_le_bytes());
+    hasher.update(records);
+    hasher.digest()
+}
+
+/// Encode `records` as one segment.
+fn encode_segment(records: &[Record]) -> Vec<u8> {
+    let count = records.len() as u64;
+    let body = records.concat();
+    let mut out = Vec::with_capacity(SEGMENT_HEADER_SIZE + body.len());
+    out.extend_from_slice(SEGMENT_MARKER);
+    out.extend_from_slice(&segment_checksum(count, &body).to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&body);
+    out
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap_or_default())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap_or_default())
+}
+
+/// Encode a unit record.
+fn unit_record(id: u64, unit: &IsccUnit) -> Record {
+    let mut record = [0u8; RECORD_SIZE];
+    record[..8].copy_from_slice(&id.to_le_bytes());
+    record[8] = unit.maintype() as u8;
+    record[9] = unit.subtype() as u8;
+    record[10] = unit.version() as u8;
+    record[11] = KIND_UNIT;
+    record[12..14].copy_from_slice(&(unit.bit_length() as u16).to_le_bytes());
+    let digest = unit.digest();
+    record[DIGEST_OFFSET..DIGEST_OFFSET + digest.len()].copy_from_slice(digest);
+    record
+}
+
+/// Encode a tombstone record.
+fn tombstone_record(id: u64) -> Record {
+    let mut record = [0u8; RECORD_SIZE];
+    record[..8].copy_from_slice(&id.to_le_bytes());
+    record[11] = KIND_TOMBSTONE;
+    record
+}
+
+fn record_id(record: &[u8]) -> u64 {
+    read_u64(record, 0)
+}
+
+fn record_bits(record: &[u8]) -> u32 {
+    u32::from(u16::from_le_bytes([record[12], record[13]]))
+}
+
+/// Decode the unit stored in a unit record.
+fn record_unit(record: &[u8]) -> IsccResult<IsccUnit> {
+    let unit = IsccUnit::new(
+        MainType::try_from(record[8])?,
+        SubType::try_from(record[9])?,
+        Version::try_from(record[10])?,
+        record_bits(record),
+        &record[DIGEST_OFFSET..],
+    )?;
+    check_bits(&unit)?;
+    Ok(unit)
+}
+
+/// Byte range of one segment's records.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    offset: usize,
+    count: usize,
+}
+
+/// Validate all segments in `data` and return their record ranges.
+///
+/// A torn trailing segment, left by a writer that crashed mid-append, is
+/// ignored: parsing stops before a final segment whose header or records are
+/// cut off, or whose checksum fails although it ends exactly at the end of
+/// the file.
+fn parse_segments(data: &[u8]) -> IsccResult<Vec<Segment>> {
+    check_file_header(data)?;
+    let mut segments = Vec::new();
+    let mut pos = FILE_HEADER_SIZE;
+    while pos < data.len() {
+        let index = segments.len();
+        let Some(end) = segment_end(&data[pos..data.len().min(pos + SEGMENT_HEADER_SIZE)], pos)?
+        else {
+            break;
+        };
+        if end > data.len() {
+            break;
+        }
+        let offset = pos + SEGMENT_HEADER_SIZE;
+        let count = (end - offset) / RECORD_SIZE;
+        if segment_checksum(count as u64, &data[offset..end]) != read_u32(data, pos + 4) {
+            if end == data.len() {
+                break;
+            }
+            return Err(IsccError::InvalidInput(format!(
+                "index segment {index} checksum mismatch"
+            )));
+        }
+        for record in data[offset..end].chunks_exact(RECORD_SIZE) {
+            match record[11] {
+                KIND_UNIT => {
+                    record_unit(record)?;
+                }
+                KIND_TOMBSTONE => {}
+                kind => {
+                    return Err(IsccError::InvalidInput(format!(
+                        "index segment {index} has unknown record kind {kind}"
+                    )));
+                }
+            }
+        }
+        segments.push(Segment { offset, count });
+        pos = end;
+    }
+    Ok(segments)
+}
+
+/// End offset of the segment at `pos` with the given header bytes, or `None`
+/// if the header is cut off.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if the segment marker is missing.
+fn segment_end(header: &[u8], pos: usize) -> IsccResult<Option<usize>> {
+    if header.len() < SEGMENT_HEADER_SIZE {
+        return Ok(None);
+    }
+    if &header[..4] != SEGMENT_MARKER {
+        return Err(IsccError::InvalidInput(format!(
+            "index segment at byte {pos} has no segment marker"
+        )));
+    }
+    let count = usize::try_from(read_u64(header, 8)).unwrap_or(usize::MAX);
+    Ok(Some(
+        count
+            .saturating_mul(RECORD_SIZE)
+            .saturating_add(pos + SEGMENT_HEADER_SIZE),
+    ))
+}
+
+/// Length of the intact prefix of the index file `file` of `len` bytes,
+/// reading only segment headers and the final segment's records.
+///
+/// Uses the same rules as [`parse_segments`] for a torn trailing segment.
+fn intact_len(file: &mut File, len: usize) -> IsccResult<usize> {
+    let mut pos = FILE_HEADER_SIZE;
+    let mut header = Vec::with_capacity(SEGMENT_HEADER_SIZE);
+    while pos < len {
+        header.clear();
+        file.seek(SeekFrom::Start(pos as u64))?;
+        (&mut *file)
+            .take(SEGMENT_HEADER_SIZE as u64)
+            .read_to_end(&mut header)?;
+        let Some(end) = segment_end(&header, pos)? else {
+            break;
+        };
+        if end > len {
+            break;
+        }
+        if end == len {
+            let mut records = vec![0u8; end - pos - SEGMENT_HEADER_SIZE];
+            file.read_exact(&mut records)?;
+            let count = (records.len() / RECORD_SIZE) as u64;
+            if segment_checksum(count, &records) != read_u32(&header, 4) {
+                break;
+            }
+        }
+        pos = end;
+    }
+    Ok(pos)
+}
+
+/// A batch of index changes to append to an index file as one segment.
+///
+/// Changes are applied in the order they were added.
+#[derive(Debug, Default, Clone)]
+pub struct IndexSegment {
+    records: Vec<Record>,
+}
+
+impl IndexSegment {
+    /// Create an empty segment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of records in the segment.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the segment holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Add an ISCC-UNIT string, or every unit of a composite ISCC-CODE,
+    /// under `id`. See [`IsccIndex::insert`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `iscc` cannot be decoded, or
+    /// `IsccError::InvalidBits` if a unit has fewer than 64 or more than 256
+    /// digest bits. Nothing is added on error.
+    pub fn insert(&mut self, id: u64, iscc: &str) -> IsccResult<()> {
+        let units = codec::decompose_units(iscc)?;
+        for unit in &units {
+            check_insert_bits(unit)?;
+        }
+        for unit in &units {
+            self.insert_unit(id, unit)?;
+        }
+        Ok(())
+    }
+
+    /// Add a decoded unit under `id`. See [`IsccIndex::insert_unit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if the unit has fewer than 64 or more
+    /// than 256 digest bits.
+    pub fn insert_unit(&mut self, id: u64, unit: &IsccUnit) -> IsccResult<()> {
+        check_insert_bits(unit)?;
+        self.records.push(unit_record(id, unit));
+        Ok(())
+    }
+
+    /// Remove all units stored under `id` by earlier records.
+    pub fn remove(&mut self, id: u64) {
+        self.records.push(tombstone_record(id));
+    }
+
+    /// Append the segment to the index file at `path`, creating the file if
+    /// it does not exist. An empty segment is not written.
+    ///
+    /// The segment is written with a single write and synced to disk before
+    /// returning. A torn trailing segment left by an earlier crashed append
+    /// is cut off first, so the new segment directly follows the last intact
+    /// one.
+    ///
+    /// Appends to the same file must not run concurrently with each other or
+    /// with [`IndexFile::compact`]; see there.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::Io` on I/O failure, or an error if an existing
+    /// file does not start with a valid index file header.
+    pub fn append_to(&self, path: &Path) -> IsccResult<()> {
+        if self.records.is_empty() {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+        let mut out = Vec::new();
+        let len = usize::try_from(file.metadata()?.len()).unwrap_or(usize::MAX);
+        if len == 0 {
+            out.extend_from_slice(&file_header());
+        } else {
+            let mut header = Vec::with_capacity(FILE_HEADER_SIZE);
+            (&mut file)
+                .take(FILE_HEADER_SIZE as u64)
+                .read_to_end(&mut header)?;
+            check_file_header(&header)?;
+            let intact = intact_len(&mut file, len)?;
+            if intact < len {
+                OpenOptions::new()
+                    .write(true)
+                    .open(path)?
+                    .set_len(intact as u64)?;
+            }
+        }
+        out.extend_from_slice(&encode_segment(&self.records));
+        file.write_all(&out)?;
+        file.sync_data()?;
+        if len == 0 {
+            sync_parent_dir(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write `records` as a single-segment index file that atomically replaces
+/// `path`.
+fn write_compacted(path: &Path, records: &[Record]) -> IsccResult<()> {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    let tmp: PathBuf = path.with_file_name(name);
+    let result = (|| {
+        let mut file = File::create(&tmp)?;
+        file.write_all(&file_header())?;
+        if !records.is_empty() {
+            file.write_all(&encode_segment(records))?;
+        }
+        file.sync_all()?;
+        std::fs::rename(&tmp, path)?;
+        sync_parent_dir(path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    Ok(result?)
+}
+
+/// Sync the directory containing `path`, so a created or renamed entry
+/// survives a crash. A no-op where directories cannot be opened as files.
+fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        File::open(dir)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// Compute the bitmap of records that survive replay, scanning from the
+/// newest record, and the number of live units.
+fn replay<'a>(
+    records: impl DoubleEndedIterator<Item = &'a [u8]>,
+    total: usize,
+) -> (Vec<u64>, usize) {
+    let mut live = vec![0u64; total.div_ceil(64)];
+    let mut len = 0;
+    let mut removed = HashSet::new();
+    let mut seen = HashSet::new();
+    for (i, record) in (0..total).rev().zip(records.rev()) {
+        let id = record_id(record);
+        if record[11] == KIND_TOMBSTONE {
+            removed.insert(id);
+        } else if !removed.contains(&id) && seen.insert((id, record[8], record[9])) {
+            live[i / 64] |= 1 << (i % 64);
+            len += 1;
+        }
+    }
+    (live, len)
+}
+
+/// A read-only index file opened for querying.
+///
+/// [`IndexFile::open`] reads the file into memory; [`IndexFile::open_mmap`]
+/// memory-maps it when the `mmap` feature is enabled. Queries scan the stored
+/// records in place and return the same matches as an [`IsccIndex`] holding
+/// the same units.
+pub struct IndexFile {
+    data: FileBytes,
+    segments: Vec<Segment>,
+    /// Bitmap of records that are still live after replay.
+    live: Vec<u64>,
+    len: usize,
+}
+
+impl std::fmt::Debug for IndexFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexFile")
+            .field("segments", &self.segments.len())
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl IndexFile {
+    /// Open and validate the index file at `path`.
+    ///
+    /// Every segment checksum is verified, and replay state is computed once
+    /// so queries only touch live records. A torn trailing segment left by a
+    /// crashed append is ignored, keeping all segments before it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::Io` if the file cannot be read,
+    /// `IsccError::Unsupported` for an unknown format version,
+    /// `IsccError::Truncated` for a cut-off file header, or
+    /// `IsccError::InvalidInput` for bad magic bytes, checksums or records.
+    pub fn open(path: &Path) -> IsccResult<Self> {
+        Self::from_file_bytes(mmap::read_file(path)?)
+    }
+
+    /// Open the index file at `path` with a read-only memory mapping.
+    ///
+    /// Queries then scan the mapped records without copying the file. Without
+    /// the `mmap` feature, and for empty files, this is the same as
+    /// [`IndexFile::open`].
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified in place while the returned
+    /// `IndexFile` is alive, or reads may fault or observe changing data.
+    /// [`IndexSegment::append_to`] only writes past the mapped range and
+    /// [`IndexFile::compact`] and [`IsccIndex::save`] replace the file via
+    /// rename, so they are safe to run concurrently. The exception is the
+    /// first append after a crashed one, which truncates the torn tail.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`IndexFile::open`].
+    pub unsafe fn open_mmap(path: &Path) -> IsccResult<Self> {
+        // SAFETY: upheld by the caller.
+        Self::from_file_bytes(unsafe { mmap::map_file(path) }?)
+    }
+
+    /// Parse an index file from an in-memory buffer.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`IndexFile::open`], without I/O errors.
+    pub fn from_bytes(data: Vec<u8>) -> IsccResult<Self> {
+        Self::from_file_bytes(FileBytes::Owned(data))
+    }
+
+    fn from_file_bytes(data: FileBytes) -> IsccResult<Self> {
+        let segments = parse_segments(&data)?;
+        let total: usize = segments.iter().map(|s| s.count).sum();
+        let mut file = Self {
+            data,
+            segments,
+            live: Vec::new(),
+            len: 0,
+        };
+        let (live, len) = replay(file.records(), total);
+        file.live = live;
+        file.len = len;
+        Ok(file)
+    }
+
+    /// All records in file order.
+    fn records(&self) -> impl DoubleEndedIterator<Item = &[u8]> {
+        self.segments.iter().flat_map(|segment| {
+            let end = segment.offset + segment.count * RECORD_SIZE;
+            self.data[segment.offset..end].chunks_exact(RECORD_SIZE)
+        })
+    }
+
+    /// Live unit records in file order.
+    fn live_records(&self) -> impl Iterator<Item = &[u8]> {
+        self.records()
+            .enumerate()
+            .filter(|(i, _)| self.live[i / 64] >> (i % 64) & 1 == 1)
+            .map(|(_, record)| record)
+    }
+
+    /// Number of live units in the file.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the file holds no live units.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of segments in the file.
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Iterate over all live `(id, unit)` pairs in file order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, IsccUnit)> + '_ {
+        self.live_records()
+            .filter_map(|record| Some((record_id(record), record_unit(record).ok()?)))
+    }
+
+    /// Load all live units into an in-memory [`IsccIndex`].
+    pub fn to_index(&self) -> IsccIndex {
+        let mut index = IsccIndex::new();
+        for (id, unit) in self.iter() {
+            // Stored units were validated on open
+            let _ = index.insert_unit(id, unit);
+        }
+        index
+    }
+
+    /// Distances from `query` to all live units in its space.
+    fn scan(&self, query: &IsccUnit) -> impl Iterator<Item = IndexMatch> + '_ {
+        let (mtype, stype) = (query.maintype() as u8, query.subtype() as u8);
+        let digest = query.digest().to_vec();
+        let query_bits = query.bit_length();
+        self.live_records()
+            .filter(move |record| record[8] == mtype && record[9] == stype)
+            .map(move |record| {
+                let bits = record_bits(record).min(query_bits);
+                IndexMatch {
+                    id: record_id(record),
+                    distance: hamming_distance(&record[DIGEST_OFFSET..], &digest, bits),
+                    bits,
+                }
+            })
+    }
+
+    /// Return all units within Hamming distance `max_distance` of the query
+    /// unit, sorted by distance and then ID. See [`IsccIndex::radius`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `query` is not a single valid ISCC-UNIT with at
+    /// least 64 digest bits.
+    pub fn radius(&self, query: &str, max_distance: u32) -> IsccResult<Vec<IndexMatch>> {
+        self.radius_unit(&parse_query(query)?, max_distance)
+    }
+
+    /// Same as [`IndexFile::radius`] for a decoded unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if the unit has fewer than 64 digest bits.
+    pub fn radius_unit(&self, query: &IsccUnit, max_distance: u32) -> IsccResult<Vec<IndexMatch>> {
+        check_bits(query)?;
+        let mut matches: Vec<IndexMatch> = self
+            .scan(query)
+            .filter(|m| m.distance <= max_distance)
+            .collect();
+        sort_matches(&mut matches);
+        Ok(matches)
+    }
+
+    /// Return the `k` units nearest to the query unit, sorted by distance and
+    /// then ID. See [`IsccIndex::knn`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `query` is not a single valid ISCC-UNIT with at
+    /// least 64 digest bits.
+    pub fn knn(&self, query: &str, k: usize) -> IsccResult<Vec<IndexMatch>> {
+        self.knn_unit(&parse_query(query)?, k)
+    }
+
+    /// Same as [`IndexFile::knn`] for a decoded unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::InvalidBits` if the unit has fewer than 64 digest bits.
+    pub fn knn_unit(&self, query: &IsccUnit, k: usize) -> IsccResult<Vec<IndexMatch>> {
+        check_bits(query)?;
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        let mut matches: Vec<IndexMatch> = self.scan(query).collect();
+        if matches.len() > k {
+            matches.select_nth_unstable_by_key(k - 1, |m| (m.distance, m.id));
+            matches.truncate(k);
+        }
+        sort_matches(&mut matches);
+        Ok(matches)
+    }
+
+    /// Rewrite the index file at `path` with only its live units, in a single
+    /// segment.
+    ///
+    /// The compacted file is written next to `path`, synced, and renamed over
+    /// it, so the original stays intact if compaction fails.
+    ///
+    /// Compaction takes no lock. Writers must be exclusive: a segment that
+    /// another process appends while compaction runs is lost when the
+    /// compacted file replaces the original, so serialize
+    /// [`IndexSegment::append_to`] and `compact` for the same file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or written.
+    pub fn compact(path: &Path) -> IsccResult<()> {
+        let file = Self::open(path)?;
+        let records: Vec<Record> = file
+            .live_records()
+            .filter_map(|record| record.try_into().ok())
+            .collect();
+        drop(file);
+        write_compacted(path, &records)
+    }
+}
+
+impl IsccIndex {
+    /// Write all units to an index file at `path` as a single segment,
+    /// atomically replacing any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IsccError::Io` on I/O failure.
+    pub fn save(&self, path: &Path) -> IsccResult<()> {
+        let records: Vec<Record> = self
+            .iter()
+            .map(|(id, unit)| unit_record(id, unit))
+            .collect();
+        write_compacted(path, &records)
+    }
+
+    /// Load an index from the index file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`IndexFile::open`].
+    pub fn load(path: &Path) -> IsccResult<Self> {
+        Ok(IndexFile::open(path)?.to_index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::encode_component;
+
+    fn data_unit(value: u64) -> String {
+        encode_component(
+            MainType::Data,
+            SubType::None,
+            Version::V0,
+            64,
+            &value.to_be_bytes(),
+        )
+        .unwrap()
+    }
+
+    fn ids(matches: &[IndexMatch]) -> Vec<u64> {
+        matches.iter().map(|m| m.id).collect()
+    }
+
+    #[test]
+    fn test_index_file_save_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.idx");
+        let mut index = IsccIndex::new();
+        for id in 0..50u64 {
+            index.insert(id, &data_unit(id * 0x0101_0101)).unwrap();
+        }
+        let meta =
+            encode_component(MainType::Meta, SubType::None, Version::V0, 256, &[7; 32]).unwrap();
+        index.insert(99, &meta).unwrap();
+        index.save(&path).unwrap();
+
+        let file = IndexFile::open(&path).unwrap();
+        assert_eq!(file.len(), 51);
+        assert_eq!(file.segment_count(), 1);
+        // SAFETY: the file is not modified while mapped.
+        let mapped = unsafe { IndexFile::open_mmap(&path) }.unwrap();
+        assert_eq!(
+            mapped.iter().collect::<Vec<_>>(),
+            file.iter().collect::<Vec<_>>()
+        );
+        let loaded = IsccIndex::load(&path).unwrap();
+        assert_eq!(loaded.len(), 51);
+
+        for (query, k) in [(data_unit(3), 5), (data_unit(u64::MAX), 7), (meta, 1)] {
+            assert_eq!(file.knn(&query, k).unwrap(), index.knn(&query, k).unwrap());
+            assert_eq!(
+                file.radius(&query, 12).unwrap(),
+                index.radius(&query, 12).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_index_file_append_replay_and_compact() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.idx");
+
+        let mut first = IndexSegment::new();
+        first.insert(1, &data_unit(0)).unwrap();
+        first.insert(2, &data_unit(1)).unwrap();
+        first.insert(3, &data_unit(3)).unwrap();
+        first.append_to(&path).unwrap();
+
+        let mut second = IndexSegment::new();
+        second.remove(2);
+        second.insert(1, &data_unit(0xFF)).unwrap();
+        second.append_to(&path).unwrap();
+        IndexSegment::new().append_to(&path).unwrap();
+
+        let file = IndexFile::open(&path).unwrap();
+        assert_eq!(file.segment_count(), 2);
+        assert_eq!(file.len(), 2);
+        let knn = file.knn(&data_unit(0), 5).unwrap();
+        assert_eq!(ids(&knn), vec![3, 1]);
+        assert_eq!(knn[1].distance, 8);
+
+        let before = std::fs::metadata(&path).unwrap().len();
+        IndexFile::compact(&path).unwrap();
+        assert!(std::fs::metadata(&path).unwrap().len() < before);
+        let compacted = IndexFile::open(&path).unwrap();
+        assert_eq!(compacted.segment_count(), 1);
+        assert_eq!(compacted.knn(&data_unit(0), 5).unwrap(), knn);
+        let mut pairs: Vec<(u64, String)> = compacted
+            .iter()
+            .map(|(id, unit)| (id, unit.to_base32()))
+            .collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![(1, data_unit(0xFF)), (3, data_unit(3))]);
+    }
+
+    #[test]
+    fn test_index_file_reinsert_after_remove() {
+        let mut segment = IndexSegment::new();
+        segment.insert(5, &data_unit(1)).unwrap();
+        segment.remove(5);
+        segment.insert(5, &data_unit(2)).unwrap();
+        let mut data = file_header().to_vec();
+        data.extend(encode_segment(&segment.records));
+        let file = IndexFile::from_bytes(data).unwrap();
+        assert_eq!(file.len(), 1);
+        assert_eq!(file.iter().next().unwrap().1.to_base32(), data_unit(2));
+    }
+
+    #[test]
+    fn test_index_file_torn_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.idx");
+        for id in 1..=2 {
+            let mut segment = IndexSegment::new();
+            segment.insert(id, &data_unit(id)).unwrap();
+            segment.append_to(&path).unwrap();
+        }
+        let intact = std::fs::read(&path).unwrap();
+
+        // A crash mid-append leaves part of a third segment.
+        let mut third = IndexSegment::new();
+        third.insert(3, &data_unit(3)).unwrap();
+        third.insert(4, &data_unit(4)).unwrap();
+        let torn = encode_segment(&third.records);
+        for cut in [
+            1,
+            SEGMENT_HEADER_SIZE,
+            SEGMENT_HEADER_SIZE + RECORD_SIZE + 5,
+        ] {
+            let mut data = intact.clone();
+            data.extend_from_slice(&torn[..cut]);
+            let file = IndexFile::from_bytes(data).unwrap();
+            assert_eq!(file.segment_count(), 2, "cut at {cut}");
+            assert_eq!(file.len(), 2);
+        }
+        // Full length, but the records never reached the disk.
+        let mut zeroed = torn.clone();
+        zeroed[SEGMENT_HEADER_SIZE..].fill(0);
+        let mut data = intact.clone();
+        data.extend_from_slice(&zeroed);
+        assert_eq!(IndexFile::from_bytes(data).unwrap().segment_count(), 2);
+
+        // The next append replaces the torn tail.
+        let mut data = intact.clone();
+        data.extend_from_slice(&torn[..SEGMENT_HEADER_SIZE + RECORD_SIZE + 5]);
+        std::fs::write(&path, &data).unwrap();
+        assert_eq!(IndexFile::open(&path).unwrap().len(), 2);
+        third.append_to(&path).unwrap();
+        let mut expected = intact;
+        expected.extend_from_slice(&torn);
+        assert_eq!(std::fs::read(&path).unwrap(), expected);
+        let file = IndexFile::open(&path).unwrap();
+        assert_eq!(file.segment_count(), 3);
+        assert_eq!(file.len(), 4);
+    }
+
+    #[test]
+    fn test_index_rejects_units_wider_than_records() {
+        let wide =
+            encode_component(MainType::Meta, SubType::None, Version::V0, 512, &[7; 64]).unwrap();
+        let mut index = IsccIndex::new();
+        assert!(matches!(
+            index.insert(1, &wide),
+            Err(IsccError::InvalidBits { bits: 512, .. })
+        ));
+        assert!(index.is_empty());
+        let mut segment = IndexSegment::new();
+        assert!(matches!(
+            segment.insert(1, &wide),
+            Err(IsccError::InvalidBits { bits: 512, .. })
+        ));
+        assert!(segment.is_empty());
+
+        // Wide queries still compare against the stored prefix.
+        let meta =
+            encode_component(MainType::Meta, SubType::None, Version::V0, 256, &[7; 32]).unwrap();
+        index.insert(1, &meta).unwrap();
+        assert_eq!(index.knn(&wide, 1).unwrap()[0].distance, 0);
+    }
+
+    #[test]
+    fn test_index_file_rejects_corruption() {
+        let mut segment = IndexSegment::new();
+        segment.insert(1, &data_unit(42)).unwrap();
+        let mut data = file_header().to_vec();
+        data.extend(encode_segment(&segment.records));
+
+        // A corrupt segment that is not the last one is an error.
+        let mut flipped = data.clone();
+        *flipped.last_mut().unwrap() ^= 1;
+        flipped.extend(encode_segment(&segment.records));
+        assert!(matches!(
+            IndexFile::from_bytes(flipped),
+            Err(IsccError::InvalidInput(msg)) if msg.contains("checksum")
+        ));
+
+        let mut marker = data.clone();
+        marker.extend(encode_segment(&segment.records));
+        marker[FILE_HEADER_SIZE] = b'X';
+        assert!(matches!(
+            IndexFile::from_bytes(marker),
+            Err(IsccError::InvalidInput(msg)) if msg.contains("marker")
+        ));
+
+        let cut = data[..FILE_HEADER_SIZE - 1].to_vec();
+        assert!(matches!(
+            IndexFile::from_bytes(cut),
+            Err(IsccError::Truncated { .. })
+        ));
+
+        let mut future = data.clone();
+        future[8] = 2;
+        assert!(matches!(
+            IndexFile::from_bytes(future),
+            Err(IsccError::Unsupported(_))
+        ));
+
+        let mut magic = data;
+        magic[0] = b'X';
+        assert!(matches!(
+            IndexFile::from_bytes(magic),
+            Err(IsccError::InvalidInput(_))
+        ));
+
+        let empty = IndexFile::from_bytes(file_header().to_vec()).unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.knn(&data_unit(0), 3).unwrap().is_empty());
+    }
+}
@@ -4,28 +4,59 @@
 //! functions are the public Tier 1 API surface, designed to be compatible with
 //! the `iscc-core` Python reference implementation.
 
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "audio")]
+mod audio;
+pub mod cancel;
 pub mod cdc;
+pub mod chromaprint;
 pub mod codec;
+pub mod compare;
 pub mod conformance;
 pub(crate) mod dct;
+pub mod flake;
+mod imaging;
+pub mod index;
+pub mod index_file;
 pub mod minhash;
+mod mmap;
 pub mod simhash;
 pub mod streaming;
 pub mod types;
 pub mod utils;
+pub mod validate;
+pub mod video_signature;
 pub(crate) mod wtahash;
 
+#[cfg(feature = "tokio")]
+pub use async_io::gen_sum_code_from_async_reader;
+#[cfg(feature = "audio")]
+pub use audio::chromaprint_from_pcm;
+pub use cancel::CancelToken;
 pub use cdc::alg_cdc_chunks;
+pub use chromaprint::decode_chromaprint;
 pub use codec::encode_base64;
 pub use codec::iscc_decompose;
+pub use codec::{Chain, IsccCode, IsccId, IsccUnit};
+pub use codec::{MultiBase, from_multiformat, to_multiformat};
+pub use compare::{iscc_compare, iscc_distance, iscc_similarity};
 pub use conformance::conformance_selftest;
+pub use flake::FlakeGenerator;
+pub use imaging::PixelFormat;
+pub use index::{IndexMatch, IsccIndex};
+pub use index_file::{IndexFile, IndexSegment};
 pub use minhash::alg_minhash_256;
 pub use simhash::{alg_simhash, sliding_window};
-pub use streaming::{DataHasher, InstanceHasher};
+#[cfg(feature = "text-processing")]
+pub use streaming::TextHasher;
+pub use streaming::{DataHasher, InstanceHasher, SumHasher};
 pub use types::*;
 #[cfg(feature = "text-processing")]
 pub use utils::{text_clean, text_collapse};
 pub use utils::{text_remove_newlines, text_trim};
+pub use validate::{IsccValidationError, iscc_clean, iscc_normalize, iscc_validate};
+pub use video_signature::{SignatureFrame, VideoSignature, parse_video_signature};
 
 /// Max UTF-8 byte length for name metadata trimming.
 #[cfg(feature = "meta-code")]
@@ -45,12 +76,71 @@ pub const IO_READ_SIZE: usize = 4_194_304;
 /// Character n-gram width for text content features.
 pub const TEXT_NGRAM_SIZE: usize = 13;
 
+/// Average chunk size in characters for granular Text-Code features.
+pub const TEXT_AVG_CHUNK_SIZE: usize = 1024;
+
 /// Error type for ISCC operations.
+///
+/// Every variant has a stable machine-readable [`code`](IsccError::code) that
+/// the language bindings expose alongside the message, so callers can match
+/// on error kinds instead of message text.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum IsccError {
     /// Input data is invalid.
     #[error("invalid input: {0}")]
     InvalidInput(String),
+    /// Reading from a file or stream failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Base32 text could not be decoded.
+    #[error("base32 decode error: {0}")]
+    Base32(#[from] data_encoding::DecodeError),
+    /// ISCC header is malformed or holds out-of-range field values.
+    #[error("invalid header: {0}")]
+    Header(String),
+    /// Input is shorter than its header or the algorithm requires.
+    #[error("truncated input: expected {expected} bytes, got {actual}")]
+    Truncated { expected: usize, actual: usize },
+    /// Requested bit length is not supported.
+    #[error("invalid bit length {bits} (expected {expected})")]
+    InvalidBits { bits: u32, expected: String },
+    /// Meta payload exceeds the size limit.
+    #[error("meta payload exceeds size limit ({size} > {limit} bytes)")]
+    MetaTooLarge { size: usize, limit: usize },
+    /// JSON could not be parsed or canonicalized.
+    #[error("invalid JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    /// Valid but unsupported value (e.g. an unknown version or encoding).
+    #[error("unsupported: {0}")]
+    Unsupported(String),
+    /// ISCC string failed validation.
+    #[error(transparent)]
+    Validation(#[from] validate::IsccValidationError),
+    /// Operation was stopped through a [`CancelToken`].
+    #[error("operation cancelled")]
+    Cancelled,
+}
+
+impl IsccError {
+    /// Stable machine-readable error code (e.g. `"invalid_bits"`).
+    ///
+    /// Codes never change between releases and are identical in all bindings.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidInput(_) => "invalid_input",
+            Self::Io(_) => "io",
+            Self::Base32(_) => "base32",
+            Self::Header(_) => "header",
+            Self::Truncated { .. } => "truncated",
+            Self::InvalidBits { .. } => "invalid_bits",
+            Self::MetaTooLarge { .. } => "meta_too_large",
+            Self::InvalidJson(_) => "invalid_json",
+            Self::Unsupported(_) => "unsupported",
+            Self::Validation(_) => "validation",
+            Self::Cancelled => "cancelled",
+        }
+    }
 }
 
 /// Result type alias for ISCC operations.
@@ -155,11 +245,9 @@ fn decode_data_url(data_url: &str) -> IsccResult<Vec<u8>> {
 /// Parse a meta string as JSON and re-serialize to RFC 8785 (JCS) canonical bytes.
 #[cfg(feature = "meta-code")]
 fn parse_meta_json(meta_str: &str) -> IsccResult<Vec<u8>> {
-    let parsed: serde_json::Value = serde_json::from_str(meta_str)
-        .map_err(|e| IsccError::InvalidInput(format!("invalid JSON in meta: {e}")))?;
+    let parsed: serde_json::Value = serde_json::from_str(meta_str)?;
     let mut buf = Vec::new();
-    serde_json_canonicalizer::to_writer(&parsed, &mut buf)
-        .map_err(|e| IsccError::InvalidInput(format!("JSON canonicalization failed: {e}")))?;
+    serde_json_canonicalizer::to_writer(&parsed, &mut buf)?;
     Ok(buf)
 }
 
@@ -185,8 +273,9 @@ fn build_meta_data_url(json_bytes: &[u8], json_value: &serde_json::Value) -> Str
 ///
 /// # Errors
 ///
-/// Returns `IsccError::InvalidInput` if enum values are out of range, if `mtype` is
-/// `MainType::Iscc` (5), or if `digest.len() < bit_length / 8`.
+/// Returns `IsccError::Header` if enum values are out of range,
+/// `IsccError::InvalidInput` if `mtype` is `MainType::Iscc` (5), or
+/// `IsccError::Truncated` if `digest.len() < bit_length / 8`.
 pub fn encode_component(
     mtype: u8,
     stype: u8,
@@ -199,19 +288,18 @@ pub fn encode_component(
     let vs = codec::Version::try_from(version)?;
     let needed = (bit_length / 8) as usize;
     if digest.len() < needed {
-        return Err(IsccError::InvalidInput(format!(
-            "digest length {} < bit_length/8 ({})",
-            digest.len(),
-            needed
-        )));
+        return Err(IsccError::Truncated {
+            expected: needed,
+            actual: digest.len(),
+        });
     }
     codec::encode_component(mt, st, vs, bit_length, digest)
 }
 
 /// Decode an ISCC unit string into its header components and raw digest.
 ///
-/// Inverse of [`encode_component`]. Strips an optional `"ISCC:"` prefix and
-/// dashes, base32-decodes the string, parses the variable-length header, and
+/// Inverse of [`encode_component`]. Cleans the input with [`iscc_clean`]
+/// (whitespace, `ISCC:` prefix, dashes), base32-decodes the string, parses the variable-length header, and
 /// returns the digest truncated to exactly the encoded bit-length.
 ///
 /// Returns `(maintype, subtype, version, length_index, digest)` where the
@@ -220,22 +308,23 @@ pub fn encode_component(
 ///
 /// # Errors
 ///
-/// Returns `IsccError::InvalidInput` on invalid base32 input, malformed
-/// header, or if the decoded body is shorter than the expected digest length.
+/// Returns `IsccError::Base32` on invalid base32 input, `IsccError::Header`
+/// on a malformed header, or `IsccError::Truncated` if the decoded body is
+/// shorter than the expected digest length.
 pub fn iscc_decode(iscc: &str) -> IsccResult<(u8, u8, u8, u8, Vec<u8>)> {
-    // Strip optional "ISCC:" prefix (case-sensitive, matching iscc_decompose)
-    let clean = iscc.strip_prefix("ISCC:").unwrap_or(iscc);
-    // Remove dashes (matching iscc_clean behavior for base32 input)
-    let clean = clean.replace('-', "");
-    let raw = codec::decode_base32(&clean)?;
+    let raw = codec::decode_base32(&validate::iscc_clean(iscc))?;
     let (mt, st, vs, length_index, tail) = codec::decode_header(&raw)?;
     let bit_length = codec::decode_length(mt, length_index, st);
     let nbytes = (bit_length / 8) as usize;
     if tail.len() < nbytes {
-        return Err(IsccError::InvalidInput(format!(
-            "decoded body too short: expected {nbytes} digest bytes, got {}",
-            tail.len()
-        )));
+        return Err(IsccError::Truncated {
+            expected: nbytes,
+            actual: tail.len(),
+        });
+    }
+    if mt == codec::MainType::Id {
+        let unit = codec::IsccUnit::new(mt, st, vs, bit_length, &tail[..nbytes])?;
+        codec::IsccId::try_from(&unit)?;
     }
     Ok((
         mt as u8,
@@ -259,7 +348,7 @@ pub fn iscc_decode(iscc: &str) -> IsccResult<(u8, u8, u8, u8, Vec<u8>)> {
 ///
 /// # Errors
 ///
-/// Returns [`IsccError::InvalidInput`] if `json` is not valid JSON or if
+/// Returns [`IsccError::InvalidJson`] if `json` is not valid JSON or if
 /// JCS canonicalization fails.
 ///
 /// # Examples
@@ -274,11 +363,9 @@ pub fn iscc_decode(iscc: &str) -> IsccResult<(u8, u8, u8, u8, Vec<u8>)> {
 /// ```
 #[cfg(feature = "meta-code")]
 pub fn json_to_data_url(json: &str) -> IsccResult<String> {
-    let parsed: serde_json::Value = serde_json::from_str(json)
-        .map_err(|e| IsccError::InvalidInput(format!("invalid JSON: {e}")))?;
+    let parsed: serde_json::Value = serde_json::from_str(json)?;
     let mut canonical_bytes = Vec::new();
-    serde_json_canonicalizer::to_writer(&parsed, &mut canonical_bytes)
-        .map_err(|e| IsccError::InvalidInput(format!("JSON canonicalization failed: {e}")))?;
+    serde_json_canonicalizer::to_writer(&parsed, &mut canonical_bytes)?;
     Ok(build_meta_data_url(&canonical_bytes, &parsed))
 }
 
@@ -316,10 +403,10 @@ pub fn gen_meta_code_v0(
     if let Some(meta_str) = meta {
         const PRE_DECODE_LIMIT: usize = META_TRIM_META * 4 / 3 + 256;
         if meta_str.len() > PRE_DECODE_LIMIT {
-            return Err(IsccError::InvalidInput(format!(
-                "meta string exceeds size limit ({} > {PRE_DECODE_LIMIT} bytes)",
-                meta_str.len()
-            )));
+            return Err(IsccError::MetaTooLarge {
+                size: meta_str.len(),
+                limit: PRE_DECODE_LIMIT,
+            });
         }
     }
 
@@ -333,10 +420,10 @@ pub fn gen_meta_code_v0(
     // Post-decode check: reject payloads exceeding META_TRIM_META
     if let Some(ref payload) = meta_payload {
         if payload.len() > META_TRIM_META {
-            return Err(IsccError::InvalidInput(format!(
-                "decoded meta payload exceeds size limit ({} > {META_TRIM_META} bytes)",
-                payload.len()
-            )));
+            return Err(IsccError::MetaTooLarge {
+                size: payload.len(),
+                limit: META_TRIM_META,
+            });
         }
     }
 
@@ -345,7 +432,7 @@ pub fn gen_meta_code_v0(
         let meta_code_digest = soft_hash_meta_v0_with_bytes(&name, payload);
         let metahash = utils::multi_hash_blake3(payload);
 
-        let meta_code = codec::encode_component(
+        let unit = codec::IsccUnit::new(
             codec::MainType::Meta,
             codec::SubType::None,
             codec::Version::V0,
@@ -357,15 +444,15 @@ pub fn gen_meta_code_v0(
         let meta_value = match meta {
             Some(meta_str) if meta_str.starts_with("data:") => meta_str.to_string(),
             Some(meta_str) => {
-                let parsed: serde_json::Value = serde_json::from_str(meta_str)
-                    .map_err(|e| IsccError::InvalidInput(format!("invalid JSON: {e}")))?;
+                let parsed: serde_json::Value = serde_json::from_str(meta_str)?;
                 build_meta_data_url(payload, &parsed)
             }
             None => unreachable!(),
         };
 
         Ok(MetaCodeResult {
-            iscc: format!("ISCC:{meta_code}"),
+            iscc: unit.to_string(),
+            unit,
             name: name.clone(),
             description: if desc_clean.is_empty() {
                 None
@@ -393,7 +480,7 @@ pub fn gen_meta_code_v0(
         };
         let meta_code_digest = soft_hash_meta_v0(&name, extra);
 
-        let meta_code = codec::encode_component(
+        let unit = codec::IsccUnit::new(
             codec::MainType::Meta,
             codec::SubType::None,
             codec::Version::V0,
@@ -402,7 +489,8 @@ pub fn gen_meta_code_v0(
         )?;
 
         Ok(MetaCodeResult {
-            iscc: format!("ISCC:{meta_code}"),
+            iscc: unit.to_string(),
+            unit,
             name: name.clone(),
             description: if desc_clean.is_empty() {
                 None
@@ -436,10 +524,30 @@ fn soft_hash_text_v0(text: &str) -> Vec<u8> {
 /// create a similarity-preserving fingerprint.
 #[cfg(feature = "text-processing")]
 pub fn gen_text_code_v0(text: &str, bits: u32) -> IsccResult<TextCodeResult> {
-    let collapsed = utils::text_collapse(text);
+    text_code_v0(&utils::text_collapse(text), bits)
+}
+
+/// Generate a Text-Code from plain text content, keeping per-chunk features.
+///
+/// Same code as [`gen_text_code_v0`], with `features` set to content-defined
+/// chunks of the collapsed text (about [`TEXT_AVG_CHUNK_SIZE`] characters
+/// each). Every chunk carries a 32-bit simprint over its character n-grams,
+/// its position in the collapsed text, and the span of the original input
+/// it came from, for locating shared passages between similar texts.
+#[cfg(feature = "text-processing")]
+pub fn gen_text_code_v0_granular(text: &str, bits: u32) -> IsccResult<TextCodeResult> {
+    let (collapsed, source) = utils::text_collapse_mapped(text);
+    let mut result = text_code_v0(&collapsed, bits)?;
+    result.features = Some(text_features(&collapsed, &source));
+    Ok(result)
+}
+
+/// Build a Text-Code result from already collapsed text.
+#[cfg(feature = "text-processing")]
+fn text_code_v0(collapsed: &str, bits: u32) -> IsccResult<TextCodeResult> {
     let characters = collapsed.chars().count();
-    let hash_digest = soft_hash_text_v0(&collapsed);
-    let component = codec::encode_component(
+    let hash_digest = soft_hash_text_v0(collapsed);
+    let unit = codec::IsccUnit::new(
         codec::MainType::Content,
         codec::SubType::TEXT,
         codec::Version::V0,
@@ -447,11 +555,55 @@ pub fn gen_text_code_v0(text: &str, bits: u32) -> IsccResult<TextCodeResult> {
         &hash_digest,
     )?;
     Ok(TextCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
         characters,
+        features: None,
     })
 }
 
+/// Split collapsed text into content-defined chunks and compute their
+/// simprints.
+///
+/// Chunking runs on the UTF-32 encoding so that cut points always fall on
+/// character boundaries. `source` maps each collapsed character to its span
+/// in the original input.
+#[cfg(feature = "text-processing")]
+fn text_features(collapsed: &str, source: &[std::ops::Range<usize>]) -> Vec<TextFeature> {
+    let chars: Vec<char> = collapsed.chars().collect();
+    let utf32: Vec<u8> = chars
+        .iter()
+        .flat_map(|&c| u32::from(c).to_be_bytes())
+        .collect();
+    let chunks = cdc::alg_cdc_chunks_unchecked(&utf32, true, (TEXT_AVG_CHUNK_SIZE * 4) as u32);
+    let mut offset = 0;
+    let mut features = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        let size = chunk.len() / 4;
+        let text: String = chars[offset..offset + size].iter().collect();
+        let digests: Vec<[u8; 4]> = simhash::sliding_window_strs(&text, TEXT_NGRAM_SIZE)
+            .iter()
+            .map(|ngram| xxhash_rust::xxh32::xxh32(ngram.as_bytes(), 0).to_be_bytes())
+            .collect();
+        let simprint = simhash::alg_simhash_inner(&digests);
+        let (source_offset, source_size) = if size == 0 {
+            (0, 0)
+        } else {
+            let start = source[offset].start;
+            (start, source[offset + size - 1].end - start)
+        };
+        features.push(TextFeature {
+            offset,
+            size,
+            simprint: u32::from_be_bytes([simprint[0], simprint[1], simprint[2], simprint[3]]),
+            source_offset,
+            source_size,
+        });
+        offset += size;
+    }
+    features
+}
+
 /// Transpose a matrix represented as a Vec of Vecs.
 fn transpose_matrix(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
     let rows = matrix.len();
@@ -525,9 +677,10 @@ fn soft_hash_image_v0(pixels: &[u8], bits: u32) -> IsccResult<Vec<u8>> {
         )));
     }
     if bits > 256 {
-        return Err(IsccError::InvalidInput(format!(
-            "bits must be <= 256, got {bits}"
-        )));
+        return Err(IsccError::InvalidBits {
+            bits,
+            expected: "<= 256".into(),
+        });
     }
 
     // Step 1: Row-wise DCT (32 rows of 32 pixels)
@@ -577,7 +730,7 @@ fn soft_hash_image_v0(pixels: &[u8], bits: u32) -> IsccResult<Vec<u8>> {
 /// perceptual hash.
 pub fn gen_image_code_v0(pixels: &[u8], bits: u32) -> IsccResult<ImageCodeResult> {
     let hash_digest = soft_hash_image_v0(pixels, bits)?;
-    let component = codec::encode_component(
+    let unit = codec::IsccUnit::new(
         codec::MainType::Content,
         codec::SubType::Image,
         codec::Version::V0,
@@ -585,10 +738,87 @@ pub fn gen_image_code_v0(pixels: &[u8], bits: u32) -> IsccResult<ImageCodeResult
         &hash_digest,
     )?;
     Ok(ImageCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
     })
 }
 
+/// Generate an Image-Code from an encoded image file.
+///
+/// Decodes PNG, JPEG, GIF (first frame) or WebP data and normalizes it like
+/// iscc-sdk before hashing: applies the EXIF orientation, flattens
+/// transparency onto white, trims a uniform border, converts to grayscale
+/// with ITU-R 601-2 luma weights and resizes to 32×32 with Pillow's bicubic
+/// filter. Requires the `image` feature.
+///
+/// JPEG data is decoded by `zune-jpeg`, not libjpeg as in Pillow. Its IDCT
+/// and chroma upsampling can yield slightly different pixels, so codes for
+/// JPEG files may differ from iscc-sdk in a few bits. The same applies to
+/// lossy WebP. Palette images (indexed PNG, GIF) without transparency are
+/// trimmed by iscc-sdk on their palette indices rather than their colors,
+/// so a border this function trims may be kept there, or the reverse.
+///
+/// # Errors
+///
+/// Returns `IsccError::Unsupported` for unrecognized or disabled formats,
+/// `IsccError::InvalidInput` for corrupt image data, and
+/// `IsccError::InvalidBits` for an invalid `bits` value.
+#[cfg(feature = "image")]
+pub fn gen_image_code_from_bytes(data: &[u8], bits: u32) -> IsccResult<ImageCodeResult> {
+    use image::{DynamicImage, ImageDecoder, ImageError, ImageReader};
+
+    let decode_err = |e: ImageError| match e {
+        ImageError::Unsupported(e) => IsccError::Unsupported(e.to_string()),
+        e => IsccError::InvalidInput(format!("cannot decode image: {e}")),
+    };
+    let mut decoder = ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()?
+        .into_decoder()
+        .map_err(decode_err)?;
+    let orientation = decoder.orientation().map_err(decode_err)?;
+    let mut img = DynamicImage::from_decoder(decoder).map_err(decode_err)?;
+    img.apply_orientation(orientation);
+    let rgba = img.into_rgba8();
+    gen_image_code_from_pixels(rgba.width(), rgba.height(), PixelFormat::Rgba, &rgba, bits)
+}
+
+/// Generate an Image-Code from a raw pixel buffer of any size.
+///
+/// `data` holds `width * height` row-major pixels in `format`. The pixels
+/// get the same normalization as [`gen_image_code_from_bytes`] (minus EXIF
+/// orientation): transparency flattened onto white, uniform border trimmed,
+/// ITU-R 601-2 grayscale conversion and bicubic resize to 32×32. A 32×32
+/// `Gray` buffer without a trimmable border hashes like `gen_image_code_v0`.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if a dimension is zero or `data` has
+/// the wrong length, and `IsccError::InvalidBits` for an invalid `bits`.
+pub fn gen_image_code_from_pixels(
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    data: &[u8],
+    bits: u32,
+) -> IsccResult<ImageCodeResult> {
+    let (width, height) = (width as usize, height as usize);
+    if width == 0 || height == 0 {
+        return Err(IsccError::InvalidInput("image has no pixels".into()));
+    }
+    let expected = width
+        .checked_mul(height)
+        .and_then(|n| n.checked_mul(format.channels()));
+    if expected != Some(data.len()) {
+        return Err(IsccError::InvalidInput(format!(
+            "expected {width}×{height} pixels of {} bytes, got {} bytes",
+            format.channels(),
+            data.len()
+        )));
+    }
+    let pixels = imaging::normalize(width, height, format, data);
+    gen_image_code_v0(&pixels, bits)
+}
+
 /// Split a slice into `n` parts, distributing remainder across first chunks.
 ///
 /// Equivalent to `numpy.array_split` / `more_itertools.divide`:
@@ -660,7 +890,7 @@ fn soft_hash_audio_v0(cv: &[i32]) -> Vec<u8> {
 /// integer fingerprint vector using multi-stage SimHash.
 pub fn gen_audio_code_v0(cv: &[i32], bits: u32) -> IsccResult<AudioCodeResult> {
     let hash_digest = soft_hash_audio_v0(cv);
-    let component = codec::encode_component(
+    let unit = codec::IsccUnit::new(
         codec::MainType::Content,
         codec::SubType::Audio,
         codec::Version::V0,
@@ -668,10 +898,44 @@ pub fn gen_audio_code_v0(cv: &[i32], bits: u32) -> IsccResult<AudioCodeResult> {
         &hash_digest,
     )?;
     Ok(AudioCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
     })
 }
 
+/// Generate an Audio-Code from mono 16-bit PCM samples.
+///
+/// Computes the Chromaprint fingerprint with [`chromaprint_from_pcm`] and
+/// hashes it with `gen_audio_code_v0`. Requires the `audio` feature.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if `sample_rate` is below 1000 Hz and
+/// `IsccError::InvalidBits` for an invalid `bits` value.
+#[cfg(feature = "audio")]
+pub fn gen_audio_code_from_pcm(
+    samples: &[i16],
+    sample_rate: u32,
+    bits: u32,
+) -> IsccResult<AudioCodeResult> {
+    gen_audio_code_v0(&chromaprint_from_pcm(samples, sample_rate)?, bits)
+}
+
+/// Generate an Audio-Code from `fpcalc` output.
+///
+/// Accepts the compressed base64 fingerprint, a comma-separated list of raw
+/// integers (`fpcalc -raw`), or the complete plain or `-json` output of
+/// `fpcalc`, and hashes the decoded values with `gen_audio_code_v0`.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` for an undecodable fingerprint and
+/// `IsccError::InvalidJson` for malformed JSON output.
+pub fn gen_audio_code_from_chromaprint(fp: &str, bits: u32) -> IsccResult<AudioCodeResult> {
+    let cv = chromaprint::parse_fingerprint(fp)?;
+    gen_audio_code_v0(&cv, bits)
+}
+
 /// Compute a similarity-preserving hash from video frame signatures.
 ///
 /// Deduplicates frame signatures, computes column-wise sums across all
@@ -710,7 +974,7 @@ pub fn gen_video_code_v0<S: AsRef<[i32]> + Ord>(
     bits: u32,
 ) -> IsccResult<VideoCodeResult> {
     let digest = soft_hash_video_v0(frame_sigs, bits)?;
-    let component = codec::encode_component(
+    let unit = codec::IsccUnit::new(
         codec::MainType::Content,
         codec::SubType::Video,
         codec::Version::V0,
@@ -718,10 +982,30 @@ pub fn gen_video_code_v0<S: AsRef<[i32]> + Ord>(
         &digest,
     )?;
     Ok(VideoCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
     })
 }
 
+/// Generate a Video-Code from an FFmpeg `signature` filter output file.
+///
+/// Reads the binary or XML MPEG-7 signature written by e.g.
+/// `ffmpeg -i video.mp4 -vf signature=format=binary:filename=sig.bin -f null -`
+/// and hashes its per-frame fine signatures with `gen_video_code_v0`.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if the file cannot be read, the errors of
+/// [`parse_video_signature`] for malformed content, and
+/// `IsccError::InvalidInput` if the signature holds no frames.
+pub fn gen_video_code_from_signature_file(
+    path: &std::path::Path,
+    bits: u32,
+) -> IsccResult<VideoCodeResult> {
+    let signature = parse_video_signature(&std::fs::read(path)?)?;
+    gen_video_code_v0(&signature.frame_signatures(), bits)
+}
+
 /// Combine multiple Content-Code digests into a single similarity hash.
 ///
 /// Takes raw decoded ISCC bytes (header + body) for each Content-Code and
@@ -781,7 +1065,7 @@ pub fn gen_mixed_code_v0(codes: &[&str], bits: u32) -> IsccResult<MixedCodeResul
 
     let digest = soft_hash_codes_v0(&decoded, bits)?;
 
-    let component = codec::encode_component(
+    let unit = codec::IsccUnit::new(
         codec::MainType::Content,
         codec::SubType::Mixed,
         codec::Version::V0,
@@ -790,30 +1074,126 @@ pub fn gen_mixed_code_v0(codes: &[&str], bits: u32) -> IsccResult<MixedCodeResul
     )?;
 
     Ok(MixedCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
         parts: codes.iter().map(|s| s.to_string()).collect(),
     })
 }
 
+/// Domain separator for the per-dimension projection hashes of Semantic-Codes.
+const SEMANTIC_PROJECTION_SEED: &[u8] = b"iscc-semantic-v0";
+
+/// Compute a similarity-preserving digest from an embedding vector.
+///
+/// Weighted SimHash over seeded random projections: every dimension `j` gets
+/// a fixed 256-bit pattern `blake3(seed || j)`, and each output bit is the sign
+/// of the embedding-weighted sum of ±1 values taken from those patterns. This
+/// is random-hyperplane LSH, so the Hamming distance between digests tracks
+/// the cosine angle between embeddings and is invariant to vector scaling.
+/// Accumulation is done in `f64` in dimension order for deterministic output.
+fn soft_hash_semantic_v0(embedding: &[f32], bits: u32) -> IsccResult<Vec<u8>> {
+    if embedding.is_empty() {
+        return Err(IsccError::InvalidInput(
+            "embedding must not be empty".into(),
+        ));
+    }
+    if let Some(pos) = embedding.iter().position(|v| !v.is_finite()) {
+        return Err(IsccError::InvalidInput(format!(
+            "embedding contains a non-finite value at index {pos}"
+        )));
+    }
+    let nbits = bits.min(256) as usize;
+    let mut sums = vec![0f64; nbits];
+    for (j, &value) in embedding.iter().enumerate() {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(SEMANTIC_PROJECTION_SEED);
+        hasher.update(&(j as u32).to_le_bytes());
+        let pattern = hasher.finalize();
+        let pattern = pattern.as_bytes();
+        for (i, sum) in sums.iter_mut().enumerate() {
+            if pattern[i / 8] & (0x80 >> (i % 8)) != 0 {
+                *sum += f64::from(value);
+            } else {
+                *sum -= f64::from(value);
+            }
+        }
+    }
+    let signs: Vec<bool> = sums.iter().map(|&s| s >= 0.0).collect();
+    Ok(bits_to_bytes(&signs))
+}
+
+/// Generate a Semantic-Code from an embedding vector.
+///
+/// Turns a float embedding (e.g. from a text or image model) into a compact
+/// similarity-preserving ISCC unit of MainType Semantic. `subtype` declares the
+/// content modality and must be a Content SubType (`None` for text, `Image`,
+/// `Audio`, `Video`, or `Mixed`) so the unit composes with Content-Codes of
+/// the same modality in [`gen_iscc_code_v0`].
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if the embedding is empty or contains
+/// NaN/infinite values or `subtype` is not a Content SubType, and
+/// `IsccError::InvalidBits` if `bits` is not a multiple of 32 in 32..=256.
+pub fn gen_semantic_code_v0(
+    embedding: &[f32],
+    subtype: codec::SubType,
+    bits: u32,
+) -> IsccResult<SemanticCodeResult> {
+    if subtype as u8 > codec::SubType::Mixed as u8 {
+        return Err(IsccError::InvalidInput(format!(
+            "invalid Semantic-Code subtype: {subtype:?}"
+        )));
+    }
+    let digest = soft_hash_semantic_v0(embedding, bits)?;
+    let unit = codec::IsccUnit::new(
+        codec::MainType::Semantic,
+        subtype,
+        codec::Version::V0,
+        bits,
+        &digest,
+    )?;
+    Ok(SemanticCodeResult {
+        iscc: unit.to_string(),
+        unit,
+    })
+}
+
 /// Generate a Data-Code from raw byte data.
 ///
 /// Produces an ISCC Data-Code by splitting data into content-defined chunks,
 /// hashing each chunk with xxh32, and applying MinHash to create a
 /// similarity-preserving fingerprint.
 pub fn gen_data_code_v0(data: &[u8], bits: u32) -> IsccResult<DataCodeResult> {
-    let chunks = cdc::alg_cdc_chunks_unchecked(data, false, cdc::DATA_AVG_CHUNK_SIZE);
-    let mut features: Vec<u32> = chunks
-        .iter()
-        .map(|chunk| xxhash_rust::xxh32::xxh32(chunk, 0))
-        .collect();
+    data_code_v0(data, bits, false)
+}
+
+/// Generate a Data-Code from raw byte data, keeping per-chunk features.
+///
+/// Same code as [`gen_data_code_v0`], with `features` set to the offset,
+/// size and xxh32 hash of every content-defined chunk. Comparing the
+/// features of two similar files shows which byte ranges they share.
+pub fn gen_data_code_v0_granular(data: &[u8], bits: u32) -> IsccResult<DataCodeResult> {
+    data_code_v0(data, bits, true)
+}
+
+/// Shared implementation of the Data-Code generators.
+fn data_code_v0(data: &[u8], bits: u32, granular: bool) -> IsccResult<DataCodeResult> {
+    let chunks = cdc::data_chunks(data);
+    let mut features = cdc::chunk_features(&chunks);
 
     // Defensive: ensure at least one feature (alg_cdc_chunks guarantees >= 1 chunk)
     if features.is_empty() {
         features.push(xxhash_rust::xxh32::xxh32(b"", 0));
     }
 
+    let granular_features = granular.then(|| {
+        let sizes: Vec<u32> = chunks.iter().map(|chunk| chunk.len() as u32).collect();
+        streaming::data_features(&features, &sizes)
+    });
+
     let digest = minhash::alg_minhash_256(&features);
-    let component = codec::encode_component(
+    let unit = codec::IsccUnit::new(
         codec::MainType::Data,
         codec::SubType::None,
         codec::Version::V0,
@@ -822,7 +1202,9 @@ pub fn gen_data_code_v0(data: &[u8], bits: u32) -> IsccResult<DataCodeResult> {
     )?;
 
     Ok(DataCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
+        features: granular_features,
     })
 }
 
@@ -834,7 +1216,7 @@ pub fn gen_instance_code_v0(data: &[u8], bits: u32) -> IsccResult<InstanceCodeRe
     let digest = blake3::hash(data);
     let datahash = utils::multi_hash_blake3(data);
     let filesize = data.len() as u64;
-    let component = codec::encode_component(
+    let unit = codec::IsccUnit::new(
         codec::MainType::Instance,
         codec::SubType::None,
         codec::Version::V0,
@@ -842,7 +1224,8 @@ pub fn gen_instance_code_v0(data: &[u8], bits: u32) -> IsccResult<InstanceCodeRe
         digest.as_bytes(),
     )?;
     Ok(InstanceCodeResult {
-        iscc: format!("ISCC:{component}"),
+        iscc: unit.to_string(),
+        unit,
         datahash,
         filesize,
     })
@@ -944,9 +1327,8 @@ pub fn gen_iscc_code_v0(codes: &[&str], wide: bool) -> IsccResult<IsccCodeResult
         }
     };
 
-    // Step 10–11: Get optional MainTypes and encode
+    // Step 10–11: Get optional MainTypes
     let optional_types = &main_types[..n - 2];
-    let encoded_length = codec::encode_units(optional_types)?;
 
     // Step 12: Build digest body
     let bytes_per_unit = if is_wide { 16 } else { 8 };
@@ -956,30 +1338,147 @@ pub fn gen_iscc_code_v0(codes: &[&str], wide: bool) -> IsccResult<IsccCodeResult
         digest.extend_from_slice(&tail[..take]);
     }
 
-    // Step 13–14: Encode header + digest as base32
-    let header = codec::encode_header(
-        codec::MainType::Iscc,
-        st,
-        codec::Version::V0,
-        encoded_length,
-    )?;
-    let mut code_bytes = header;
-    code_bytes.extend_from_slice(&digest);
-    let code = codec::encode_base32(&code_bytes);
-
-    // Step 15: Return with prefix
+    // Step 13–15: Encode header + digest and return with prefix
+    let code = codec::IsccCode::new(st, codec::Version::V0, optional_types, &digest)?;
     Ok(IsccCodeResult {
-        iscc: format!("ISCC:{code}"),
+        iscc: code.to_string(),
+        code,
     })
 }
 
+/// Generate an ISCC-ID from an ISCC-CODE, chain, wallet address, and counter.
+///
+/// Builds a 64-bit SimHash over the first 8 bytes of every unit in the
+/// ISCC-CODE plus the first 8 bytes of the BLAKE3 hash of `wallet`, so the
+/// ID stays similarity-preserving while binding it to the declaring wallet.
+/// A non-zero uniqueness counter `uc` (used by registries to resolve
+/// collisions) is appended as a uvarint, growing the ID up to 96 bits.
+///
+/// Only the version 0 layout is generated. Version 1 IDs (timestamp and
+/// HUB-ID) are issued by an ISCC-HUB rather than derived from an ISCC-CODE,
+/// so they are out of scope here; see [`IsccId`].
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidInput` if `iscc_code` is not a valid ISCC-CODE,
+/// `chain_id` is not a known [`Chain`], or `uc` exceeds [`IsccId::MAX_COUNTER`].
+pub fn gen_iscc_id_v0(
+    iscc_code: &str,
+    chain_id: u8,
+    wallet: &str,
+    uc: u32,
+) -> IsccResult<IsccIdResult> {
+    let chain = codec::Chain::try_from(chain_id)?;
+    let code: codec::IsccCode = iscc_code.parse()?;
+    let mut digests: Vec<Vec<u8>> = code
+        .units()
+        .iter()
+        .map(|unit| unit.digest()[..8].to_vec())
+        .collect();
+    digests.push(blake3::hash(wallet.as_bytes()).as_bytes()[..8].to_vec());
+    let digest = simhash::alg_simhash_inner(&digests);
+    let id = codec::IsccId::new(chain, &digest, uc)?;
+    Ok(IsccIdResult {
+        iscc: id.to_string(),
+        id,
+    })
+}
+
+/// Generate a time-ordered Flake-Code (64 or 128 bits).
+///
+/// Uses a process-wide [`FlakeGenerator`] so codes from concurrent callers
+/// are unique and strictly ascending. Use [`FlakeGenerator::with_sources`]
+/// directly to inject the clock and randomness.
+///
+/// # Errors
+///
+/// Returns `IsccError::InvalidBits` if `bits` is not 64 or 128.
+pub fn gen_flake_code_v0(bits: u32) -> IsccResult<FlakeCodeResult> {
+    static GENERATOR: std::sync::OnceLock<std::sync::Mutex<FlakeGenerator>> =
+        std::sync::OnceLock::new();
+    GENERATOR
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .generate(bits)
+}
+
+/// Feed everything `reader` yields into `sink` in `IO_READ_SIZE` pieces.
+///
+/// Retries reads interrupted by a signal, like `std::io::copy`.
+fn read_all<R: std::io::Read>(mut reader: R, mut sink: impl FnMut(&[u8])) -> IsccResult<()> {
+    let mut buf = vec![0u8; IO_READ_SIZE];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => sink(&buf[..n]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Generate a Data-Code from everything a reader yields.
+///
+/// Streams `reader` to its end through a [`DataHasher`], so stdin, sockets
+/// and decompressing readers can be hashed without buffering the content.
+/// The result is identical to `gen_data_code_v0` over the same bytes.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if reading fails.
+pub fn gen_data_code_from_reader<R: std::io::Read>(
+    reader: R,
+    bits: u32,
+) -> IsccResult<DataCodeResult> {
+    let mut hasher = DataHasher::new();
+    read_all(reader, |data| hasher.update(data))?;
+    hasher.finalize(bits)
+}
+
+/// Generate an Instance-Code from everything a reader yields.
+///
+/// Streams `reader` to its end through an [`InstanceHasher`]. The result is
+/// identical to `gen_instance_code_v0` over the same bytes.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if reading fails.
+pub fn gen_instance_code_from_reader<R: std::io::Read>(
+    reader: R,
+    bits: u32,
+) -> IsccResult<InstanceCodeResult> {
+    let mut hasher = InstanceHasher::new();
+    read_all(reader, |data| hasher.update(data))?;
+    hasher.finalize(bits)
+}
+
+/// Generate a composite ISCC-CODE from everything a reader yields.
+///
+/// Streams `reader` to its end through a [`SumHasher`] (Data-Code and
+/// Instance-Code from the same read buffer). The result is identical to
+/// `gen_sum_code_v0` for a file with the same contents.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if reading fails.
+pub fn gen_sum_code_from_reader<R: std::io::Read>(
+    reader: R,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+) -> IsccResult<SumCodeResult> {
+    let mut hasher = SumHasher::new();
+    read_all(reader, |data| hasher.update(data))?;
+    hasher.finalize(bits, wide, add_units)
+}
+
 /// Generate a composite ISCC-CODE from a file in a single pass.
 ///
-/// Opens the file at `path`, reads it with an optimal buffer size, and feeds
-/// both `DataHasher` (CDC/MinHash) and `InstanceHasher` (BLAKE3) from the
-/// same read buffer. Composes the final ISCC-CODE from the Data-Code and
-/// Instance-Code internally. This avoids multiple passes over the file and
-/// eliminates per-chunk FFI overhead in language bindings.
+/// Opens the file at `path` and hashes it with [`gen_sum_code_from_reader`]
+/// (Data-Code and Instance-Code from the same read buffer). This avoids
+/// multiple passes over the file and eliminates per-chunk FFI overhead in
+/// language bindings.
 ///
 /// When `add_units` is `true`, the result includes the individual Data-Code
 /// and Instance-Code ISCC strings at the requested `bits` precision.
@@ -989,44 +1488,115 @@ pub fn gen_sum_code_v0(
     wide: bool,
     add_units: bool,
 ) -> IsccResult<SumCodeResult> {
-    use std::io::Read;
-
-    let mut file = std::fs::File::open(path)
-        .map_err(|e| IsccError::InvalidInput(format!("Cannot open file: {e}")))?;
+    gen_sum_code_from_reader(std::fs::File::open(path)?, bits, wide, add_units)
+}
 
-    let mut data_hasher = streaming::DataHasher::new();
-    let mut instance_hasher = streaming::InstanceHasher::new();
+/// Generate a composite ISCC-CODE from a file with progress and cancellation.
+///
+/// Same result as [`gen_sum_code_v0`]. After every `IO_READ_SIZE` block,
+/// `progress` is called with the bytes processed so far and the file size
+/// (`0` when unknown, e.g. for pipes). `cancel` is checked before every
+/// block read.
+///
+/// # Errors
+///
+/// Returns `IsccError::Cancelled` once `cancel` is cancelled, or
+/// `IsccError::Io` if the file cannot be read.
+pub fn gen_sum_code_with_progress(
+    path: &std::path::Path,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+    mut progress: impl FnMut(u64, u64),
+    cancel: Option<&CancelToken>,
+) -> IsccResult<SumCodeResult> {
+    use std::io::Read;
 
+    let mut file = std::fs::File::open(path)?;
+    let meta = file.metadata()?;
+    let total = if meta.is_file() { meta.len() } else { 0 };
+    let mut hasher = SumHasher::new();
+    let mut done = 0u64;
     let mut buf = vec![0u8; IO_READ_SIZE];
     loop {
-        let n = file
-            .read(&mut buf)
-            .map_err(|e| IsccError::InvalidInput(format!("Cannot read file: {e}")))?;
-        if n == 0 {
-            break;
+        if cancel.is_some_and(CancelToken::is_cancelled) {
+            return Err(IsccError::Cancelled);
         }
-        data_hasher.update(&buf[..n]);
-        instance_hasher.update(&buf[..n]);
+        let n = match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        hasher.update(&buf[..n]);
+        done += n as u64;
+        progress(done, total);
     }
+    hasher.finalize(bits, wide, add_units)
+}
 
-    let data_result = data_hasher.finalize(bits)?;
-    let instance_result = instance_hasher.finalize(bits)?;
-
-    // Borrow strings for gen_iscc_code_v0 before potentially moving them into units.
-    let iscc_result = gen_iscc_code_v0(&[&data_result.iscc, &instance_result.iscc], wide)?;
-
-    let units = if add_units {
-        Some(vec![data_result.iscc, instance_result.iscc])
-    } else {
-        None
-    };
+/// Generate a composite ISCC-CODE from a memory-mapped file.
+///
+/// With the `mmap` feature, a regular non-empty file is mapped and the whole
+/// region is hashed in one [`SumHasher::update`], so CDC and BLAKE3 work on
+/// the mapping directly instead of copying through an `IO_READ_SIZE` buffer.
+/// Empty files, pipes, other special files and builds without the feature
+/// fall back to the buffered reads of [`gen_sum_code_v0`]. The result is
+/// identical either way.
+///
+/// # Safety
+///
+/// The file must not be truncated or modified, by this or any other
+/// process, until the function returns. Truncating a mapped file makes the
+/// next read of a lost page raise `SIGBUS`, and concurrent writes change
+/// the bytes while they are hashed. Use [`gen_sum_code_v0`] for files that
+/// may change.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if the file cannot be opened, mapped or read.
+pub unsafe fn gen_sum_code_mmap(
+    path: &std::path::Path,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+) -> IsccResult<SumCodeResult> {
+    let file = std::fs::File::open(path)?;
+    #[cfg(feature = "mmap")]
+    // SAFETY: the caller guarantees the file does not change while mapped.
+    if let Some(map) = unsafe { mmap::map(&file)? } {
+        let mut hasher = SumHasher::new();
+        hasher.update(&map);
+        return hasher.finalize(bits, wide, add_units);
+    }
+    gen_sum_code_from_reader(file, bits, wide, add_units)
+}
 
-    Ok(SumCodeResult {
-        iscc: iscc_result.iscc,
-        datahash: instance_result.datahash,
-        filesize: instance_result.filesize,
-        units,
-    })
+/// Generate a composite ISCC-CODE from a file on a dedicated thread pool.
+///
+/// Same result as [`gen_sum_code_v0`], computed on a rayon pool of
+/// `threads` worker threads (`0` picks one per CPU). The Data-Code and
+/// Instance-Code pipelines run concurrently, with multithreaded BLAKE3 for
+/// the Instance-Code and parallel speculative CDC for the Data-Code. Plain
+/// [`gen_sum_code_v0`] uses the same pipelines on the global rayon pool.
+///
+/// # Errors
+///
+/// Returns `IsccError::Io` if the file cannot be read or the thread pool
+/// cannot be created.
+#[cfg(feature = "parallel")]
+pub fn gen_sum_code_parallel(
+    path: &std::path::Path,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+    threads: usize,
+) -> IsccResult<SumCodeResult> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(std::io::Error::other)?;
+    pool.install(|| gen_sum_code_v0(path, bits, wide, add_units))
 }
 
 #[cfg(test)]
@@ -1151,7 +1721,7 @@ mod tests {
     fn test_gen_meta_code_v0_invalid_json() {
         assert!(matches!(
             gen_meta_code_v0("test", None, Some("not json"), 64),
-            Err(IsccError::InvalidInput(_))
+            Err(IsccError::InvalidJson(_))
         ));
     }
 
@@ -1266,6 +1836,68 @@ mod tests {
         assert_eq!(result.characters, 10); // "helloworld" after collapse
     }
 
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_gen_text_code_v0_granular_features() {
+        let words = [
+            "quick", "brown", "Fox", "jumps", "över", "the", "lazy", "dög", "façade",
+        ];
+        let mut state = 7u32;
+        let text: String = (0..6000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                let word = words[(state >> 16) as usize % words.len()];
+                if state % 7 == 0 {
+                    format!("{word}. ")
+                } else {
+                    format!("{word} ")
+                }
+            })
+            .collect();
+        let plain = gen_text_code_v0(&text, 64).unwrap();
+        let granular = gen_text_code_v0_granular(&text, 64).unwrap();
+        assert_eq!(granular.iscc, plain.iscc);
+        assert_eq!(granular.characters, plain.characters);
+        assert!(plain.features.is_none());
+
+        let features = granular.features.unwrap();
+        assert!(features.len() > 5, "got {} chunks", features.len());
+        let collapsed: Vec<char> = text_collapse(&text).chars().collect();
+        let source: Vec<char> = text.chars().collect();
+        let mut offset = 0;
+        for feature in &features {
+            assert_eq!(feature.offset, offset);
+            offset += feature.size;
+            // The source span collapses to exactly the chunk's characters
+            let span: String = source
+                [feature.source_offset..feature.source_offset + feature.source_size]
+                .iter()
+                .collect();
+            let chunk: String = collapsed[feature.offset..offset].iter().collect();
+            assert_eq!(text_collapse(&span), chunk);
+        }
+        assert_eq!(offset, collapsed.len());
+
+        // Identical passages yield identical simprints
+        let shifted = format!("Preface. {text}");
+        let other = gen_text_code_v0_granular(&shifted, 64)
+            .unwrap()
+            .features
+            .unwrap();
+        let ours: std::collections::HashSet<u32> = features.iter().map(|f| f.simprint).collect();
+        assert!(other.iter().filter(|f| ours.contains(&f.simprint)).count() > 1);
+    }
+
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_gen_text_code_v0_granular_empty() {
+        let result = gen_text_code_v0_granular("", 64).unwrap();
+        assert_eq!(result.iscc, "ISCC:EAASL4F2WZY7KBXB");
+        let features = result.features.unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!((features[0].size, features[0].source_size), (0, 0));
+    }
+
     #[cfg(feature = "text-processing")]
     #[test]
     fn test_gen_text_code_v0_conformance() {
@@ -1323,6 +1955,264 @@ mod tests {
         assert!(gen_image_code_v0(&[0u8; 100], 64).is_err());
     }
 
+    #[test]
+    fn test_gen_image_code_from_pixels() {
+        let gray: Vec<u8> = (0..32u32)
+            .flat_map(|y| (0..32u32).map(move |x| ((x * 7 + y * 13) % 256) as u8))
+            .collect();
+        let expected = gen_image_code_v0(&gray, 64).unwrap().iscc;
+        let from_gray = gen_image_code_from_pixels(32, 32, PixelFormat::Gray, &gray, 64).unwrap();
+        assert_eq!(from_gray.iscc, expected);
+
+        // Equal channels and opaque alpha are the same image in every format.
+        let rgb: Vec<u8> = gray.iter().flat_map(|&p| [p, p, p]).collect();
+        let rgba: Vec<u8> = gray.iter().flat_map(|&p| [p, p, p, 255]).collect();
+        let la: Vec<u8> = gray.iter().flat_map(|&p| [p, 255]).collect();
+        for (format, data) in [
+            (PixelFormat::Rgb, &rgb),
+            (PixelFormat::Rgba, &rgba),
+            (PixelFormat::GrayAlpha, &la),
+        ] {
+            let result = gen_image_code_from_pixels(32, 32, format, data, 64).unwrap();
+            assert_eq!(result.iscc, expected, "{format:?}");
+        }
+
+        // Regression values for larger frames, which are downsampled first.
+        let big: Vec<u8> = (0..480u32)
+            .flat_map(|y| (0..640u32).flat_map(move |x| [(x / 3) as u8, (y / 2) as u8, 128]))
+            .collect();
+        let result = gen_image_code_from_pixels(640, 480, PixelFormat::Rgb, &big, 128).unwrap();
+        assert_eq!(result.iscc, "ISCC:EEB2AK2PNNDQ6XLCIFKY6V4PDKV4I");
+
+        // A gray frame gives the same code as its RGB and RGBA renderings.
+        let frame: Vec<u8> = (0..480u32)
+            .flat_map(|y| (0..640u32).map(move |x| ((x / 5 + y / 3) * 11 % 256) as u8))
+            .collect();
+        let rgb: Vec<u8> = frame.iter().flat_map(|&p| [p, p, p]).collect();
+        let rgba: Vec<u8> = frame.iter().flat_map(|&p| [p, p, p, 255]).collect();
+        for (format, data) in [
+            (PixelFormat::Gray, &frame),
+            (PixelFormat::Rgb, &rgb),
+            (PixelFormat::Rgba, &rgba),
+        ] {
+            let result = gen_image_code_from_pixels(640, 480, format, data, 64).unwrap();
+            assert_eq!(result.iscc, "ISCC:EEA57EI3WXREBI2W", "{format:?}");
+        }
+    }
+
+    #[test]
+    fn test_gen_image_code_from_pixels_matches_pillow() {
+        // Pillow's bicubic resize maps a constant line to the same constant
+        // and skips an axis whose size is unchanged, so a 640×32 frame of
+        // constant rows normalizes to those rows and a 32×480 frame of
+        // constant columns to those columns. All levels are within 100 of
+        // the corner, so iscc-sdk trims nothing.
+        let level = |i: usize| (100 + i * 37 % 101) as u8;
+        for (width, height, by_row) in [(640, 32, true), (32, 480, false)] {
+            let frame: Vec<u8> = (0..width * height)
+                .map(|i| level(if by_row { i / width } else { i % width }))
+                .collect();
+            let expected: Vec<u8> = (0..32 * 32)
+                .map(|i| level(if by_row { i / 32 } else { i % 32 }))
+                .collect();
+            let code = gen_image_code_v0(&expected, 64).unwrap().iscc;
+            let rgb: Vec<u8> = frame.iter().flat_map(|&p| [p, p, p]).collect();
+            let rgba: Vec<u8> = frame.iter().flat_map(|&p| [p, p, p, 255]).collect();
+            for (format, data) in [
+                (PixelFormat::Gray, &frame),
+                (PixelFormat::Rgb, &rgb),
+                (PixelFormat::Rgba, &rgba),
+            ] {
+                assert_eq!(
+                    imaging::normalize(width, height, format, data),
+                    expected,
+                    "{width}×{height} {format:?}"
+                );
+                let result =
+                    gen_image_code_from_pixels(width as u32, height as u32, format, data, 64)
+                        .unwrap();
+                assert_eq!(result.iscc, code, "{width}×{height} {format:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gen_image_code_from_pixels_invalid() {
+        for (width, height, len) in [(0, 4, 0), (4, 4, 15), (4, 4, 17)] {
+            assert!(matches!(
+                gen_image_code_from_pixels(width, height, PixelFormat::Gray, &vec![0; len], 64),
+                Err(IsccError::InvalidInput(_))
+            ));
+        }
+        assert!(matches!(
+            gen_image_code_from_pixels(u32::MAX, u32::MAX, PixelFormat::Rgba, &[0; 4], 64),
+            Err(IsccError::InvalidInput(_))
+        ));
+    }
+
+    #[cfg(feature = "image")]
+    fn encode_png(img: image::DynamicImage) -> Vec<u8> {
+        let mut png = Vec::new();
+        img.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        png
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_gen_image_code_from_bytes() {
+        // A 32×32 image whose content touches every edge is neither trimmed
+        // nor resized, so it hashes like the raw pixels.
+        let pixels: Vec<u8> = (0..32u32)
+            .flat_map(|y| (0..32u32).map(move |x| ((x * 7 + y * 13) % 256) as u8))
+            .collect();
+        let gray = image::GrayImage::from_raw(32, 32, pixels.clone()).unwrap();
+        let png = encode_png(gray.into());
+        assert_eq!(
+            gen_image_code_from_bytes(&png, 64).unwrap().iscc,
+            gen_image_code_v0(&pixels, 64).unwrap().iscc
+        );
+
+        // Fully transparent pixels are white whatever their color channels.
+        let clear = image::RgbaImage::from_pixel(40, 20, image::Rgba([0, 0, 0, 0]));
+        let png = encode_png(clear.into());
+        assert_eq!(
+            gen_image_code_from_bytes(&png, 64).unwrap().iscc,
+            gen_image_code_v0(&[255u8; 1024], 64).unwrap().iscc
+        );
+
+        // A uniform border is trimmed before resizing.
+        let framed = image::GrayImage::from_fn(96, 96, |x, y| {
+            let inner = (32..64).contains(&x) && (32..64).contains(&y);
+            image::Luma([if inner {
+                pixels[((y - 32) * 32 + x - 32) as usize]
+            } else {
+                0
+            }])
+        });
+        let trimmed =
+            image::GrayImage::from_fn(32, 32, |x, y| framed.get_pixel(x + 32, y + 32).to_owned());
+        assert_eq!(
+            gen_image_code_from_bytes(&encode_png(framed.into()), 64)
+                .unwrap()
+                .iscc,
+            gen_image_code_from_bytes(&encode_png(trimmed.into()), 64)
+                .unwrap()
+                .iscc
+        );
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_gen_image_code_from_bytes_exif_orientation() {
+        use image::ImageEncoder;
+
+        let img = image::GrayImage::from_fn(48, 24, |x, y| image::Luma([(x * 5 + y * 3) as u8]));
+        // Big-endian TIFF header with a single Orientation = 6 (rotate 90° CW) entry.
+        let exif = [
+            b'M', b'M', 0, 42, 0, 0, 0, 8, 0, 1, 0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0,
+            0,
+        ];
+        let mut tagged = Vec::new();
+        let mut encoder = image::codecs::png::PngEncoder::new(&mut tagged);
+        encoder.set_exif_metadata(exif.to_vec()).unwrap();
+        encoder
+            .write_image(&img, 48, 24, image::ExtendedColorType::L8)
+            .unwrap();
+        let rotated = image::imageops::rotate90(&img);
+        assert_eq!(
+            gen_image_code_from_bytes(&tagged, 64).unwrap().iscc,
+            gen_image_code_from_bytes(&encode_png(rotated.into()), 64)
+                .unwrap()
+                .iscc
+        );
+        assert_ne!(
+            gen_image_code_from_bytes(&tagged, 64).unwrap().iscc,
+            gen_image_code_from_bytes(&encode_png(img.into()), 64)
+                .unwrap()
+                .iscc
+        );
+    }
+
+    /// 45×77 RGBA image with a 32×32 gray pattern at (6, 5), framed by
+    /// opaque white and fully transparent black pixels.
+    #[cfg(feature = "image")]
+    fn framed_pattern(pattern: &[u8]) -> image::RgbaImage {
+        image::RgbaImage::from_fn(45, 77, |x, y| {
+            if (6..38).contains(&x) && (5..37).contains(&y) {
+                let v = pattern[((y - 5) * 32 + x - 6) as usize];
+                image::Rgba([v, v, v, 255])
+            } else if (x + y) % 2 == 0 {
+                image::Rgba([0, 0, 0, 0])
+            } else {
+                image::Rgba([255, 255, 255, 255])
+            }
+        })
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_gen_image_code_from_bytes_matches_iscc_sdk() {
+        use image::ImageEncoder;
+
+        // iscc-sdk flattens the frame to white and trims it, since every
+        // edge of the pattern differs from white by more than 100. That
+        // leaves the 32×32 pattern, which Pillow does not resize, so the
+        // Image-Code is that of the pattern itself.
+        let pattern: Vec<u8> = (0..32u32)
+            .flat_map(|y| (0..32u32).map(move |x| ((x * 7 + y * 13) % 256) as u8))
+            .collect();
+        let expected = gen_image_code_v0(&pattern, 64).unwrap().iscc;
+        assert_eq!(expected, "ISCC:EEAYYNMFG5XOELK2");
+
+        let framed = framed_pattern(&pattern);
+        assert_eq!(
+            gen_image_code_from_bytes(&encode_png(framed.clone().into()), 64)
+                .unwrap()
+                .iscc,
+            expected
+        );
+
+        // The same picture stored rotated, with EXIF orientation 6.
+        let flat = image::GrayImage::from_fn(45, 77, |x, y| {
+            let px = framed.get_pixel(x, y);
+            image::Luma([if px[3] == 0 { 255 } else { px[0] }])
+        });
+        let stored = image::imageops::rotate270(&flat);
+        let exif = [
+            b'M', b'M', 0, 42, 0, 0, 0, 8, 0, 1, 0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0,
+            0,
+        ];
+        let mut tagged = Vec::new();
+        let mut encoder = image::codecs::png::PngEncoder::new(&mut tagged);
+        encoder.set_exif_metadata(exif.to_vec()).unwrap();
+        encoder
+            .write_image(&stored, 77, 45, image::ExtendedColorType::L8)
+            .unwrap();
+        assert_eq!(
+            gen_image_code_from_bytes(&tagged, 64).unwrap().iscc,
+            expected
+        );
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_gen_image_code_from_bytes_errors() {
+        assert!(matches!(
+            gen_image_code_from_bytes(b"not an image", 64),
+            Err(IsccError::Unsupported(_))
+        ));
+        let png = encode_png(image::GrayImage::new(8, 8).into());
+        assert!(matches!(
+            gen_image_code_from_bytes(&png[..png.len() / 2], 64),
+            Err(IsccError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            gen_image_code_from_bytes(&png, 512),
+            Err(IsccError::InvalidBits { .. })
+        ));
+    }
+
     #[test]
     fn test_gen_image_code_v0_conformance() {
         let json_str = include_str!("../tests/data.json");
@@ -1377,6 +2267,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gen_audio_code_from_chromaprint() {
+        let raw = gen_audio_code_from_chromaprint("-1,0,1", 256).unwrap();
+        assert_eq!(raw.iscc, gen_audio_code_v0(&[-1, 0, 1], 256).unwrap().iscc);
+        let json = r#"{"duration": 1.5, "fingerprint": [4294967295, 0, 1]}"#;
+        assert_eq!(
+            gen_audio_code_from_chromaprint(json, 256).unwrap().iscc,
+            raw.iscc
+        );
+
+        // Compressed fingerprint of [1, 0] as printed by fpcalc.
+        let result = gen_audio_code_from_chromaprint("DURATION=1\nFINGERPRINT=AAAAAkEA", 64);
+        assert_eq!(
+            result.unwrap().iscc,
+            gen_audio_code_v0(&[1, 0], 64).unwrap().iscc
+        );
+        assert!(matches!(
+            gen_audio_code_from_chromaprint("AQAA!", 64),
+            Err(IsccError::InvalidInput(_))
+        ));
+    }
+
+    #[cfg(feature = "audio")]
+    #[test]
+    fn test_gen_audio_code_from_pcm() {
+        let samples: Vec<i16> = (0..11025 * 4)
+            .map(|i| ((i as f64 * 0.25).sin() * 8000.0) as i16)
+            .collect();
+        let cv = chromaprint_from_pcm(&samples, 11025).unwrap();
+        assert!(!cv.is_empty());
+        let result = gen_audio_code_from_pcm(&samples, 11025, 64).unwrap();
+        assert_eq!(result.iscc, gen_audio_code_v0(&cv, 64).unwrap().iscc);
+    }
+
     #[test]
     fn test_gen_audio_code_v0_conformance() {
         let json_str = include_str!("../tests/data.json");
@@ -1586,6 +2510,27 @@ mod tests {
         assert!(result.is_ok(), "expected Ok, got {result:?}");
     }
 
+    #[test]
+    fn test_gen_data_code_v0_granular_features() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+        let plain = gen_data_code_v0(&data, 64).unwrap();
+        let granular = gen_data_code_v0_granular(&data, 64).unwrap();
+        assert_eq!(granular.iscc, plain.iscc);
+        assert!(plain.features.is_none());
+
+        let features = granular.features.unwrap();
+        let chunks = alg_cdc_chunks(&data, false, cdc::DATA_AVG_CHUNK_SIZE).unwrap();
+        assert_eq!(features.len(), chunks.len());
+        let mut offset = 0u64;
+        for (feature, chunk) in features.iter().zip(&chunks) {
+            assert_eq!(feature.offset, offset);
+            assert_eq!(feature.size as usize, chunk.len());
+            assert_eq!(feature.feature, xxhash_rust::xxh32::xxh32(chunk, 0));
+            offset += u64::from(feature.size);
+        }
+        assert_eq!(offset, data.len() as u64);
+    }
+
     #[test]
     fn test_gen_data_code_v0_conformance() {
         let json_str = include_str!("../tests/data.json");
@@ -1849,11 +2794,15 @@ mod tests {
     #[test]
     fn test_encode_component_rejects_short_digest() {
         let result = encode_component(0, 0, 0, 64, &[0u8; 4]);
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
         assert!(
-            err.contains("digest length 4 < bit_length/8 (8)"),
-            "unexpected error: {err}"
+            matches!(
+                result,
+                Err(IsccError::Truncated {
+                    expected: 8,
+                    actual: 4
+                })
+            ),
+            "unexpected result: {result:?}"
         );
     }
 
@@ -1878,6 +2827,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ---- IsccError tests ----
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        assert_eq!(
+            IsccError::InvalidInput(String::new()).code(),
+            "invalid_input"
+        );
+        assert_eq!(codec::decode_base32("0").unwrap_err().code(), "base32");
+        assert_eq!(codec::MainType::try_from(9).unwrap_err().code(), "header");
+        assert_eq!(
+            codec::Version::try_from(1).unwrap_err().code(),
+            "unsupported"
+        );
+        assert_eq!(
+            gen_data_code_v0(b"x", 48).unwrap_err().code(),
+            "invalid_bits"
+        );
+        assert_eq!(iscc_decode("AAAZXZ6O").unwrap_err().code(), "truncated");
+        let json = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        assert_eq!(IsccError::from(json).code(), "invalid_json");
+        assert_eq!(iscc_normalize("").unwrap_err().code(), "validation");
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "gone");
+        assert_eq!(IsccError::from(io).code(), "io");
+    }
+
     // ---- iscc_decode tests ----
 
     /// Round-trip: encode a Meta-Code digest, decode back, verify all fields match.
@@ -2145,8 +3120,14 @@ mod tests {
         let json_str = format!(r#"{{"x":"{padding}"}}"#);
         let result = gen_meta_code_v0("test", None, Some(&json_str), 64);
         assert!(
-            matches!(result, Err(IsccError::InvalidInput(ref msg)) if msg.contains("size limit")),
-            "payload exceeding META_TRIM_META should return InvalidInput"
+            matches!(
+                result,
+                Err(IsccError::MetaTooLarge {
+                    size: 128_001,
+                    limit: META_TRIM_META
+                })
+            ),
+            "payload exceeding META_TRIM_META should return MetaTooLarge"
         );
     }
 
@@ -2160,11 +3141,185 @@ mod tests {
         let data_url = format!("data:application/octet-stream;base64,{padding}");
         let result = gen_meta_code_v0("test", None, Some(&data_url), 64);
         assert!(
-            matches!(result, Err(IsccError::InvalidInput(ref msg)) if msg.contains("size limit")),
+            matches!(result, Err(IsccError::MetaTooLarge { limit, .. }) if limit == pre_decode_limit),
             "oversized Data-URL should be rejected before decoding"
         );
     }
 
+    // ---- Typed result value tests ----
+
+    /// The typed `unit`/`code` fields display as the same string as `iscc`.
+    #[test]
+    fn test_result_typed_values_match_iscc() {
+        let data = gen_data_code_v0(b"typed values", 128).unwrap();
+        assert_eq!(data.unit.to_string(), data.iscc);
+        assert_eq!(data.unit.bit_length(), 128);
+        let instance = gen_instance_code_v0(b"typed values", 64).unwrap();
+        assert_eq!(instance.unit.to_string(), instance.iscc);
+        let code = gen_iscc_code_v0(&[&data.iscc, &instance.iscc], false).unwrap();
+        assert_eq!(code.code.to_string(), code.iscc);
+        assert_eq!(code.code.units()[1], instance.unit);
+    }
+
+    // ---- gen_semantic_code_v0 tests ----
+
+    /// Deterministic pseudo-embedding for tests.
+    fn test_embedding(seed: u32, dims: usize) -> Vec<f32> {
+        (0..dims)
+            .map(|i| ((i as f32 + 1.0) * (seed as f32 + 0.37)).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_gen_semantic_code_v0_basic() {
+        let embedding = test_embedding(1, 384);
+        let result = gen_semantic_code_v0(&embedding, codec::SubType::None, 64).unwrap();
+        assert_eq!(result.unit.to_string(), result.iscc);
+        assert_eq!(result.unit.maintype(), codec::MainType::Semantic);
+        assert_eq!(result.unit.bit_length(), 64);
+        assert_eq!(
+            result,
+            gen_semantic_code_v0(&embedding, codec::SubType::None, 64).unwrap()
+        );
+        // 64-bit digest is a prefix of the 256-bit digest
+        let wide = gen_semantic_code_v0(&embedding, codec::SubType::None, 256).unwrap();
+        assert_eq!(&wide.unit.digest()[..8], result.unit.digest());
+    }
+
+    #[test]
+    fn test_gen_semantic_code_v0_similarity() {
+        let embedding = test_embedding(1, 384);
+        let scaled: Vec<f32> = embedding.iter().map(|v| v * 3.5).collect();
+        let perturbed: Vec<f32> = embedding
+            .iter()
+            .enumerate()
+            .map(|(i, v)| if i % 50 == 0 { v + 0.05 } else { *v })
+            .collect();
+        let other = test_embedding(7, 384);
+        let semantic = |e: &[f32]| gen_semantic_code_v0(e, codec::SubType::None, 256).unwrap();
+        let base = semantic(&embedding);
+        assert_eq!(semantic(&scaled), base);
+        let near = iscc_distance(&base.iscc, &semantic(&perturbed).iscc).unwrap();
+        let far = iscc_distance(&base.iscc, &semantic(&other).iscc).unwrap();
+        assert!(near < 32, "near distance {near}");
+        assert!(far > near, "far {far} <= near {near}");
+    }
+
+    #[test]
+    fn test_gen_semantic_code_v0_rejects_invalid() {
+        let embedding = test_embedding(1, 16);
+        assert!(gen_semantic_code_v0(&[], codec::SubType::None, 64).is_err());
+        assert!(gen_semantic_code_v0(&[0.5, f32::NAN], codec::SubType::None, 64).is_err());
+        assert!(gen_semantic_code_v0(&embedding, codec::SubType::Sum, 64).is_err());
+        assert!(gen_semantic_code_v0(&embedding, codec::SubType::None, 48).is_err());
+        assert!(gen_semantic_code_v0(&embedding, codec::SubType::None, 512).is_err());
+    }
+
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_gen_semantic_code_v0_composes() {
+        let text = "Semantic and content units side by side";
+        let semantic =
+            gen_semantic_code_v0(&test_embedding(3, 64), codec::SubType::None, 64).unwrap();
+        let content = gen_text_code_v0(text, 64).unwrap();
+        let data = gen_data_code_v0(text.as_bytes(), 64).unwrap();
+        let instance = gen_instance_code_v0(text.as_bytes(), 64).unwrap();
+        let code = gen_iscc_code_v0(
+            &[&instance.iscc, &content.iscc, &data.iscc, &semantic.iscc],
+            false,
+        )
+        .unwrap();
+        let units = code.code.units();
+        assert_eq!(units.len(), 4);
+        assert_eq!(units[0], semantic.unit);
+        assert_eq!(units[1], content.unit);
+        assert_eq!(
+            code.code.optional_units(),
+            &[codec::MainType::Semantic, codec::MainType::Content]
+        );
+
+        let image =
+            gen_semantic_code_v0(&test_embedding(3, 64), codec::SubType::Image, 64).unwrap();
+        assert!(
+            gen_iscc_code_v0(
+                &[&image.iscc, &content.iscc, &data.iscc, &instance.iscc],
+                false
+            )
+            .is_err()
+        );
+    }
+
+    // ---- gen_iscc_id_v0 tests ----
+
+    #[test]
+    fn test_gen_iscc_id_v0_roundtrip() {
+        let data = gen_data_code_v0(b"iscc id", 64).unwrap();
+        let instance = gen_instance_code_v0(b"iscc id", 64).unwrap();
+        let code = gen_iscc_code_v0(&[&data.iscc, &instance.iscc], false).unwrap();
+        let result = gen_iscc_id_v0(&code.iscc, 1, "wallet", 0).unwrap();
+        assert_eq!(result.id.to_string(), result.iscc);
+        assert_eq!(result.id.chain(), Chain::Bitcoin);
+        assert_eq!(result.id.counter(), 0);
+        assert_eq!(result, gen_iscc_id_v0(&code.iscc, 1, "wallet", 0).unwrap());
+
+        let (mt, st, _vs, li, digest) = iscc_decode(&result.iscc).unwrap();
+        assert_eq!((mt, st, li), (codec::MainType::Id as u8, 1, 0));
+        assert_eq!(digest, result.id.digest());
+        assert_eq!(
+            iscc_decompose(&result.iscc).unwrap(),
+            vec![result.iscc[5..].to_string()]
+        );
+    }
+
+    #[test]
+    fn test_gen_iscc_id_v0_counter_and_wallet() {
+        let data = gen_data_code_v0(b"iscc id", 64).unwrap();
+        let instance = gen_instance_code_v0(b"iscc id", 64).unwrap();
+        let code = gen_iscc_code_v0(&[&data.iscc, &instance.iscc], false).unwrap();
+        let base = gen_iscc_id_v0(&code.iscc, 2, "wallet", 0).unwrap();
+        let counted = gen_iscc_id_v0(&code.iscc, 2, "wallet", 300).unwrap();
+        assert_eq!(counted.id.digest(), base.id.digest());
+        assert_eq!(counted.id.counter(), 300);
+        assert_eq!(counted.iscc.parse::<IsccId>().unwrap(), counted.id);
+        let (.., digest) = iscc_decode(&counted.iscc).unwrap();
+        assert_eq!(digest.len(), 10);
+        let other = gen_iscc_id_v0(&code.iscc, 2, "other wallet", 0).unwrap();
+        assert_ne!(other.id.digest(), base.id.digest());
+    }
+
+    #[test]
+    fn test_gen_iscc_id_v0_rejects_invalid() {
+        let data = gen_data_code_v0(b"iscc id", 64).unwrap();
+        let instance = gen_instance_code_v0(b"iscc id", 64).unwrap();
+        let code = gen_iscc_code_v0(&[&data.iscc, &instance.iscc], false).unwrap();
+        assert!(gen_iscc_id_v0(&data.iscc, 0, "wallet", 0).is_err());
+        assert!(gen_iscc_id_v0(&code.iscc, 4, "wallet", 0).is_err());
+        assert!(gen_iscc_id_v0(&code.iscc, 0, "wallet", IsccId::MAX_COUNTER + 1).is_err());
+    }
+
+    // ---- gen_flake_code_v0 tests ----
+
+    #[test]
+    fn test_gen_flake_code_v0_roundtrip() {
+        for bits in [64, 128] {
+            let result = gen_flake_code_v0(bits).unwrap();
+            assert_eq!(result.unit.to_string(), result.iscc);
+            let (mt, st, vs, _li, digest) = iscc_decode(&result.iscc).unwrap();
+            assert_eq!(mt, codec::MainType::Flake as u8);
+            assert_eq!((st, vs), (0, 0));
+            assert_eq!(digest, result.unit.digest());
+            assert_eq!(digest.len() * 8, bits as usize);
+        }
+    }
+
+    #[test]
+    fn test_gen_flake_code_v0_ascending() {
+        let a = gen_flake_code_v0(64).unwrap();
+        let b = gen_flake_code_v0(64).unwrap();
+        assert!(b.unit.digest() > a.unit.digest());
+        assert!(gen_flake_code_v0(32).is_err());
+    }
+
     // ---- gen_sum_code_v0 tests ----
 
     /// Helper: write data to a unique temp file and return the path.
@@ -2218,12 +3373,12 @@ mod tests {
     fn test_gen_sum_code_v0_file_not_found() {
         let path = std::env::temp_dir().join("iscc_test_nonexistent_file_xyz");
         let result = gen_sum_code_v0(&path, 64, false, false);
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
+        let err = result.unwrap_err();
         assert!(
-            err_msg.contains("Cannot open file"),
-            "error message should mention file open failure: {err_msg}"
+            matches!(err, IsccError::Io(_)),
+            "expected an I/O error for a missing file: {err}"
         );
+        assert_eq!(err.code(), "io");
     }
 
     #[test]
@@ -2369,4 +3524,193 @@ mod tests {
 
         std::fs::remove_file(&path).ok();
     }
+
+    /// Reader yielding `data` in short pieces with an interruption first.
+    struct TrickleReader<'a> {
+        data: &'a [u8],
+        interrupted: bool,
+    }
+
+    impl std::io::Read for TrickleReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if !self.interrupted {
+                self.interrupted = true;
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            let n = buf.len().min(self.data.len()).min(777);
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_gen_from_reader_matches_oneshot() {
+        let data: Vec<u8> = (0..50_000u32)
+            .map(|i| (i.wrapping_mul(131) >> 4) as u8)
+            .collect();
+        let reader = || TrickleReader {
+            data: &data,
+            interrupted: false,
+        };
+
+        let dc = gen_data_code_from_reader(reader(), 64).unwrap();
+        assert_eq!(dc.iscc, gen_data_code_v0(&data, 64).unwrap().iscc);
+        let ic = gen_instance_code_from_reader(reader(), 64).unwrap();
+        assert_eq!(
+            ic.datahash,
+            gen_instance_code_v0(&data, 64).unwrap().datahash
+        );
+
+        let path = write_temp_file("sum_reader", &data);
+        let sc = gen_sum_code_from_reader(reader(), 128, true, true).unwrap();
+        let expected = gen_sum_code_v0(&path, 128, true, true).unwrap();
+        assert_eq!(sc.iscc, expected.iscc);
+        assert_eq!(sc.filesize, expected.filesize);
+        assert_eq!(sc.units, expected.units);
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_gen_from_reader_propagates_errors() {
+        struct FailingReader;
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("connection reset"))
+            }
+        }
+        assert!(matches!(
+            gen_sum_code_from_reader(FailingReader, 64, false, false),
+            Err(IsccError::Io(_))
+        ));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_gen_sum_code_parallel_matches_serial() {
+        let mut state = 0x9e37_79b9_u32;
+        let data: Vec<u8> = (0..IO_READ_SIZE + 300_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state >> 11) as u8
+            })
+            .collect();
+        let path = write_temp_file("sum_parallel", &data);
+
+        let expected_data = gen_data_code_v0(&data, 64).unwrap();
+        let expected_instance = gen_instance_code_v0(&data, 64).unwrap();
+        for threads in [1, 3, 0] {
+            let result = gen_sum_code_parallel(&path, 64, false, true, threads).unwrap();
+            let units = result.units.unwrap();
+            assert_eq!(units[0], expected_data.iscc, "threads={threads}");
+            assert_eq!(units[1], expected_instance.iscc);
+            assert_eq!(result.datahash, expected_instance.datahash);
+            assert_eq!(result.filesize, data.len() as u64);
+        }
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_gen_sum_code_mmap_matches_buffered() {
+        let data: Vec<u8> = (0..IO_READ_SIZE as u32 + 70_000)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        for (name, bytes) in [("mmap_empty", &data[..0]), ("mmap_large", &data[..])] {
+            let path = write_temp_file(name, bytes);
+            // SAFETY: the temporary file is not modified while hashed.
+            let mapped = unsafe { gen_sum_code_mmap(&path, 128, true, true) }.unwrap();
+            let buffered = gen_sum_code_v0(&path, 128, true, true).unwrap();
+            assert_eq!(mapped.iscc, buffered.iscc, "{name}");
+            assert_eq!(mapped.datahash, buffered.datahash);
+            assert_eq!(mapped.filesize, buffered.filesize);
+            assert_eq!(mapped.units, buffered.units);
+            std::fs::remove_file(&path).ok();
+        }
+        let missing = std::path::Path::new("/nonexistent/iscc");
+        // SAFETY: there is no file to modify.
+        assert!(matches!(
+            unsafe { gen_sum_code_mmap(missing, 64, false, false) },
+            Err(IsccError::Io(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_gen_sum_code_mmap_special_file_falls_back() {
+        // `/dev/null` is not a regular file, so it is read, not mapped.
+        let path = std::path::Path::new("/dev/null");
+        // SAFETY: `/dev/null` is never mapped.
+        let result = unsafe { gen_sum_code_mmap(path, 64, false, false) }.unwrap();
+        assert_eq!(
+            result.iscc,
+            gen_sum_code_v0(path, 64, false, false).unwrap().iscc
+        );
+        assert_eq!(result.filesize, 0);
+    }
+
+    #[test]
+    fn test_gen_sum_code_with_progress() {
+        let data: Vec<u8> = (0..IO_READ_SIZE as u32 * 2 + 1000)
+            .map(|i| (i % 241) as u8)
+            .collect();
+        let path = write_temp_file("sum_progress", &data);
+
+        let mut calls = Vec::new();
+        let token = CancelToken::new();
+        let result = gen_sum_code_with_progress(
+            &path,
+            64,
+            false,
+            true,
+            |done, total| calls.push((done, total)),
+            Some(&token),
+        )
+        .unwrap();
+        let expected = gen_sum_code_v0(&path, 64, false, true).unwrap();
+        assert_eq!(result.iscc, expected.iscc);
+        assert_eq!(result.units, expected.units);
+        let total = data.len() as u64;
+        let size = IO_READ_SIZE as u64;
+        assert_eq!(calls, [(size, total), (2 * size, total), (total, total)]);
+
+        // Cancelling from the callback stops before the next block.
+        let mut calls = 0;
+        let err = gen_sum_code_with_progress(
+            &path,
+            64,
+            false,
+            false,
+            |_, _| {
+                calls += 1;
+                token.cancel();
+            },
+            Some(&token),
+        )
+        .unwrap_err();
+        assert!(matches!(err, IsccError::Cancelled));
+        assert_eq!(err.code(), "cancelled");
+        assert_eq!(calls, 1);
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_hashers_as_io_write() {
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i % 253) as u8).collect();
+        let mut hasher = SumHasher::new();
+        let copied = std::io::copy(&mut data.as_slice(), &mut hasher).unwrap();
+        assert_eq!(copied, data.len() as u64);
+        let result = hasher.finalize(64, false, true).unwrap();
+        let expected = gen_sum_code_from_reader(data.as_slice(), 64, false, true).unwrap();
+        assert_eq!(result.iscc, expected.iscc);
+        assert_eq!(result.units, expected.units);
+
+        let mut ih = InstanceHasher::new();
+        std::io::Write::write_all(&mut ih, &data).unwrap();
+        assert_eq!(
+            ih.finalize(64).unwrap().iscc,
+            gen_instance_code_v0(&data, 64).unwrap().iscc
+        );
+    }
 }
@@ -159,13 +159,48 @@ fn minhash(features: &[u32]) -> Vec<u64> {
         .map(|(&a, &b)| {
             features
                 .iter()
-                .map(|&f| (((a.wrapping_mul(f as u64).wrapping_add(b)) & MAXI64) % MPRIME) & MAXH)
+                .map(|&f| permute(a, b, f))
                 .min()
                 .unwrap_or(MAXH)
         })
         .collect()
 }
 
+/// Apply the universal hash function `(a, b)` to a single feature.
+#[inline]
+fn permute(a: u64, b: u64, f: u32) -> u64 {
+    (((a.wrapping_mul(f as u64).wrapping_add(b)) & MAXI64) % MPRIME) & MAXH
+}
+
+/// Incremental MinHash over a stream of 32-bit features.
+///
+/// Keeps the running minimum per dimension, so pushing features one at a
+/// time yields the same digest as `alg_minhash_256` over all of them.
+#[cfg(feature = "text-processing")]
+pub(crate) struct MinHashAccumulator {
+    mins: [u64; 64],
+}
+
+#[cfg(feature = "text-processing")]
+impl MinHashAccumulator {
+    /// Create an accumulator with no features.
+    pub(crate) fn new() -> Self {
+        Self { mins: [MAXH; 64] }
+    }
+
+    /// Fold a feature into the running minimums.
+    pub(crate) fn push(&mut self, feature: u32) {
+        for ((min, &a), &b) in self.mins.iter_mut().zip(&MPA).zip(&MPB) {
+            *min = (*min).min(permute(a, b, feature));
+        }
+    }
+
+    /// Compress the running minimums into a 256-bit digest.
+    pub(crate) fn digest_256(&self) -> Vec<u8> {
+        minhash_compress(&self.mins, 4)
+    }
+}
+
 /// Compress a MinHash vector by extracting and interleaving LSB bits.
 ///
 /// Extracts `lsb` least-significant bits from each hash value. Iterates
@@ -221,6 +256,18 @@ mod tests {
         assert!(result.iter().all(|&v| v <= MAXH));
     }
 
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_minhash_accumulator_matches_batch() {
+        let features = [7u32, 0xDEAD_BEEF, 42, u32::MAX];
+        let mut acc = MinHashAccumulator::new();
+        assert_eq!(acc.digest_256(), alg_minhash_256(&[]));
+        for &f in &features {
+            acc.push(f);
+        }
+        assert_eq!(acc.digest_256(), alg_minhash_256(&features));
+    }
+
     #[test]
     fn test_minhash_compress_basic() {
         // With lsb=1, extracting 1 bit from each of 64 hash values → 8 bytes
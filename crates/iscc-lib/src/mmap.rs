@@ -0,0 +1,98 @@
+//! Read-only file mapping with a buffered fallback.
+//!
+//! With the `mmap` feature, regular non-empty files are mapped into memory
+//! with `memmap2`. Everywhere else, and for empty files or special files such
+//! as pipes, the contents are read into an owned buffer instead.
+//!
+//! A mapping reflects later writes to the same file, and reading a mapped
+//! page after the file was truncated raises `SIGBUS`. Mapping is therefore
+//! `unsafe`: callers must guarantee the mapped range is neither modified nor
+//! truncated while mapped, as for append-only files or files replaced
+//! atomically via rename.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::ops::Deref;
+use std::path::Path;
+
+/// Contents of a file, either mapped or read into memory.
+pub(crate) enum FileBytes {
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Deref for FileBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(feature = "mmap")]
+            Self::Mapped(map) => map,
+            Self::Owned(buf) => buf,
+        }
+    }
+}
+
+/// Read the file at `path` into memory.
+pub(crate) fn read_file(path: &Path) -> io::Result<FileBytes> {
+    Ok(FileBytes::Owned(std::fs::read(path)?))
+}
+
+/// Map the file at `path`, falling back to reading it into memory.
+///
+/// # Safety
+///
+/// The file must not be truncated, and its current contents must not be
+/// modified, while the returned value is alive.
+pub(crate) unsafe fn map_file(path: &Path) -> io::Result<FileBytes> {
+    let mut file = File::open(path)?;
+    #[cfg(feature = "mmap")]
+    // SAFETY: upheld by the caller.
+    if let Some(map) = unsafe { map(&file)? } {
+        return Ok(FileBytes::Mapped(map));
+    }
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    Ok(FileBytes::Owned(buf))
+}
+
+/// Map `file` read-only.
+///
+/// Returns `None` for empty files and anything that is not a regular file,
+/// which cannot (or need not) be mapped.
+///
+/// # Safety
+///
+/// Same contract as [`map_file`].
+#[cfg(feature = "mmap")]
+pub(crate) unsafe fn map(file: &File) -> io::Result<Option<memmap2::Mmap>> {
+    let meta = file.metadata()?;
+    if !meta.is_file() || meta.len() == 0 {
+        return Ok(None);
+    }
+    // SAFETY: upheld by the caller.
+    unsafe { memmap2::Mmap::map(file) }.map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_read_file_contents_and_empty() {
+        let mut tmp = tempfile::NamedTempFile::new().unwrap();
+        tmp.write_all(b"hello mapped world").unwrap();
+        tmp.flush().unwrap();
+        assert_eq!(&*read_file(tmp.path()).unwrap(), b"hello mapped world");
+        // SAFETY: the temporary file is not modified while mapped.
+        let mapped = unsafe { map_file(tmp.path()) }.unwrap();
+        assert_eq!(&*mapped, b"hello mapped world");
+
+        let empty = tempfile::NamedTempFile::new().unwrap();
+        assert!(read_file(empty.path()).unwrap().is_empty());
+        // SAFETY: as above.
+        assert!(unsafe { map_file(empty.path()) }.unwrap().is_empty());
+    }
+}
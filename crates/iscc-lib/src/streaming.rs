@@ -3,17 +3,54 @@
 //! Provides `DataHasher` and `InstanceHasher` — streaming counterparts to
 //! `gen_data_code_v0` and `gen_instance_code_v0`. Both follow the
 //! `new() → update(&[u8]) → finalize()` pattern for incremental processing
-//! of large files without loading entire contents into memory.
+//! of large files without loading entire contents into memory. `SumHasher`
+//! feeds both at once to produce a full ISCC-SUM, and `TextHasher` does the
+//! same for `gen_text_code_v0` with `update(&str)`. The byte hashers also
+//! implement `std::io::Write`, so they work as `std::io::copy` targets.
+//!
+//! `DataHasher` and `InstanceHasher` can be paused with `save_state` and
+//! resumed, possibly on another machine, with `restore_state`. A snapshot is
+//! a versioned little-endian byte string:
+//!
+//! ```text
+//! header    magic "ISCC-DHS" / "ISCC-IHS" | version: u32 | checksum: u32
+//! DataHasher      chunk count: u64 | tail length: u64
+//!                 | features: [u32; n] | sizes: [u32; n] | tail bytes
+//! InstanceHasher  filesize: u64 | subtree count: u32 | pending length: u32
+//!                 | chaining values: [[u8; 32]; m] | pending bytes
+//! ```
+//!
+//! The checksum is XXH32 over everything after the header.
+
+#[cfg(feature = "text-processing")]
+use crate::types::TextCodeResult;
+use crate::types::{DataCodeResult, DataFeature, InstanceCodeResult, SumCodeResult};
+use crate::{IsccError, IsccResult, cdc, codec, minhash};
+use blake3::hazmat::{ChainingValue, HasherExt, Mode};
+#[cfg(feature = "text-processing")]
+use unicode_normalization::UnicodeNormalization;
+
+/// Magic bytes of a `DataHasher` snapshot.
+const DATA_STATE_MAGIC: &[u8; 8] = b"ISCC-DHS";
+
+/// Magic bytes of an `InstanceHasher` snapshot.
+const INSTANCE_STATE_MAGIC: &[u8; 8] = b"ISCC-IHS";
+
+/// Current snapshot format version.
+pub const HASHER_STATE_VERSION: u32 = 1;
+
+/// Largest slice `DataHasher` chunks in one pass (64 MiB).
+const DATA_WINDOW_SIZE: usize = 1 << 26;
 
-use crate::types::{DataCodeResult, InstanceCodeResult};
-use crate::{IsccResult, cdc, codec, minhash};
+/// Size of the snapshot header in bytes.
+const STATE_HEADER_SIZE: usize = 16;
 
 /// Streaming Instance-Code generator.
 ///
 /// Incrementally hashes data with BLAKE3 to produce an ISCC Instance-Code
 /// identical to `gen_instance_code_v0` for the same byte stream.
 pub struct InstanceHasher {
-    hasher: blake3::Hasher,
+    tree: Blake3Tree,
     filesize: u64,
 }
 
@@ -21,7 +58,7 @@ impl InstanceHasher {
     /// Create a new `InstanceHasher`.
     pub fn new() -> Self {
         Self {
-            hasher: blake3::Hasher::new(),
+            tree: Blake3Tree::new(),
             filesize: 0,
         }
     }
@@ -29,7 +66,7 @@ impl InstanceHasher {
     /// Push data into the hasher.
     pub fn update(&mut self, data: &[u8]) {
         self.filesize += data.len() as u64;
-        self.hasher.update(data);
+        self.tree.update(data);
     }
 
     /// Consume the hasher and produce an Instance-Code result.
@@ -37,9 +74,9 @@ impl InstanceHasher {
     /// Equivalent to calling `gen_instance_code_v0` with the concatenation
     /// of all data passed to `update`.
     pub fn finalize(self, bits: u32) -> IsccResult<InstanceCodeResult> {
-        let digest = self.hasher.finalize();
+        let digest = self.tree.finalize();
         let datahash = format!("1e20{}", hex::encode(digest.as_bytes()));
-        let component = codec::encode_component(
+        let unit = codec::IsccUnit::new(
             codec::MainType::Instance,
             codec::SubType::None,
             codec::Version::V0,
@@ -47,11 +84,75 @@ impl InstanceHasher {
             digest.as_bytes(),
         )?;
         Ok(InstanceCodeResult {
-            iscc: format!("ISCC:{component}"),
+            iscc: unit.to_string(),
+            unit,
             datahash,
             filesize: self.filesize,
         })
     }
+
+    /// Serialize the BLAKE3 chaining state and filesize into a snapshot.
+    ///
+    /// The snapshot holds at most one pending 1 KiB chunk plus one chaining
+    /// value per completed subtree, independent of how much data was hashed.
+    pub fn save_state(&self) -> Vec<u8> {
+        let tree = &self.tree;
+        let mut body = Vec::with_capacity(16 + tree.stack.len() * 32 + tree.pending.len());
+        body.extend_from_slice(&self.filesize.to_le_bytes());
+        body.extend_from_slice(&(tree.stack.len() as u32).to_le_bytes());
+        body.extend_from_slice(&(tree.pending.len() as u32).to_le_bytes());
+        for cv in &tree.stack {
+            body.extend_from_slice(cv);
+        }
+        body.extend_from_slice(&tree.pending);
+        encode_state(INSTANCE_STATE_MAGIC, body)
+    }
+
+    /// Rebuild a hasher from a snapshot produced by `save_state`.
+    ///
+    /// Returns `IsccError::Unsupported` for an unknown snapshot version,
+    /// `IsccError::Truncated` for a cut-off snapshot, or
+    /// `IsccError::InvalidInput` for bad magic bytes, checksum or contents.
+    pub fn restore_state(state: &[u8]) -> IsccResult<Self> {
+        let body = decode_state(INSTANCE_STATE_MAGIC, state)?;
+        check_state_len(body, 16)?;
+        let filesize = read_u64(body, 0);
+        let depth = read_u32(body, 8) as usize;
+        let pending_len = read_u32(body, 12) as usize;
+        let inconsistent = || IsccError::InvalidInput("inconsistent InstanceHasher state".into());
+        // Bound the untrusted sizes before they enter any offset arithmetic.
+        if depth > 2 * u64::BITS as usize || pending_len > blake3::CHUNK_LEN {
+            return Err(inconsistent());
+        }
+        check_state_body(body, 16 + depth * 32 + pending_len, "InstanceHasher")?;
+
+        let stack: Vec<ChainingValue> = body[16..16 + depth * 32]
+            .chunks_exact(32)
+            .map(|cv| ChainingValue::try_from(cv).expect("chunks are 32 bytes"))
+            .collect();
+        let pending = body[16 + depth * 32..].to_vec();
+        let committed = filesize.saturating_sub(pending_len as u64);
+        let chunks = committed / blake3::CHUNK_LEN as u64;
+
+        // Lazy merging leaves at least one entry per set bit of `chunks`
+        // and, without pending bytes, at least two entries to merge.
+        let consistent = committed + pending_len as u64 == filesize
+            && committed % blake3::CHUNK_LEN as u64 == 0
+            && depth >= chunks.count_ones() as usize
+            && (chunks == 0) == (depth == 0)
+            && (chunks == 0 || pending_len > 0 || depth > 1);
+        if !consistent {
+            return Err(inconsistent());
+        }
+        Ok(Self {
+            tree: Blake3Tree {
+                stack,
+                chunks,
+                pending,
+            },
+            filesize,
+        })
+    }
 }
 
 impl Default for InstanceHasher {
@@ -61,6 +162,195 @@ impl Default for InstanceHasher {
     }
 }
 
+impl std::io::Write for InstanceHasher {
+    /// Push `buf` into the hasher. Never fails and always consumes all bytes.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Incremental BLAKE3 hasher with a serializable chaining state.
+///
+/// `blake3::Hasher` keeps its tree state private, so this rebuilds the same
+/// tree from `hazmat` subtree hashes. `stack` holds the chaining values of
+/// the completed subtrees covering the first `chunks` chunks and `pending`
+/// the bytes of the current chunk. Like `blake3::Hasher`, merging is lazy:
+/// the last two entries stay apart until more input proves neither is the
+/// root.
+struct Blake3Tree {
+    stack: Vec<ChainingValue>,
+    chunks: u64,
+    pending: Vec<u8>,
+}
+
+impl Blake3Tree {
+    fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            chunks: 0,
+            pending: Vec::with_capacity(blake3::CHUNK_LEN),
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        if !self.pending.is_empty() {
+            let take = (blake3::CHUNK_LEN - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if data.is_empty() {
+                return;
+            }
+            let cv = self.subtree_cv(&self.pending);
+            self.push(cv, 1);
+            self.pending.clear();
+        }
+
+        // Hash the largest aligned power-of-two subtrees. Multi-chunk
+        // subtrees are pushed as two halves so a final subtree can still
+        // become the root.
+        while data.len() > blake3::CHUNK_LEN {
+            let mut count = 1u64 << (data.len() / blake3::CHUNK_LEN).ilog2();
+            if self.chunks > 0 {
+                count = count.min(1 << self.chunks.trailing_zeros());
+            }
+            let (subtree, rest) = data.split_at(count as usize * blake3::CHUNK_LEN);
+            if count == 1 {
+                let cv = self.subtree_cv(subtree);
+                self.push(cv, 1);
+            } else {
+                let (left, right) = subtree.split_at(subtree.len() / 2);
+                let cv = self.subtree_cv(left);
+                self.push(cv, count / 2);
+                let cv = self.subtree_cv(right);
+                self.push(cv, count / 2);
+            }
+            data = rest;
+        }
+        self.pending.extend_from_slice(data);
+    }
+
+    /// Chaining value of a subtree starting at the current chunk offset.
+    ///
+    /// With the `parallel` feature, subtrees of 128 KiB and more are hashed
+    /// on the rayon thread pool.
+    fn subtree_cv(&self, data: &[u8]) -> ChainingValue {
+        let mut hasher = blake3::Hasher::new();
+        hasher.set_input_offset(self.chunks * blake3::CHUNK_LEN as u64);
+        #[cfg(feature = "parallel")]
+        if data.len() >= 128 * 1024 {
+            return hasher.update_rayon(data).finalize_non_root();
+        }
+        hasher.update(data).finalize_non_root()
+    }
+
+    /// Push a completed subtree of `count` chunks after merging the
+    /// subtrees before it.
+    fn push(&mut self, cv: ChainingValue, count: u64) {
+        self.merge_stack();
+        self.stack.push(cv);
+        self.chunks += count;
+    }
+
+    /// Merge completed sibling pairs, leaving one entry per set bit of
+    /// `chunks`. Only valid once more input is known to follow.
+    fn merge_stack(&mut self) {
+        while self.stack.len() > self.chunks.count_ones() as usize {
+            let right = self.stack.pop().unwrap_or_default();
+            let left = self.stack.pop().unwrap_or_default();
+            self.stack.push(blake3::hazmat::merge_subtrees_non_root(
+                &left,
+                &right,
+                Mode::Hash,
+            ));
+        }
+    }
+
+    fn finalize(mut self) -> blake3::Hash {
+        if self.stack.is_empty() {
+            return blake3::hash(&self.pending);
+        }
+        let mut right = if self.pending.is_empty() {
+            self.stack.pop().unwrap_or_default()
+        } else {
+            self.merge_stack();
+            self.subtree_cv(&self.pending)
+        };
+        while self.stack.len() > 1 {
+            let left = self.stack.pop().unwrap_or_default();
+            right = blake3::hazmat::merge_subtrees_non_root(&left, &right, Mode::Hash);
+        }
+        blake3::hazmat::merge_subtrees_root(&self.stack[0], &right, Mode::Hash)
+    }
+}
+
+/// Frame a snapshot body with magic bytes, version and checksum.
+fn encode_state(magic: &[u8; 8], body: Vec<u8>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(STATE_HEADER_SIZE + body.len());
+    out.extend_from_slice(magic);
+    out.extend_from_slice(&HASHER_STATE_VERSION.to_le_bytes());
+    out.extend_from_slice(&xxhash_rust::xxh32::xxh32(&body, 0).to_le_bytes());
+    out.extend_from_slice(&body);
+    out
+}
+
+/// Validate a snapshot header and return its body.
+fn decode_state<'a>(magic: &[u8; 8], state: &'a [u8]) -> IsccResult<&'a [u8]> {
+    check_state_len(state, STATE_HEADER_SIZE)?;
+    if &state[..8] != magic {
+        return Err(IsccError::InvalidInput(
+            "not a matching hasher state".into(),
+        ));
+    }
+    let version = read_u32(state, 8);
+    if version != HASHER_STATE_VERSION {
+        return Err(IsccError::Unsupported(format!(
+            "hasher state version {version}"
+        )));
+    }
+    let body = &state[STATE_HEADER_SIZE..];
+    if xxhash_rust::xxh32::xxh32(body, 0) != read_u32(state, 12) {
+        return Err(IsccError::InvalidInput(
+            "hasher state checksum mismatch".into(),
+        ));
+    }
+    Ok(body)
+}
+
+/// Fail with `IsccError::Truncated` unless `data` holds `expected` bytes.
+fn check_state_len(data: &[u8], expected: usize) -> IsccResult<()> {
+    if data.len() < expected {
+        return Err(IsccError::Truncated {
+            expected,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Require a snapshot body of exactly `expected` bytes.
+fn check_state_body(body: &[u8], expected: usize, what: &str) -> IsccResult<()> {
+    check_state_len(body, expected)?;
+    if body.len() > expected {
+        return Err(IsccError::InvalidInput(format!(
+            "trailing bytes in {what} state"
+        )));
+    }
+    Ok(())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap_or_default())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap_or_default())
+}
+
 /// Streaming Data-Code generator.
 ///
 /// Incrementally processes data with content-defined chunking (CDC) and
@@ -69,6 +359,8 @@ impl Default for InstanceHasher {
 /// per-call heap allocations.
 pub struct DataHasher {
     chunk_features: Vec<u32>,
+    /// Byte length of each hashed chunk, parallel to `chunk_features`.
+    chunk_sizes: Vec<u32>,
     buf: Vec<u8>,
 }
 
@@ -77,56 +369,165 @@ impl DataHasher {
     pub fn new() -> Self {
         Self {
             chunk_features: Vec::new(),
+            chunk_sizes: Vec::new(),
             buf: Vec::new(),
         }
     }
 
     /// Push data into the hasher.
     ///
-    /// Appends data to the internal buffer (which starts with the retained
-    /// tail from the previous call), runs CDC, hashes all complete chunks,
-    /// and shifts the last chunk (tail) to the front of the buffer for the
-    /// next call. The buffer is reused across calls to avoid allocations.
+    /// Small updates are appended to the internal buffer, which starts with
+    /// the retained tail (the last, possibly incomplete chunk) from the
+    /// previous call, and chunked there. Larger updates only complete the
+    /// tail's chunk in the buffer and are otherwise chunked in place, so big
+    /// or memory-mapped slices are never copied. Either way the last chunk
+    /// is kept as the tail for the next call.
     pub fn update(&mut self, data: &[u8]) {
-        self.buf.extend_from_slice(data);
+        self.update_windowed(data, DATA_WINDOW_SIZE);
+    }
 
-        let chunks = cdc::alg_cdc_chunks_unchecked(&self.buf, false, cdc::DATA_AVG_CHUNK_SIZE);
+    fn update_windowed(&mut self, data: &[u8], window: usize) {
+        let (_, max_size, _, _, _) = cdc::alg_cdc_params(cdc::DATA_AVG_CHUNK_SIZE);
+        if data.len() < max_size {
+            // Detach the buffer so chunks can borrow it while hashing.
+            let mut buf = std::mem::take(&mut self.buf);
+            buf.extend_from_slice(data);
+            let tail_len = self.push_complete(&cdc::data_chunks(&buf));
 
-        // Process all chunks except the last (which becomes the new tail).
-        // This mirrors the Python `push()` method's `prev_chunk` pattern.
-        let mut prev_chunk: Option<&[u8]> = None;
-        for chunk in &chunks {
-            if let Some(pc) = prev_chunk {
-                self.chunk_features.push(xxhash_rust::xxh32::xxh32(pc, 0));
+            // Shift tail to front of buffer, reusing existing capacity
+            let tail_start = buf.len() - tail_len;
+            buf.copy_within(tail_start.., 0);
+            buf.truncate(tail_len);
+            self.buf = buf;
+            return;
+        }
+
+        // With `max_size` bytes of lookahead the tail's cut point is final.
+        let mut rest = data;
+        if !self.buf.is_empty() {
+            let mut buf = std::mem::take(&mut self.buf);
+            let tail_len = buf.len();
+            buf.extend_from_slice(&data[..max_size]);
+            let cut = cdc::data_cut(&buf);
+            debug_assert!(cut >= tail_len);
+            self.push_chunk(&buf[..cut]);
+            rest = &data[cut - tail_len..];
+            buf.clear();
+            self.buf = buf;
+        }
+
+        // Chunk in bounded windows to bound the size of the chunk list. Only
+        // a window's last chunk may depend on the bytes after it, so it is
+        // re-chunked as the start of the next window.
+        loop {
+            let end = rest.len().min(window);
+            let chunks = cdc::data_chunks(&rest[..end]);
+            let tail_len = self.push_complete(&chunks);
+            if end == rest.len() {
+                self.buf.extend_from_slice(&rest[end - tail_len..]);
+                return;
             }
-            prev_chunk = Some(chunk);
+            rest = &rest[end - tail_len..];
         }
+    }
 
-        // Extract tail length before dropping borrows on self.buf
-        let tail_len = prev_chunk.map_or(0, |c| c.len());
-        drop(chunks);
+    /// Hash all chunks but the last and return the last chunk's length.
+    fn push_complete(&mut self, chunks: &[&[u8]]) -> usize {
+        // This mirrors the Python `push()` method's `prev_chunk` pattern.
+        let Some((tail, complete)) = chunks.split_last() else {
+            return 0;
+        };
+        self.chunk_features.extend(cdc::chunk_features(complete));
+        self.chunk_sizes
+            .extend(complete.iter().map(|chunk| chunk.len() as u32));
+        tail.len()
+    }
 
-        // Shift tail to front of buffer, reusing existing capacity
-        let tail_start = self.buf.len() - tail_len;
-        self.buf.copy_within(tail_start.., 0);
-        self.buf.truncate(tail_len);
+    fn push_chunk(&mut self, chunk: &[u8]) {
+        self.chunk_features
+            .push(xxhash_rust::xxh32::xxh32(chunk, 0));
+        self.chunk_sizes.push(chunk.len() as u32);
     }
 
     /// Consume the hasher and produce a Data-Code result.
     ///
     /// Equivalent to calling `gen_data_code_v0` with the concatenation
     /// of all data passed to `update`.
-    pub fn finalize(mut self, bits: u32) -> IsccResult<DataCodeResult> {
+    pub fn finalize(self, bits: u32) -> IsccResult<DataCodeResult> {
+        self.finish(bits, false)
+    }
+
+    /// Consume the hasher and produce a Data-Code result with per-chunk
+    /// features.
+    ///
+    /// Equivalent to calling `gen_data_code_v0_granular` with the
+    /// concatenation of all data passed to `update`.
+    pub fn finalize_granular(self, bits: u32) -> IsccResult<DataCodeResult> {
+        self.finish(bits, true)
+    }
+
+    /// Serialize the chunk features and tail buffer into a snapshot.
+    ///
+    /// The snapshot grows by 8 bytes per hashed chunk (about 1 KiB of input
+    /// on average) plus the retained tail of at most 8 KiB.
+    pub fn save_state(&self) -> Vec<u8> {
+        let count = self.chunk_features.len();
+        let mut body = Vec::with_capacity(16 + count * 8 + self.buf.len());
+        body.extend_from_slice(&(count as u64).to_le_bytes());
+        body.extend_from_slice(&(self.buf.len() as u64).to_le_bytes());
+        for feature in &self.chunk_features {
+            body.extend_from_slice(&feature.to_le_bytes());
+        }
+        for size in &self.chunk_sizes {
+            body.extend_from_slice(&size.to_le_bytes());
+        }
+        body.extend_from_slice(&self.buf);
+        encode_state(DATA_STATE_MAGIC, body)
+    }
+
+    /// Rebuild a hasher from a snapshot produced by `save_state`.
+    ///
+    /// Returns `IsccError::Unsupported` for an unknown snapshot version,
+    /// `IsccError::Truncated` for a cut-off snapshot, or
+    /// `IsccError::InvalidInput` for bad magic bytes, checksum or contents.
+    pub fn restore_state(state: &[u8]) -> IsccResult<Self> {
+        let body = decode_state(DATA_STATE_MAGIC, state)?;
+        check_state_len(body, 16)?;
+        let count = usize::try_from(read_u64(body, 0)).unwrap_or(usize::MAX);
+        let tail_len = usize::try_from(read_u64(body, 8)).unwrap_or(usize::MAX);
+        let (_, max_size, _, _, _) = cdc::alg_cdc_params(cdc::DATA_AVG_CHUNK_SIZE);
+        if count > body.len() / 8 || tail_len > max_size {
+            return Err(IsccError::InvalidInput(
+                "inconsistent DataHasher state".into(),
+            ));
+        }
+        check_state_body(body, 16 + count * 8 + tail_len, "DataHasher")?;
+
+        let words = |start: usize| -> Vec<u32> {
+            (0..count).map(|i| read_u32(body, start + i * 4)).collect()
+        };
+        let mut buf = Vec::with_capacity(max_size);
+        buf.extend_from_slice(&body[16 + count * 8..]);
+        Ok(Self {
+            chunk_features: words(16),
+            chunk_sizes: words(16 + count * 4),
+            buf,
+        })
+    }
+
+    fn finish(mut self, bits: u32, granular: bool) -> IsccResult<DataCodeResult> {
         if !self.buf.is_empty() {
             self.chunk_features
                 .push(xxhash_rust::xxh32::xxh32(&self.buf, 0));
+            self.chunk_sizes.push(self.buf.len() as u32);
         } else if self.chunk_features.is_empty() {
             // Empty input: ensure at least one feature
             self.chunk_features.push(xxhash_rust::xxh32::xxh32(b"", 0));
+            self.chunk_sizes.push(0);
         }
 
         let digest = minhash::alg_minhash_256(&self.chunk_features);
-        let component = codec::encode_component(
+        let unit = codec::IsccUnit::new(
             codec::MainType::Data,
             codec::SubType::None,
             codec::Version::V0,
@@ -135,11 +536,31 @@ impl DataHasher {
         )?;
 
         Ok(DataCodeResult {
-            iscc: format!("ISCC:{component}"),
+            iscc: unit.to_string(),
+            unit,
+            features: granular.then(|| data_features(&self.chunk_features, &self.chunk_sizes)),
         })
     }
 }
 
+/// Pair chunk features with their sizes and running byte offsets.
+pub(crate) fn data_features(features: &[u32], sizes: &[u32]) -> Vec<DataFeature> {
+    let mut offset = 0u64;
+    features
+        .iter()
+        .zip(sizes)
+        .map(|(&feature, &size)| {
+            let item = DataFeature {
+                offset,
+                size,
+                feature,
+            };
+            offset += u64::from(size);
+            item
+        })
+        .collect()
+}
+
 impl Default for DataHasher {
     /// Create a new `DataHasher` (delegates to `new()`).
     fn default() -> Self {
@@ -147,10 +568,235 @@ impl Default for DataHasher {
     }
 }
 
+impl std::io::Write for DataHasher {
+    /// Push `buf` into the hasher. Never fails and always consumes all bytes.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Streaming ISCC-SUM generator.
+///
+/// Feeds every `update` to both a `DataHasher` and an `InstanceHasher` and
+/// composes their codes on `finalize`, producing a result identical to
+/// `gen_sum_code_v0` for a file with the same contents.
+pub struct SumHasher {
+    data: DataHasher,
+    instance: InstanceHasher,
+}
+
+impl SumHasher {
+    /// Create a new `SumHasher`.
+    pub fn new() -> Self {
+        Self {
+            data: DataHasher::new(),
+            instance: InstanceHasher::new(),
+        }
+    }
+
+    /// Push data into the hasher.
+    ///
+    /// With the `parallel` feature, both hashers run concurrently on the
+    /// rayon thread pool.
+    pub fn update(&mut self, data: &[u8]) {
+        #[cfg(feature = "parallel")]
+        rayon::join(|| self.data.update(data), || self.instance.update(data));
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.data.update(data);
+            self.instance.update(data);
+        }
+    }
+
+    /// Consume the hasher and produce an ISCC-SUM result.
+    ///
+    /// Composes the Data-Code and Instance-Code at `bits` into an ISCC-CODE
+    /// (256-bit body when `wide` is set). When `add_units` is `true`, the
+    /// result also carries both unit ISCC strings.
+    pub fn finalize(self, bits: u32, wide: bool, add_units: bool) -> IsccResult<SumCodeResult> {
+        let data_result = self.data.finalize(bits)?;
+        let instance_result = self.instance.finalize(bits)?;
+
+        // Borrow strings for gen_iscc_code_v0 before potentially moving them into units.
+        let iscc_result =
+            crate::gen_iscc_code_v0(&[&data_result.iscc, &instance_result.iscc], wide)?;
+
+        let units = if add_units {
+            Some(vec![data_result.iscc, instance_result.iscc])
+        } else {
+            None
+        };
+
+        Ok(SumCodeResult {
+            iscc: iscc_result.iscc,
+            code: iscc_result.code,
+            datahash: instance_result.datahash,
+            filesize: instance_result.filesize,
+            units,
+        })
+    }
+}
+
+impl Default for SumHasher {
+    /// Create a new `SumHasher` (delegates to `new()`).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::io::Write for SumHasher {
+    /// Push `buf` into the hasher. Never fails and always consumes all bytes.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Streaming Text-Code generator.
+///
+/// Incrementally collapses text and hashes its character n-grams to produce
+/// an ISCC Text-Code identical to `gen_text_code_v0` for the concatenation
+/// of all text passed to `update`. Input is held back only until a point
+/// where normalization cannot join characters across the split, so memory
+/// stays bounded for ordinary text.
+#[cfg(feature = "text-processing")]
+pub struct TextHasher {
+    /// Raw input not yet passed through `collapse_filter`.
+    pending: String,
+    /// Filtered text not yet passed through NFKC.
+    filtered: String,
+    /// Last collapsed characters, up to `TEXT_NGRAM_SIZE - 1` between calls.
+    window: String,
+    window_len: usize,
+    characters: usize,
+    minhash: minhash::MinHashAccumulator,
+}
+
+#[cfg(feature = "text-processing")]
+impl TextHasher {
+    /// Create a new `TextHasher`.
+    pub fn new() -> Self {
+        Self {
+            pending: String::new(),
+            filtered: String::new(),
+            window: String::new(),
+            window_len: 0,
+            characters: 0,
+            minhash: minhash::MinHashAccumulator::new(),
+        }
+    }
+
+    /// Push text into the hasher.
+    ///
+    /// Collapses everything up to the last safe split point in the buffered
+    /// input and hashes the resulting n-grams; the remainder is kept for the
+    /// next call.
+    pub fn update(&mut self, text: &str) {
+        let start = self.pending.len();
+        self.pending.push_str(text);
+        let split = text
+            .char_indices()
+            .rev()
+            .map(|(i, c)| (start + i, c))
+            .find(|&(i, c)| i > 0 && crate::utils::is_collapse_boundary(c))
+            .map(|(i, _)| i);
+        if let Some(split) = split {
+            let filtered = crate::utils::collapse_filter(&self.pending[..split]);
+            self.pending.drain(..split);
+            self.push_filtered(&filtered, false);
+        }
+    }
+
+    /// Consume the hasher and produce a Text-Code result.
+    ///
+    /// Equivalent to calling `gen_text_code_v0` with the concatenation of
+    /// all text passed to `update`.
+    pub fn finalize(mut self, bits: u32) -> IsccResult<TextCodeResult> {
+        let filtered = crate::utils::collapse_filter(&self.pending);
+        self.push_filtered(&filtered, true);
+        if self.characters < crate::TEXT_NGRAM_SIZE {
+            // Short text: a single n-gram over the whole (possibly empty) text
+            self.minhash
+                .push(xxhash_rust::xxh32::xxh32(self.window.as_bytes(), 0));
+        }
+
+        let digest = self.minhash.digest_256();
+        let unit = codec::IsccUnit::new(
+            codec::MainType::Content,
+            codec::SubType::TEXT,
+            codec::Version::V0,
+            bits,
+            &digest,
+        )?;
+
+        Ok(TextCodeResult {
+            iscc: unit.to_string(),
+            unit,
+            characters: self.characters,
+            features: None,
+        })
+    }
+
+    /// Append filtered text and NFKC-normalize up to the last safe split
+    /// point, or everything when `flush` is set.
+    fn push_filtered(&mut self, filtered: &str, flush: bool) {
+        let start = self.filtered.len();
+        self.filtered.push_str(filtered);
+        let split = if flush {
+            Some(self.filtered.len())
+        } else {
+            filtered
+                .char_indices()
+                .rev()
+                .map(|(i, c)| (start + i, c))
+                .find(|&(i, c)| i > 0 && crate::utils::is_nfkc_boundary(c))
+                .map(|(i, _)| i)
+        };
+        if let Some(split) = split {
+            let tail = self.filtered.split_off(split);
+            let head = std::mem::replace(&mut self.filtered, tail);
+            for c in head.nfkc() {
+                self.push_char(c);
+            }
+        }
+    }
+
+    /// Slide the n-gram window by one collapsed character.
+    fn push_char(&mut self, c: char) {
+        self.window.push(c);
+        self.window_len += 1;
+        self.characters += 1;
+        if self.window_len == crate::TEXT_NGRAM_SIZE {
+            self.minhash
+                .push(xxhash_rust::xxh32::xxh32(self.window.as_bytes(), 0));
+            let first = self.window.chars().next().map_or(0, char::len_utf8);
+            self.window.drain(..first);
+            self.window_len -= 1;
+        }
+    }
+}
+
+#[cfg(feature = "text-processing")]
+impl Default for TextHasher {
+    /// Create a new `TextHasher` (delegates to `new()`).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{gen_data_code_v0, gen_instance_code_v0};
+    use crate::{gen_data_code_v0, gen_data_code_v0_granular, gen_instance_code_v0};
 
     // ---- InstanceHasher tests ----
 
@@ -288,6 +934,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_instance_hasher_tree_matches_blake3() {
+        let data: Vec<u8> = (0..70_000u32)
+            .map(|i| (i.wrapping_mul(31) >> 3) as u8)
+            .collect();
+        for len in [
+            0, 1, 1023, 1024, 1025, 2048, 2049, 3072, 5000, 65_536, 70_000,
+        ] {
+            let expected = blake3::hash(&data[..len]);
+            for chunk_size in [1, 7, 1000, 1024, 1025, 4096, 65_539] {
+                if chunk_size == 1 && len > 5000 {
+                    continue;
+                }
+                let mut tree = Blake3Tree::new();
+                for chunk in data[..len].chunks(chunk_size) {
+                    tree.update(chunk);
+                }
+                assert_eq!(
+                    tree.finalize(),
+                    expected,
+                    "len={len} chunk_size={chunk_size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_instance_hasher_save_restore() {
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+        let oneshot = gen_instance_code_v0(&data, 64).unwrap();
+        for split in [0, 1, 1024, 1025, 3000, 8192, 20_000] {
+            let mut ih = InstanceHasher::new();
+            ih.update(&data[..split]);
+            let state = ih.save_state();
+            let mut resumed = InstanceHasher::restore_state(&state).unwrap();
+            assert_eq!(resumed.save_state(), state);
+            resumed.update(&data[split..]);
+            let result = resumed.finalize(64).unwrap();
+            assert_eq!(result.iscc, oneshot.iscc, "split={split}");
+            assert_eq!(result.datahash, oneshot.datahash);
+            assert_eq!(result.filesize, oneshot.filesize);
+        }
+    }
+
+    #[test]
+    fn test_instance_hasher_restore_rejects_bad_state() {
+        let mut ih = InstanceHasher::new();
+        ih.update(&[7u8; 3000]);
+        let state = ih.save_state();
+
+        assert!(matches!(
+            InstanceHasher::restore_state(&state[..10]),
+            Err(IsccError::Truncated { .. })
+        ));
+        assert!(matches!(
+            InstanceHasher::restore_state(&DataHasher::new().save_state()),
+            Err(IsccError::InvalidInput(_))
+        ));
+        let mut corrupt = state.clone();
+        *corrupt.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            InstanceHasher::restore_state(&corrupt),
+            Err(IsccError::InvalidInput(_))
+        ));
+        let mut future = state.clone();
+        future[8] = 2;
+        assert!(matches!(
+            InstanceHasher::restore_state(&future),
+            Err(IsccError::Unsupported(_))
+        ));
+        // Valid checksum but a filesize that disagrees with the tree.
+        let mut body = state[STATE_HEADER_SIZE..].to_vec();
+        body[0] ^= 1;
+        assert!(matches!(
+            InstanceHasher::restore_state(&encode_state(INSTANCE_STATE_MAGIC, body)),
+            Err(IsccError::InvalidInput(_))
+        ));
+        // Crafted sizes that would overflow offsets on 32-bit targets.
+        for (depth, pending_len) in [(u32::MAX, 0), (0, u32::MAX), (u32::MAX, u32::MAX)] {
+            let mut body = state[STATE_HEADER_SIZE..].to_vec();
+            body[8..12].copy_from_slice(&depth.to_le_bytes());
+            body[12..16].copy_from_slice(&pending_len.to_le_bytes());
+            assert!(matches!(
+                InstanceHasher::restore_state(&encode_state(INSTANCE_STATE_MAGIC, body)),
+                Err(IsccError::InvalidInput(_))
+            ));
+        }
+    }
+
     // ---- DataHasher tests ----
 
     #[test]
@@ -351,6 +1086,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_data_hasher_large_updates_chunk_in_place() {
+        let mut state = 0x1234_5678_u32;
+        let mut data: Vec<u8> = (0..200_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+        data[50_000..70_000].fill(0);
+        let oneshot = gen_data_code_v0_granular(&data, 64).unwrap();
+
+        let patterns: [&[usize]; 4] = [&[8192], &[100, 8192, 3, 20_000], &[9000, 1], &[200_000]];
+        for pattern in patterns {
+            for window in [9000, 20_000, DATA_WINDOW_SIZE] {
+                let mut dh = DataHasher::new();
+                let mut pos = 0;
+                for &size in pattern.iter().cycle() {
+                    if pos == data.len() {
+                        break;
+                    }
+                    let end = (pos + size).min(data.len());
+                    dh.update_windowed(&data[pos..end], window);
+                    pos = end;
+                }
+                let result = dh.finalize_granular(64).unwrap();
+                assert_eq!(
+                    result.iscc, oneshot.iscc,
+                    "pattern={pattern:?} window={window}"
+                );
+                assert_eq!(result.features, oneshot.features);
+            }
+        }
+    }
+
     #[test]
     fn test_data_hasher_default() {
         let dh = DataHasher::default();
@@ -371,6 +1143,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_data_hasher_granular_matches_oneshot() {
+        let data: Vec<u8> = (0..20_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect();
+        let oneshot = crate::gen_data_code_v0_granular(&data, 64).unwrap();
+        for split in [1, 700, 4096] {
+            let mut dh = DataHasher::new();
+            for part in data.chunks(split) {
+                dh.update(part);
+            }
+            let streaming = dh.finalize_granular(64).unwrap();
+            assert_eq!(streaming, oneshot, "split={split}");
+        }
+
+        let empty = DataHasher::new().finalize_granular(64).unwrap();
+        let features = empty.features.unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!((features[0].offset, features[0].size), (0, 0));
+        assert!(DataHasher::new().finalize(64).unwrap().features.is_none());
+    }
+
+    #[test]
+    fn test_data_hasher_save_restore() {
+        let data: Vec<u8> = (0..30_000u32)
+            .map(|i| (i.wrapping_mul(7919) >> 5) as u8)
+            .collect();
+        let oneshot = gen_data_code_v0_granular(&data, 64).unwrap();
+        for split in [0, 1, 500, 4096, 12_345, 30_000] {
+            let mut dh = DataHasher::new();
+            dh.update(&data[..split]);
+            let state = dh.save_state();
+            let mut resumed = DataHasher::restore_state(&state).unwrap();
+            assert_eq!(resumed.save_state(), state);
+            resumed.update(&data[split..]);
+            let result = resumed.finalize_granular(64).unwrap();
+            assert_eq!(result.iscc, oneshot.iscc, "split={split}");
+            assert_eq!(result.features, oneshot.features);
+        }
+    }
+
+    #[test]
+    fn test_data_hasher_restore_rejects_bad_state() {
+        let mut dh = DataHasher::new();
+        dh.update(&[3u8; 5000]);
+        let state = dh.save_state();
+        assert!(matches!(
+            DataHasher::restore_state(&state[..state.len() - 1]),
+            Err(IsccError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            DataHasher::restore_state(&InstanceHasher::new().save_state()),
+            Err(IsccError::InvalidInput(_))
+        ));
+        let mut body = state[STATE_HEADER_SIZE..].to_vec();
+        body.push(0);
+        assert!(matches!(
+            DataHasher::restore_state(&encode_state(DATA_STATE_MAGIC, body)),
+            Err(IsccError::InvalidInput(_))
+        ));
+    }
+
     #[test]
     fn test_data_hasher_conformance() {
         let json_str = include_str!("../tests/data.json");
@@ -434,4 +1268,118 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_sum_hasher_matches_components() {
+        let data: Vec<u8> = (0..300_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect();
+        let mut sh = SumHasher::default();
+        for chunk in data.chunks(7919) {
+            sh.update(chunk);
+        }
+        let result = sh.finalize(128, true, true).unwrap();
+
+        let data_code = gen_data_code_v0(&data, 128).unwrap();
+        let instance_code = gen_instance_code_v0(&data, 128).unwrap();
+        let expected =
+            crate::gen_iscc_code_v0(&[&data_code.iscc, &instance_code.iscc], true).unwrap();
+        assert_eq!(result.iscc, expected.iscc);
+        assert_eq!(result.datahash, instance_code.datahash);
+        assert_eq!(result.filesize, data.len() as u64);
+        assert_eq!(result.units, Some(vec![data_code.iscc, instance_code.iscc]));
+    }
+
+    #[test]
+    fn test_sum_hasher_empty() {
+        let result = SumHasher::new().finalize(64, false, false).unwrap();
+        assert_eq!(result.filesize, 0);
+        assert!(result.units.is_none());
+        let data_code = gen_data_code_v0(b"", 64).unwrap();
+        let instance_code = gen_instance_code_v0(b"", 64).unwrap();
+        let expected =
+            crate::gen_iscc_code_v0(&[&data_code.iscc, &instance_code.iscc], false).unwrap();
+        assert_eq!(result.iscc, expected.iscc);
+    }
+
+    /// Feed `text` to a `TextHasher` in pieces of the given char lengths
+    /// (cycled), then compare against the one-shot result.
+    #[cfg(feature = "text-processing")]
+    fn assert_text_hasher_matches(text: &str, bits: u32, piece_lens: &[usize]) {
+        let oneshot = crate::gen_text_code_v0(text, bits).unwrap();
+        let chars: Vec<char> = text.chars().collect();
+        let mut hasher = TextHasher::new();
+        let mut pos = 0;
+        for &n in piece_lens.iter().cycle() {
+            if pos >= chars.len() {
+                break;
+            }
+            let end = (pos + n).min(chars.len());
+            hasher.update(&chars[pos..end].iter().collect::<String>());
+            pos = end;
+        }
+        let streaming = hasher.finalize(bits).unwrap();
+        assert_eq!(streaming.iscc, oneshot.iscc, "ISCC mismatch for {text:?}");
+        assert_eq!(streaming.characters, oneshot.characters);
+    }
+
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_text_hasher_conformance() {
+        let json_str = include_str!("../tests/data.json");
+        let data: serde_json::Value = serde_json::from_str(json_str).unwrap();
+        let cases = data["gen_text_code_v0"].as_object().unwrap();
+
+        for (name, tc) in cases {
+            let inputs = tc["inputs"].as_array().unwrap();
+            let text = inputs[0].as_str().unwrap();
+            let bits = inputs[1].as_u64().unwrap() as u32;
+
+            let mut th = TextHasher::new();
+            th.update(text);
+            let result = th.finalize(bits).unwrap();
+            assert_eq!(
+                result.iscc,
+                tc["outputs"]["iscc"].as_str().unwrap(),
+                "ISCC mismatch in test case {name}"
+            );
+
+            for pieces in [&[1][..], &[2, 5, 13], &[7, 1, 30]] {
+                assert_text_hasher_matches(text, bits, pieces);
+            }
+        }
+    }
+
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_text_hasher_split_everywhere() {
+        // Final sigma, combining marks, Hangul jamo and compatibility forms
+        // whose collapse depends on neighbouring characters.
+        let samples = [
+            "ΑΣ ΒΣ ΑΣ'Σ Σ",
+            "e\u{301} a\u{308}\u{301} Å ﬁne ①",
+            "\u{1100} \u{1161}\u{11A8}가 각",
+            "漢字かな交じり文は空白なしで長く続くことが多い一二三四五六七八九十",
+        ];
+        for text in samples {
+            for n in 1..=text.chars().count() {
+                assert_text_hasher_matches(text, 64, &[n]);
+            }
+        }
+    }
+
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_text_hasher_empty_and_default() {
+        let empty = TextHasher::default().finalize(64).unwrap();
+        assert_eq!(empty.iscc, "ISCC:EAASL4F2WZY7KBXB");
+        assert_eq!(empty.characters, 0);
+
+        let mut th = TextHasher::new();
+        th.update("");
+        th.update("Hello");
+        th.update("");
+        th.update(" World");
+        assert_eq!(th.finalize(64).unwrap().iscc, "ISCC:EAASKDNZNYGUUF5A");
+    }
 }
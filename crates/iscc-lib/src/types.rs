@@ -3,6 +3,10 @@
 //! Each `gen_*_v0` function returns a dedicated result struct carrying the ISCC
 //! code string plus any additional fields (metahash, name, characters, etc.)
 //! that match the `iscc-core` Python reference implementation's dict returns.
+//! Every result also carries the typed [`IsccUnit`] or [`IsccCode`] value
+//! behind its `iscc` string, so callers need not re-parse it.
+
+use crate::codec::{IsccCode, IsccId, IsccUnit};
 
 /// Result of [`gen_meta_code_v0`](crate::gen_meta_code_v0).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,6 +14,8 @@
 pub struct MetaCodeResult {
     /// ISCC code string (e.g., `"ISCC:AAAZXZ6OU74YAZIM"`).
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
     /// Normalized name after cleaning, newline removal, and trimming.
     pub name: String,
     /// Normalized description (present only when description was non-empty).
@@ -26,8 +32,33 @@ pub struct MetaCodeResult {
 pub struct TextCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
     /// Character count after `text_collapse`.
     pub characters: usize,
+    /// Per-chunk features in text order (present only for
+    /// [`gen_text_code_v0_granular`](crate::gen_text_code_v0_granular)).
+    pub features: Option<Vec<TextFeature>>,
+}
+
+/// One content-defined chunk of the collapsed text and its simprint.
+///
+/// Offsets and sizes count Unicode characters. `offset`/`size` locate the
+/// chunk in the `text_collapse`d text; `source_offset`/`source_size` locate
+/// the span of the original input it was derived from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TextFeature {
+    /// Character offset of the chunk in the collapsed text.
+    pub offset: usize,
+    /// Chunk length in characters of the collapsed text.
+    pub size: usize,
+    /// 32-bit SimHash over the chunk's character n-gram hashes.
+    pub simprint: u32,
+    /// Character offset of the chunk's source span in the original input.
+    pub source_offset: usize,
+    /// Length in characters of the chunk's source span in the original input.
+    pub source_size: usize,
 }
 
 /// Result of [`gen_image_code_v0`](crate::gen_image_code_v0).
@@ -36,6 +67,8 @@ pub struct TextCodeResult {
 pub struct ImageCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
 }
 
 /// Result of [`gen_audio_code_v0`](crate::gen_audio_code_v0).
@@ -44,6 +77,8 @@ pub struct ImageCodeResult {
 pub struct AudioCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
 }
 
 /// Result of [`gen_video_code_v0`](crate::gen_video_code_v0).
@@ -52,6 +87,8 @@ pub struct AudioCodeResult {
 pub struct VideoCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
 }
 
 /// Result of [`gen_mixed_code_v0`](crate::gen_mixed_code_v0).
@@ -60,16 +97,45 @@ pub struct VideoCodeResult {
 pub struct MixedCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
     /// Input Content-Code strings (passed through unchanged).
     pub parts: Vec<String>,
 }
 
+/// Result of [`gen_semantic_code_v0`](crate::gen_semantic_code_v0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SemanticCodeResult {
+    /// ISCC code string.
+    pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
+}
+
 /// Result of [`gen_data_code_v0`](crate::gen_data_code_v0).
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct DataCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
+    /// Per-chunk features in input order (present only for the granular
+    /// variants, e.g. [`gen_data_code_v0_granular`](crate::gen_data_code_v0_granular)).
+    pub features: Option<Vec<DataFeature>>,
+}
+
+/// One content-defined chunk of a Data-Code input and its feature hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataFeature {
+    /// Byte offset of the chunk in the input.
+    pub offset: u64,
+    /// Chunk length in bytes.
+    pub size: u32,
+    /// xxh32 hash of the chunk, as fed into MinHash.
+    pub feature: u32,
 }
 
 /// Result of [`gen_instance_code_v0`](crate::gen_instance_code_v0).
@@ -78,6 +144,8 @@ pub struct DataCodeResult {
 pub struct InstanceCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
     /// Hex-encoded BLAKE3 multihash (`"1e20..."`) of the input data.
     pub datahash: String,
     /// Byte length of the input data.
@@ -90,6 +158,28 @@ pub struct InstanceCodeResult {
 pub struct IsccCodeResult {
     /// ISCC code string.
     pub iscc: String,
+    /// Typed ISCC-CODE (same value as `iscc`).
+    pub code: IsccCode,
+}
+
+/// Result of [`gen_flake_code_v0`](crate::gen_flake_code_v0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FlakeCodeResult {
+    /// ISCC code string.
+    pub iscc: String,
+    /// Typed ISCC-UNIT (same value as `iscc`).
+    pub unit: IsccUnit,
+}
+
+/// Result of [`gen_iscc_id_v0`](crate::gen_iscc_id_v0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IsccIdResult {
+    /// ISCC-ID string.
+    pub iscc: String,
+    /// Typed ISCC-ID (same value as `iscc`).
+    pub id: IsccId,
 }
 
 /// Result of [`gen_sum_code_v0`](crate::gen_sum_code_v0).
@@ -98,6 +188,8 @@ pub struct IsccCodeResult {
 pub struct SumCodeResult {
     /// Composite ISCC-CODE string (e.g., `"ISCC:KAC..."`).
     pub iscc: String,
+    /// Typed ISCC-CODE (same value as `iscc`).
+    pub code: IsccCode,
     /// Hex-encoded BLAKE3 multihash (`"1e20..."`) of the file.
     pub datahash: String,
     /// Byte length of the file.
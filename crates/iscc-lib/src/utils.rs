@@ -3,6 +3,9 @@
 //! Provides text cleaning, trimming, collapsing, and BLAKE3 multihash functions
 //! ported from `iscc-core` `code_meta.py` and `utils.py`.
 
+#[cfg(feature = "text-processing")]
+use std::ops::Range;
+
 #[cfg(feature = "text-processing")]
 use unicode_general_category::{GeneralCategory, get_general_category};
 #[cfg(feature = "text-processing")]
@@ -138,17 +141,120 @@ pub fn text_trim(text: &str, nbytes: usize) -> String {
 /// recombines with NFKC normalization.
 #[cfg(feature = "text-processing")]
 pub fn text_collapse(text: &str) -> String {
+    // 3. NFKC normalize the filtered result
+    collapse_filter(text).nfkc().collect()
+}
+
+/// First stage of [`text_collapse`]: everything except the final NFKC pass.
+#[cfg(feature = "text-processing")]
+pub(crate) fn collapse_filter(text: &str) -> String {
     // 1. NFD normalize and lowercase
     let nfd_lower: String = text.nfd().collect::<String>().to_lowercase();
 
     // 2. Filter: keep chars that are NOT whitespace AND NOT in C/M/P categories
-    let filtered: String = nfd_lower
+    nfd_lower
         .chars()
         .filter(|&c| !c.is_whitespace() && !is_cmp_category(c))
-        .collect();
+        .collect()
+}
 
-    // 3. NFKC normalize the filtered result
-    filtered.nfkc().collect()
+/// Whether [`collapse_filter`] may be applied separately to the text before
+/// and from `c` on with the same result as on the whole text.
+///
+/// Holds for whitespace and for uncased starters in categories Lo and Nd:
+/// NFD never reorders across a starter, and such characters end the context
+/// of the final-sigma rule in lowercasing.
+#[cfg(feature = "text-processing")]
+pub(crate) fn is_collapse_boundary(c: char) -> bool {
+    use unicode_normalization::char::canonical_combining_class;
+
+    c.is_whitespace()
+        || (canonical_combining_class(c) == 0
+            && matches!(
+                get_general_category(c),
+                GeneralCategory::OtherLetter | GeneralCategory::DecimalNumber
+            )
+            && !c.is_lowercase()
+            && !c.is_uppercase())
+}
+
+/// Whether NFKC may be applied separately to the text before and from `c`
+/// on with the same result as on the whole text.
+///
+/// Holds for starters that are NFKC-stable and never compose with a
+/// preceding character.
+#[cfg(feature = "text-processing")]
+pub(crate) fn is_nfkc_boundary(c: char) -> bool {
+    use unicode_normalization::char::canonical_combining_class;
+    use unicode_normalization::{IsNormalized, is_nfkc_quick};
+
+    canonical_combining_class(c) == 0 && is_nfkc_quick(std::iter::once(c)) == IsNormalized::Yes
+}
+
+/// Apply [`text_collapse`] and map each collapsed character back to the
+/// range of input characters it was derived from.
+///
+/// Works in a single pass: the text is filtered in pieces split before each
+/// [`is_collapse_boundary`] character, and the filtered text is normalized in
+/// runs split before each [`is_nfkc_boundary`] character, so the output is
+/// exactly the collapse of the whole text. Within a piece, each filtered
+/// character maps to its own starter character and the marks following it,
+/// unless filtering those alone gives a different result (as with the
+/// context-dependent final sigma); then the piece's characters map to the
+/// whole piece. A run that NFKC composes maps to the union of its sources.
+#[cfg(feature = "text-processing")]
+pub(crate) fn text_collapse_mapped(text: &str) -> (String, Vec<Range<usize>>) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut filtered = Vec::new();
+    for piece in split_before(&chars, is_collapse_boundary) {
+        filter_piece(&chars, piece, &mut filtered);
+    }
+
+    let mut collapsed = String::with_capacity(text.len());
+    let mut map = Vec::with_capacity(filtered.len());
+    let filtered_chars: Vec<char> = filtered.iter().map(|(c, _)| *c).collect();
+    for run in split_before(&filtered_chars, is_nfkc_boundary) {
+        let source = filtered[run.start].1.start..filtered[run.end - 1].1.end;
+        for c in filtered_chars[run].iter().copied().nfkc() {
+            collapsed.push(c);
+            map.push(source.clone());
+        }
+    }
+    (collapsed, map)
+}
+
+/// Split `chars` into consecutive non-empty ranges, starting a new range
+/// before every character after the first that matches `split`.
+#[cfg(feature = "text-processing")]
+fn split_before(chars: &[char], split: fn(char) -> bool) -> impl Iterator<Item = Range<usize>> {
+    let mut start = 0;
+    (1..=chars.len()).filter_map(move |end| {
+        if end < chars.len() && !split(chars[end]) {
+            return None;
+        }
+        let range = start..end;
+        start = end;
+        Some(range)
+    })
+}
+
+/// Append the [`collapse_filter`] output of `chars[piece]` to `out`, each
+/// character with its source range.
+#[cfg(feature = "text-processing")]
+fn filter_piece(chars: &[char], piece: Range<usize>, out: &mut Vec<(char, Range<usize>)>) {
+    use unicode_normalization::char::canonical_combining_class;
+
+    let whole = collapse_filter(&chars[piece.clone()].iter().collect::<String>());
+    let start = out.len();
+    for sub in split_before(&chars[piece.clone()], |c| canonical_combining_class(c) == 0) {
+        let sub = piece.start + sub.start..piece.start + sub.end;
+        let part = collapse_filter(&chars[sub.clone()].iter().collect::<String>());
+        out.extend(part.chars().map(|c| (c, sub.clone())));
+    }
+    if !out[start..].iter().map(|(c, _)| *c).eq(whole.chars()) {
+        out.truncate(start);
+        out.extend(whole.chars().map(|c| (c, piece.clone())));
+    }
 }
 
 /// Compute a BLAKE3 hash with multihash prefix.
@@ -168,6 +274,44 @@ pub(crate) fn multi_hash_blake3(data: &[u8]) -> String {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_text_collapse_mapped_sources() {
+        let (collapsed, map) = text_collapse_mapped("A b\u{0301}, C!");
+        assert_eq!(collapsed, text_collapse("A b\u{0301}, C!"));
+        assert_eq!(map, vec![0..1, 2..4, 6..7]);
+
+        // Final sigma depends on context: each word falls back to its own span
+        let (collapsed, map) = text_collapse_mapped("ΑΣ ΒΣ");
+        assert_eq!(collapsed, "αςβς");
+        assert_eq!(map, vec![0..2, 0..2, 2..5, 2..5]);
+
+        // Conjoining jamo compose across the removed space
+        let (collapsed, map) = text_collapse_mapped("x \u{1100} \u{1161}!");
+        assert_eq!(collapsed, "x\u{AC00}");
+        assert_eq!(map, vec![0..1, 2..5]);
+    }
+
+    #[cfg(feature = "text-processing")]
+    #[test]
+    fn test_text_collapse_mapped_matches_text_collapse() {
+        for text in [
+            "",
+            "  \n\t ",
+            "Hello, World! 123",
+            "Ǆemal ﬁnds ① café\u{0301} and e\u{0323}\u{0302} ΣΑΣ",
+            "日本語のテキスト、漢字とかな。한국어 텍스트",
+            "\u{0301}leading mark and trailing ΟΔΟΣ",
+        ] {
+            let (collapsed, map) = text_collapse_mapped(text);
+            assert_eq!(collapsed, text_collapse(text), "{text:?}");
+            assert_eq!(map.len(), collapsed.chars().count());
+            let len = text.chars().count();
+            assert!(map.iter().all(|r| r.start < r.end && r.end <= len));
+            assert!(map.windows(2).all(|w| w[0].start <= w[1].start));
+        }
+    }
+
     // ---- text_clean tests ----
 
     #[cfg(feature = "text-processing")]
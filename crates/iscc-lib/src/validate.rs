@@ -0,0 +1,425 @@
+//! Cleaning, normalization, and validation of ISCC strings.
+//!
+//! Accepts every form in which ISCCs are typically pasted — canonical
+//! `ISCC:<base32>`, lowercase, dashed, `iscc:` URIs, and multibase
+//! multiformat strings — and reduces them to the canonical form. Validation
+//! failures are reported as structured [`IsccValidationError`] values that
+//! identify the offending unit and the exact problem.
+
+use crate::codec::{
+    self, Chain, IsccId, IsccUnit, MULTICODEC_PREFIX, MainType, MultiBase, SubType,
+};
+use crate::{IsccError, IsccResult};
+
+/// Structured description of why an ISCC string is invalid.
+///
+/// Unit indices count ISCC headers from the start of the decoded bytes,
+/// beginning at `0`.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum IsccValidationError {
+    /// Input is empty after cleaning.
+    #[error("empty ISCC")]
+    Empty,
+    /// Character outside the base32 alphabet in a canonical ISCC string.
+    #[error("invalid base32 character {character:?} at position {position}")]
+    InvalidCharacter { position: usize, character: char },
+    /// Canonical string could not be base32-decoded (e.g. invalid length).
+    #[error("base32 decode error: {0}")]
+    InvalidBase32(String),
+    /// Multibase payload could not be decoded.
+    #[error("{base:?} decode error: {message}")]
+    InvalidMultibase { base: MultiBase, message: String },
+    /// Multibase payload lacks the `0xcc01` ISCC multicodec prefix.
+    #[error("missing ISCC multicodec prefix 0xcc01")]
+    MissingMulticodec,
+    /// Header could not be parsed.
+    #[error("unit {unit}: invalid header: {message}")]
+    InvalidHeader { unit: usize, message: String },
+    /// SubType is not allowed for the MainType.
+    #[error("unit {unit}: SubType {subtype:?} is not valid for MainType {maintype:?}")]
+    InvalidSubtype {
+        unit: usize,
+        maintype: MainType,
+        subtype: SubType,
+    },
+    /// Header declares a bit length the MainType does not support.
+    #[error("unit {unit}: invalid bit length {bits} for MainType {maintype:?}")]
+    InvalidLength {
+        unit: usize,
+        maintype: MainType,
+        bits: u32,
+    },
+    /// Body is shorter than the header declares.
+    #[error("unit {unit}: truncated body, expected {expected} bytes, got {actual}")]
+    TruncatedBody {
+        unit: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Bytes remain after an ISCC-CODE body.
+    #[error("unit {unit}: {bytes} trailing bytes after ISCC-CODE")]
+    TrailingData { unit: usize, bytes: usize },
+    /// ISCC-CODE header is inconsistent (e.g. wide SubType with optional units).
+    #[error("unit {unit}: invalid ISCC-CODE: {message}")]
+    InvalidComposite { unit: usize, message: String },
+    /// ISCC-ID body is malformed (e.g. non-canonical counter).
+    #[error("unit {unit}: invalid ISCC-ID: {message}")]
+    InvalidId { unit: usize, message: String },
+}
+
+/// Remove surrounding noise from an ISCC string without validating it.
+///
+/// Trims whitespace, strips a case-insensitive `ISCC:` prefix or `iscc:` URI
+/// scheme (with optional `//`), and removes dashes. Character case is
+/// preserved because multibase encodings are case-sensitive.
+pub fn iscc_clean(iscc: &str) -> String {
+    strip_scheme(iscc)
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect()
+}
+
+/// Trim whitespace and a case-insensitive `ISCC:` prefix or `iscc:` URI scheme.
+fn strip_scheme(iscc: &str) -> &str {
+    let trimmed = iscc.trim();
+    match trimmed.get(..5) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("iscc:") => {
+            let rest = &trimmed[5..];
+            rest.strip_prefix("//").unwrap_or(rest)
+        }
+        _ => trimmed,
+    }
+}
+
+/// Validate an ISCC string in any accepted form and return its canonical form.
+///
+/// Accepts canonical, lowercase, dashed, prefixed, URI, and multibase
+/// multiformat inputs (see [`iscc_clean`] and [`codec::from_multiformat`]).
+/// Dashes are only removed from base32 input, as `-` is a base64url digit.
+/// Checks header consistency, body length, and allowed MainType/SubType
+/// combinations for every contained unit.
+///
+/// # Errors
+///
+/// Returns an [`IsccValidationError`] describing the first problem found.
+pub fn iscc_validate(iscc: &str) -> Result<String, IsccValidationError> {
+    let clean: String = strip_scheme(iscc)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let raw = decode_any(&clean)?;
+    validate_bytes(&raw)?;
+    Ok(format!("ISCC:{}", codec::encode_base32(&raw)))
+}
+
+/// Normalize an ISCC string in any accepted form to canonical `ISCC:<base32>`.
+///
+/// Same as [`iscc_validate`] with the diagnostic wrapped in [`IsccError`].
+///
+/// # Errors
+///
+/// Returns `IsccError::Validation` if the input is not a valid ISCC.
+pub fn iscc_normalize(iscc: &str) -> IsccResult<String> {
+    Ok(iscc_validate(iscc)?)
+}
+
+/// Decode a cleaned ISCC string (multibase or base32) into raw ISCC bytes.
+///
+/// Multibase is tried first when the string starts with a multibase prefix;
+/// if that does not yield an ISCC multiformat, the string is treated as
+/// (possibly lowercase and dashed) base32 as long as it only uses base32
+/// characters.
+fn decode_any(clean: &str) -> Result<Vec<u8>, IsccValidationError> {
+    let mut chars = clean.chars();
+    let first = chars.next().ok_or(IsccValidationError::Empty)?;
+    let multibase = MultiBase::from_prefix(first).map(|base| {
+        base.decode(chars.as_str())
+            .map_err(|e| IsccValidationError::InvalidMultibase {
+                base,
+                message: e.to_string(),
+            })
+            .and_then(|data| {
+                data.strip_prefix(&MULTICODEC_PREFIX)
+                    .map(<[u8]>::to_vec)
+                    .ok_or(IsccValidationError::MissingMulticodec)
+            })
+    });
+    let multibase_error = match multibase {
+        Some(Ok(raw)) => return Ok(raw),
+        Some(Err(err)) => Some(err),
+        None => None,
+    };
+    let base32: String = clean.chars().filter(|c| *c != '-').collect();
+    let invalid = base32
+        .char_indices()
+        .find(|(_, c)| !matches!(c.to_ascii_uppercase(), 'A'..='Z' | '2'..='7'));
+    match (invalid, multibase_error) {
+        (None, _) => codec::decode_base32(&base32)
+            .map_err(|e| IsccValidationError::InvalidBase32(e.to_string())),
+        (Some(_), Some(err)) => Err(err),
+        (Some((position, character)), None) => Err(IsccValidationError::InvalidCharacter {
+            position,
+            character,
+        }),
+    }
+}
+
+/// Whether `subtype` is allowed in a header of `maintype`.
+fn subtype_allowed(maintype: MainType, subtype: SubType) -> bool {
+    match maintype {
+        MainType::Meta | MainType::Data | MainType::Instance | MainType::Flake => {
+            subtype == SubType::None
+        }
+        MainType::Semantic | MainType::Content => subtype <= SubType::Mixed,
+        MainType::Id => Chain::try_from(subtype as u8).is_ok(),
+        MainType::Iscc => true,
+    }
+}
+
+/// Walk the headers in `raw` and check every unit for consistency.
+fn validate_bytes(raw: &[u8]) -> Result<(), IsccValidationError> {
+    if raw.is_empty() {
+        return Err(IsccValidationError::Empty);
+    }
+    let mut rest = raw;
+    let mut unit = 0;
+    while !rest.is_empty() {
+        let header_error = |e: IsccError| IsccValidationError::InvalidHeader {
+            unit,
+            message: e.to_string(),
+        };
+        let (maintype, subtype, version, length, body) =
+            codec::decode_header(rest).map_err(header_error)?;
+        if !subtype_allowed(maintype, subtype) {
+            return Err(IsccValidationError::InvalidSubtype {
+                unit,
+                maintype,
+                subtype,
+            });
+        }
+        if maintype == MainType::Iscc {
+            let main_types = codec::decode_units(length).map_err(header_error)?;
+            return validate_composite(unit, subtype, &main_types, body.len());
+        }
+        let bits = codec::decode_length(maintype, length, subtype);
+        let nbytes = (bits / 8) as usize;
+        if codec::encode_length(maintype, bits).is_err() {
+            return Err(IsccValidationError::InvalidLength {
+                unit,
+                maintype,
+                bits,
+            });
+        }
+        if body.len() < nbytes {
+            return Err(IsccValidationError::TruncatedBody {
+                unit,
+                expected: nbytes,
+                actual: body.len(),
+            });
+        }
+        if maintype == MainType::Id {
+            let id_unit = IsccUnit::new(maintype, subtype, version, bits, &body[..nbytes])
+                .map_err(header_error)?;
+            IsccId::try_from(&id_unit).map_err(|e| IsccValidationError::InvalidId {
+                unit,
+                message: e.to_string(),
+            })?;
+        }
+        rest = &rest[rest.len() - body.len() + nbytes..];
+        unit += 1;
+    }
+    Ok(())
+}
+
+/// Check that an ISCC-CODE body has exactly the length its header implies.
+fn validate_composite(
+    unit: usize,
+    subtype: SubType,
+    main_types: &[MainType],
+    body_len: usize,
+) -> Result<(), IsccValidationError> {
+    let expected = if subtype == SubType::Wide {
+        if !main_types.is_empty() {
+            return Err(IsccValidationError::InvalidComposite {
+                unit,
+                message: "wide ISCC-CODE cannot contain optional units".into(),
+            });
+        }
+        32
+    } else {
+        main_types.len() * 8 + 16
+    };
+    if body_len < expected {
+        return Err(IsccValidationError::TruncatedBody {
+            unit,
+            expected,
+            actual: body_len,
+        });
+    }
+    if body_len > expected {
+        return Err(IsccValidationError::TrailingData {
+            unit,
+            bytes: body_len - expected,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const META: &str = "ISCC:AAAZXZ6OU74YAZIM";
+    const CODE: &str = "ISCC:KACYPXW445FTYNJ3CYSXHAFJMA2HUWULUNRFE3BLHRSCXYH2M5AEGQY";
+
+    #[test]
+    fn test_iscc_clean() {
+        assert_eq!(
+            iscc_clean("  ISCC:AAAZ-XZ6O-U74Y-AZIM \n"),
+            "AAAZXZ6OU74YAZIM"
+        );
+        assert_eq!(iscc_clean("iscc:aaazxz6ou74yazim"), "aaazxz6ou74yazim");
+        assert_eq!(iscc_clean("iscc://AAAZXZ6OU74YAZIM"), "AAAZXZ6OU74YAZIM");
+        assert_eq!(iscc_clean(""), "");
+    }
+
+    #[test]
+    fn test_iscc_validate_accepts_all_forms() {
+        for iscc in [META, CODE] {
+            let body = &iscc[5..];
+            let inputs = [
+                iscc.to_string(),
+                body.to_string(),
+                body.to_lowercase(),
+                format!("iscc:{}", body.to_lowercase()),
+                format!(" {iscc}\n"),
+                body.as_bytes()
+                    .chunks(4)
+                    .map(|c| std::str::from_utf8(c).unwrap())
+                    .collect::<Vec<_>>()
+                    .join("-"),
+                codec::to_multiformat(iscc, MultiBase::Base58Btc).unwrap(),
+                codec::to_multiformat(iscc, MultiBase::Base32).unwrap(),
+                codec::to_multiformat(iscc, MultiBase::Base64Url).unwrap(),
+            ];
+            for input in &inputs {
+                assert_eq!(iscc_validate(input).unwrap(), iscc, "input {input:?}");
+                assert_eq!(iscc_normalize(input).unwrap(), iscc, "input {input:?}");
+            }
+        }
+
+        // `-` is a base64url digit, not a separator.
+        for input in ["uzAEAASu-6IqogARC", "uzAEAAedzMR_-5miO"] {
+            let expected = codec::from_multiformat(input).unwrap();
+            assert_eq!(iscc_validate(input).unwrap(), expected, "input {input:?}");
+            assert_eq!(iscc_validate(&format!("iscc:{input}")).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_iscc_validate_conformance_vectors() {
+        let data: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/data.json")).unwrap();
+        for (section, cases) in data.as_object().unwrap() {
+            if section.starts_with('_') {
+                continue;
+            }
+            for (name, tc) in cases.as_object().unwrap() {
+                if let Some(iscc) = tc["outputs"]["iscc"].as_str() {
+                    assert_eq!(iscc_validate(iscc).unwrap(), iscc, "{section}/{name}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_iscc_validate_character_and_encoding_errors() {
+        assert_eq!(iscc_validate("  "), Err(IsccValidationError::Empty));
+        assert_eq!(
+            iscc_validate("ISCC:AAAZXZ6OU74YAZI1"),
+            Err(IsccValidationError::InvalidCharacter {
+                position: 15,
+                character: '1'
+            })
+        );
+        assert!(matches!(
+            iscc_validate("AAAZXZ6OU74YAZIMA"),
+            Err(IsccValidationError::InvalidBase32(_))
+        ));
+        assert_eq!(
+            iscc_validate("z11111"),
+            Err(IsccValidationError::MissingMulticodec)
+        );
+    }
+
+    #[test]
+    fn test_iscc_validate_structural_errors() {
+        let encode = |raw: &[u8]| format!("ISCC:{}", codec::encode_base32(raw));
+        let meta = codec::decode_base32(&META[5..]).unwrap();
+        // Truncated body
+        assert_eq!(
+            iscc_validate(&encode(&meta[..6])),
+            Err(IsccValidationError::TruncatedBody {
+                unit: 0,
+                expected: 8,
+                actual: 4
+            })
+        );
+        // Second unit of a sequence is truncated
+        let mut seq = meta.clone();
+        seq.extend_from_slice(&meta[..4]);
+        assert!(matches!(
+            iscc_validate(&encode(&seq)),
+            Err(IsccValidationError::TruncatedBody { unit: 1, .. })
+        ));
+        // Meta-Code with a non-None SubType
+        let image_meta = codec::encode_component(
+            MainType::Meta,
+            SubType::Image,
+            codec::Version::V0,
+            64,
+            &[0; 8],
+        )
+        .unwrap();
+        assert_eq!(
+            iscc_validate(&image_meta),
+            Err(IsccValidationError::InvalidSubtype {
+                unit: 0,
+                maintype: MainType::Meta,
+                subtype: SubType::Image
+            })
+        );
+        // Trailing bytes after an ISCC-CODE
+        let mut code = codec::decode_base32(&CODE[5..]).unwrap();
+        code.push(0);
+        assert_eq!(
+            iscc_validate(&encode(&code)),
+            Err(IsccValidationError::TrailingData { unit: 0, bytes: 1 })
+        );
+    }
+
+    #[test]
+    fn test_iscc_validate_id_errors() {
+        let mut body = vec![0u8; 8];
+        body.push(0x00);
+        let id =
+            codec::encode_component(MainType::Id, SubType::None, codec::Version::V0, 72, &body)
+                .unwrap();
+        assert!(matches!(
+            iscc_validate(&id),
+            Err(IsccValidationError::InvalidId { unit: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_validation_error_into_iscc_error() {
+        let err = iscc_normalize("").unwrap_err();
+        assert!(matches!(
+            err,
+            IsccError::Validation(IsccValidationError::Empty)
+        ));
+        assert_eq!(err.code(), "validation");
+        assert_eq!(err.to_string(), "empty ISCC");
+    }
+}
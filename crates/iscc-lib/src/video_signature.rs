@@ -0,0 +1,442 @@
+//! MPEG-7 video signature parsing.
+//!
+//! FFmpeg's `signature` filter writes the MPEG-7 Video Signature descriptor
+//! either as a bitstream (`format=binary`) or as XML (`format=xml`). Both
+//! carry one fine signature per frame: 76 bytes that each pack five ternary
+//! values (base 3, most significant first), giving the 380-element vectors
+//! `gen_video_code_v0` expects. Coarse segment signatures are skipped.
+
+use crate::{IsccError, IsccResult};
+
+/// Number of ternary elements in a frame signature.
+pub const FRAME_SIGNATURE_SIZE: usize = 380;
+
+/// Bits before the segment list: region, pixel box, frame count, time unit
+/// and span, and the segment count.
+const HEADER_BITS: usize = 32 + 1 + 32 + 16 + 16 + 32 + 32 + 16 + 1 + 32 + 32 + 32;
+
+/// Bits per coarse segment: frame and time span plus five 243-bit bags of words.
+const SEGMENT_BITS: usize = 32 + 32 + 1 + 32 + 32 + 5 * 243;
+
+/// Bits per frame: time flag and value, confidence, five words and the
+/// packed signature.
+const FRAME_BITS: usize = 1 + 32 + 8 + 5 * 8 + FRAME_SIGNATURE_SIZE / 5 * 8;
+
+/// Parsed MPEG-7 video signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoSignature {
+    /// Media time ticks per second (`MediaTimeUnit`).
+    pub media_time_unit: u32,
+    /// Fine signatures in frame order.
+    pub frames: Vec<SignatureFrame>,
+}
+
+/// Fine signature of a single frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureFrame {
+    /// Presentation time in `media_time_unit` ticks.
+    pub media_time: u64,
+    /// Confidence of the signature (0–255).
+    pub confidence: u8,
+    /// The 380 ternary signature elements (each 0, 1 or 2).
+    pub signature: Vec<i32>,
+}
+
+impl VideoSignature {
+    /// Frame signature vectors in frame order, ready for `gen_video_code_v0`.
+    pub fn frame_signatures(&self) -> Vec<&[i32]> {
+        self.frames.iter().map(|f| f.signature.as_slice()).collect()
+    }
+}
+
+/// Parse an FFmpeg `signature` filter output file in binary or XML format.
+///
+/// The format is detected from the content: XML output starts with `<`,
+/// while binary output starts with the 32-bit region count (always 1).
+///
+/// # Errors
+///
+/// Returns `IsccError::Truncated` if a binary signature is cut short,
+/// `IsccError::Unsupported` if it uses the (unspecified) compressed frame
+/// layout, and `IsccError::InvalidInput` for malformed content.
+pub fn parse_video_signature(data: &[u8]) -> IsccResult<VideoSignature> {
+    if data.trim_ascii_start().starts_with(b"<") {
+        let xml = std::str::from_utf8(data)
+            .map_err(|_| invalid("XML signature is not valid UTF-8".into()))?;
+        parse_xml(xml)
+    } else {
+        parse_binary(data)
+    }
+}
+
+/// Parse the binary bitstream written by `binary_export`.
+fn parse_binary(data: &[u8]) -> IsccResult<VideoSignature> {
+    let truncated = |bits: usize| IsccError::Truncated {
+        expected: bits.div_ceil(8),
+        actual: data.len(),
+    };
+    let mut bits = BitReader { data, pos: 0 };
+    if data.len() * 8 < HEADER_BITS {
+        return Err(truncated(HEADER_BITS));
+    }
+    let regions = bits.read(32);
+    if regions != 1 {
+        return Err(invalid(format!("expected 1 spatial region, got {regions}")));
+    }
+    bits.skip(1 + 32 + 16 + 16 + 32);
+    let num_frames = bits.read(32) as usize;
+    let media_time_unit = bits.read(16) as u32;
+    bits.skip(1 + 32 + 32);
+    let num_segments = bits.read(32) as usize;
+
+    let total = num_segments
+        .checked_mul(SEGMENT_BITS)
+        .and_then(|s| num_frames.checked_mul(FRAME_BITS)?.checked_add(s))
+        .and_then(|body| body.checked_add(HEADER_BITS + 1))
+        .ok_or_else(|| invalid("frame or segment count out of range".into()))?;
+    if data.len() * 8 < total {
+        return Err(truncated(total));
+    }
+    bits.skip(num_segments * SEGMENT_BITS);
+    if bits.read(1) != 0 {
+        return Err(IsccError::Unsupported("compressed video signatures".into()));
+    }
+
+    let frames = (0..num_frames)
+        .map(|_| {
+            bits.skip(1);
+            let media_time = bits.read(32);
+            let confidence = bits.read(8) as u8;
+            bits.skip(5 * 8);
+            let signature = (0..FRAME_SIGNATURE_SIZE / 5)
+                .flat_map(|_| unpack_ternary(bits.read(8) as u8))
+                .collect();
+            SignatureFrame {
+                media_time,
+                confidence,
+                signature,
+            }
+        })
+        .collect();
+    Ok(VideoSignature {
+        media_time_unit,
+        frames,
+    })
+}
+
+/// Split a byte into its five base-3 digits, most significant first.
+fn unpack_ternary(byte: u8) -> [i32; 5] {
+    let b = i32::from(byte);
+    [b / 81 % 3, b / 27 % 3, b / 9 % 3, b / 3 % 3, b % 3]
+}
+
+/// Most significant bit first reader; callers check the length up front.
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, n: usize) -> u64 {
+        let mut value = 0;
+        for _ in 0..n {
+            let bit = self.data[self.pos / 8] >> (7 - self.pos % 8) & 1;
+            value = value << 1 | u64::from(bit);
+            self.pos += 1;
+        }
+        value
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.pos += n;
+    }
+}
+
+/// Parse the XML document written by `xml_export`.
+fn parse_xml(xml: &str) -> IsccResult<VideoSignature> {
+    let media_time_unit = match elements(xml, "MediaTimeUnit").next() {
+        Some(text) => parse_number(text, "MediaTimeUnit")?,
+        None => return Err(invalid("XML signature has no MediaTimeUnit".into())),
+    };
+    let frames = elements(xml, "VideoFrame")
+        .map(|frame| {
+            let text = elements(frame, "FrameSignature")
+                .next()
+                .ok_or_else(|| invalid("VideoFrame without FrameSignature".into()))?;
+            let signature = text
+                .split_ascii_whitespace()
+                .map(|v| match v {
+                    "0" => Ok(0),
+                    "1" => Ok(1),
+                    "2" => Ok(2),
+                    _ => Err(invalid(format!("bad FrameSignature element {v:?}"))),
+                })
+                .collect::<IsccResult<Vec<i32>>>()?;
+            if signature.len() != FRAME_SIGNATURE_SIZE {
+                return Err(invalid(format!(
+                    "FrameSignature has {} elements, expected {FRAME_SIGNATURE_SIZE}",
+                    signature.len()
+                )));
+            }
+            let field = |name| {
+                elements(frame, name)
+                    .next()
+                    .map_or(Ok(0), |text| parse_number(text, name))
+            };
+            Ok(SignatureFrame {
+                media_time: field("MediaTimeOfFrame")?,
+                confidence: u8::try_from(field("FrameConfidence")?)
+                    .map_err(|_| invalid("FrameConfidence out of range".into()))?,
+                signature,
+            })
+        })
+        .collect::<IsccResult<_>>()?;
+    Ok(VideoSignature {
+        media_time_unit,
+        frames,
+    })
+}
+
+fn parse_number<T: std::str::FromStr>(text: &str, name: &str) -> IsccResult<T> {
+    text.trim()
+        .parse()
+        .map_err(|_| invalid(format!("bad {name} value {:?}", text.trim())))
+}
+
+/// Contents of each `<name>…</name>` element in `xml`, in document order.
+///
+/// A minimal scanner for FFmpeg's fixed output: namespace prefixes and
+/// attributes are ignored, and elements of the same name must not nest.
+fn elements<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let mut rest = xml;
+    std::iter::from_fn(move || {
+        loop {
+            let start = rest.find('<')?;
+            rest = &rest[start + 1..];
+            let end = rest.find('>')?;
+            let tag = &rest[..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with(['/', '?', '!']) || tag_name(tag) != name {
+                continue;
+            }
+            if tag.ends_with('/') {
+                return Some("");
+            }
+            let mut search = 0;
+            while let Some(offset) = rest[search..].find("</") {
+                let close = search + offset;
+                let close_end = close + rest[close..].find('>')?;
+                if tag_name(&rest[close + 2..close_end]) == name {
+                    let content = &rest[..close];
+                    rest = &rest[close_end + 1..];
+                    return Some(content);
+                }
+                search = close_end;
+            }
+            return None;
+        }
+    })
+}
+
+/// Local name of a tag: up to the first whitespace or `/`, without prefix.
+fn tag_name(tag: &str) -> &str {
+    let name = tag
+        .split(|c: char| c.is_ascii_whitespace() || c == '/')
+        .next()
+        .unwrap_or("");
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+fn invalid(msg: String) -> IsccError {
+    IsccError::InvalidInput(format!("invalid video signature: {msg}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal MSB-first bit writer mirroring FFmpeg's `put_bits`.
+    struct BitWriter {
+        bytes: Vec<u8>,
+        bits: usize,
+    }
+
+    impl BitWriter {
+        fn put(&mut self, n: usize, value: u64) {
+            for i in (0..n).rev() {
+                if self.bits % 8 == 0 {
+                    self.bytes.push(0);
+                }
+                let bit = if i < 64 { (value >> i & 1) as u8 } else { 0 };
+                *self.bytes.last_mut().unwrap() |= bit << (7 - self.bits % 8);
+                self.bits += 1;
+            }
+        }
+    }
+
+    fn sample_frames() -> Vec<SignatureFrame> {
+        (0..3u64)
+            .map(|f| SignatureFrame {
+                media_time: f * 1001,
+                confidence: 10 + f as u8,
+                signature: (0..FRAME_SIGNATURE_SIZE)
+                    .map(|i| ((i as u64 * 7 + f) % 3) as i32)
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Encode frames like FFmpeg's `binary_export` with one coarse segment.
+    fn binary_export(frames: &[SignatureFrame]) -> Vec<u8> {
+        let mut w = BitWriter {
+            bytes: Vec::new(),
+            bits: 0,
+        };
+        w.put(32, 1);
+        w.put(1, 1);
+        w.put(32, 0);
+        w.put(16, 639);
+        w.put(16, 479);
+        w.put(32, 0);
+        w.put(32, frames.len() as u64);
+        w.put(16, 30000);
+        w.put(1, 1);
+        w.put(32, 0);
+        w.put(32, frames.last().map_or(0, |f| f.media_time));
+        w.put(32, 1);
+        w.put(32 + 32 + 1 + 32 + 32, 0);
+        for _ in 0..5 {
+            w.put(243, 0);
+        }
+        w.put(1, 0);
+        for frame in frames {
+            w.put(1, 1);
+            w.put(32, frame.media_time);
+            w.put(8, frame.confidence.into());
+            w.put(40, 0x0102030405);
+            for digits in frame.signature.chunks(5) {
+                let byte = digits.iter().fold(0, |acc, &d| acc * 3 + d as u64);
+                w.put(8, byte);
+            }
+        }
+        w.bytes
+    }
+
+    /// Render frames like FFmpeg's `xml_export`.
+    fn xml_export(frames: &[SignatureFrame]) -> String {
+        let mut xml = String::from(concat!(
+            "<?xml version='1.0' encoding='ASCII' ?>\n",
+            "<Mpeg7 xmlns=\"urn:mpeg:mpeg7:schema:2001\">\n",
+            "  <DescriptionUnit xsi:type=\"DescriptorCollectionType\">\n",
+            "    <Descriptor xsi:type=\"VideoSignatureType\">\n",
+            "      <VideoSignatureRegion>\n",
+            "        <MediaTimeUnit>30000</MediaTimeUnit>\n",
+            "        <VSVideoSegment>\n",
+            "          <BagOfWords>0  1  0 </BagOfWords>\n",
+            "        </VSVideoSegment>\n",
+        ));
+        for frame in frames {
+            let sig: Vec<String> = frame.signature.iter().map(|d| d.to_string()).collect();
+            xml += &format!(
+                "        <VideoFrame>\n          <MediaTimeOfFrame>{}</MediaTimeOfFrame>\n          \
+                 <FrameConfidence>{}</FrameConfidence>\n          <Word>1  2  3  4  5 </Word>\n          \
+                 <FrameSignature>{} </FrameSignature>\n        </VideoFrame>\n",
+                frame.media_time,
+                frame.confidence,
+                sig.join("  ")
+            );
+        }
+        xml + "      </VideoSignatureRegion>\n    </Descriptor>\n  </DescriptionUnit>\n</Mpeg7>\n"
+    }
+
+    #[test]
+    fn test_parse_video_signature_binary() {
+        let frames = sample_frames();
+        let parsed = parse_video_signature(&binary_export(&frames)).unwrap();
+        assert_eq!(parsed.media_time_unit, 30000);
+        assert_eq!(parsed.frames, frames);
+        assert_eq!(parsed.frame_signatures()[1], frames[1].signature.as_slice());
+    }
+
+    #[test]
+    fn test_parse_video_signature_xml() {
+        let frames = sample_frames();
+        let parsed = parse_video_signature(xml_export(&frames).as_bytes()).unwrap();
+        assert_eq!(parsed.media_time_unit, 30000);
+        assert_eq!(parsed.frames, frames);
+        let binary = parse_video_signature(&binary_export(&frames)).unwrap();
+        assert_eq!(parsed, binary);
+    }
+
+    #[test]
+    fn test_parse_video_signature_invalid() {
+        let data = binary_export(&sample_frames());
+        assert!(matches!(
+            parse_video_signature(&data[..data.len() - 1]),
+            Err(IsccError::Truncated { .. })
+        ));
+        assert!(matches!(
+            parse_video_signature(&[0; 8]),
+            Err(IsccError::Truncated { .. })
+        ));
+        let mut two_regions = data.clone();
+        two_regions[3] = 2;
+        assert!(matches!(
+            parse_video_signature(&two_regions),
+            Err(IsccError::InvalidInput(_))
+        ));
+
+        let xml = xml_export(&sample_frames());
+        for bad in [
+            xml.replacen("<FrameSignature>2", "<FrameSignature>3", 1),
+            xml.replacen("<FrameSignature>2 ", "<FrameSignature>", 1),
+            xml.replace("<MediaTimeUnit>30000</MediaTimeUnit>", ""),
+        ] {
+            assert!(
+                matches!(
+                    parse_video_signature(bad.as_bytes()),
+                    Err(IsccError::InvalidInput(_))
+                ),
+                "{}",
+                &bad[..200]
+            );
+        }
+    }
+
+    #[test]
+    fn test_gen_video_code_from_signature_file() {
+        let frames = sample_frames();
+        let sigs: Vec<&[i32]> = frames.iter().map(|f| f.signature.as_slice()).collect();
+        let expected = crate::gen_video_code_v0(&sigs, 64).unwrap().iscc;
+
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("sig.bin");
+        let xml = dir.path().join("sig.xml");
+        std::fs::write(&bin, binary_export(&frames)).unwrap();
+        std::fs::write(&xml, xml_export(&frames)).unwrap();
+        for path in [&bin, &xml] {
+            let result = crate::gen_video_code_from_signature_file(path, 64).unwrap();
+            assert_eq!(result.iscc, expected);
+        }
+
+        std::fs::write(&bin, binary_export(&[])).unwrap();
+        assert!(matches!(
+            crate::gen_video_code_from_signature_file(&bin, 64),
+            Err(IsccError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            crate::gen_video_code_from_signature_file(&dir.path().join("missing"), 64),
+            Err(IsccError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_elements_prefixes_and_attributes() {
+        let xml = r#"<a:Frame id="1"><x>1</x></a:Frame><Frame/><Frame >2</Frame>"#;
+        assert_eq!(
+            elements(xml, "Frame").collect::<Vec<_>>(),
+            ["<x>1</x>", "", "2"]
+        );
+        assert_eq!(elements(xml, "x").collect::<Vec<_>>(), ["1"]);
+    }
+}
@@ -290,9 +290,10 @@ pub fn alg_wtahash(vec: &[i64], bits: u32) -> IsccResult<Vec<u8>> {
         )));
     }
     if bits == 0 || bits % 8 != 0 || bits > 256 {
-        return Err(IsccError::InvalidInput(format!(
-            "WTA-Hash bits must be > 0, divisible by 8, and ≤ 256, got {bits}"
-        )));
+        return Err(IsccError::InvalidBits {
+            bits,
+            expected: "> 0, divisible by 8, and ≤ 256".into(),
+        });
     }
 
     let n_bytes = (bits / 8) as usize;
@@ -2,16 +2,24 @@
  * Unit tests for text utility, helper, algorithm primitive, streaming hasher,
  * and gen_sum_code_v0 napi-rs bindings.
  *
- * Tests the 12 non-gen functions, 2 streaming hasher classes, and
- * gen_sum_code_v0:
+ * Tests the 12 non-gen functions, 3 streaming hasher classes,
+ * gen_sum_code_v0 and gen_sum_code_v0_with_progress:
  * text_clean, text_remove_newlines, text_trim, text_collapse, encode_base64,
  * iscc_decompose, conformance_selftest, sliding_window, alg_simhash,
  * alg_minhash_256, alg_cdc_chunks, soft_hash_video_v0, DataHasher,
- * InstanceHasher, gen_sum_code_v0.
+ * InstanceHasher, SumHasher, gen_sum_code_v0, gen_sum_code_v0_with_progress,
+ * CancelToken.
  */
 
 import { describe, it, after } from 'node:test';
-import { strictEqual, deepStrictEqual, throws, ok, match as assertMatch } from 'node:assert';
+import {
+    strictEqual,
+    deepStrictEqual,
+    throws,
+    rejects,
+    ok,
+    match as assertMatch,
+} from 'node:assert';
 import { writeFileSync, unlinkSync, mkdtempSync } from 'node:fs';
 import { tmpdir } from 'node:os';
 import { join } from 'node:path';
@@ -33,8 +41,19 @@ import {
     gen_instance_code_v0,
     gen_iscc_code_v0,
     gen_sum_code_v0,
+    gen_sum_code_v0_with_progress,
+    gen_text_code_v0,
+    gen_text_code_v0_granular,
+    gen_image_code_from_pixels,
+    gen_audio_code_v0,
+    gen_audio_code_from_chromaprint,
+    decode_chromaprint,
+    gen_video_code_v0,
+    gen_video_code_from_signature_file,
     DataHasher,
     InstanceHasher,
+    SumHasher,
+    CancelToken,
     META_TRIM_NAME,
     META_TRIM_DESCRIPTION,
     META_TRIM_META,
@@ -154,6 +173,11 @@ describe('iscc_decompose', () => {
     it('throws on invalid input', () => {
         throws(() => iscc_decompose('NOT_VALID'), /./);
     });
+
+    it('sets a stable error code on failure', () => {
+        throws(() => iscc_decompose('NOT_VALID'), (err) => err.code === 'base32');
+        throws(() => sliding_window('hello', 1), (err) => err.code === 'invalid_input');
+    });
 });
 
 describe('conformance_selftest', () => {
@@ -434,6 +458,77 @@ describe('InstanceHasher', () => {
     });
 });
 
+describe('SumHasher', () => {
+    it('matches gen_sum_code_v0 for the same bytes', () => {
+        const dir = mkdtempSync(join(tmpdir(), 'iscc-napi-'));
+        const filePath = join(dir, 'sum.bin');
+        const data = Buffer.from('The quick brown fox jumps over the lazy dog');
+        writeFileSync(filePath, data);
+        try {
+            const sh = new SumHasher();
+            sh.update(data.subarray(0, 10));
+            sh.update(data.subarray(10));
+            const streaming = sh.finalize(128, true, true);
+            deepStrictEqual(streaming, gen_sum_code_v0(filePath, 128, true, true));
+        } finally {
+            unlinkSync(filePath);
+        }
+    });
+
+    it('throws on update or finalize after finalize', () => {
+        const sh = new SumHasher();
+        const result = sh.finalize();
+        strictEqual(result.filesize, 0);
+        throws(() => sh.update(Buffer.from('data')), /already finalized/);
+        throws(() => sh.finalize(), /already finalized/);
+    });
+});
+
+describe('gen_sum_code_v0_with_progress', () => {
+    it('resolves to the gen_sum_code_v0 result and reports progress', async () => {
+        const dir = mkdtempSync(join(tmpdir(), 'iscc-napi-'));
+        const filePath = join(dir, 'progress.bin');
+        const data = Buffer.alloc(IO_READ_SIZE + 1000, 7);
+        writeFileSync(filePath, data);
+        try {
+            const calls = [];
+            const result = await gen_sum_code_v0_with_progress(
+                filePath,
+                64,
+                false,
+                true,
+                (done, total) => calls.push([done, total]),
+            );
+            deepStrictEqual(result, gen_sum_code_v0(filePath, 64, false, true));
+            // Progress calls are queued to the event loop; let them drain.
+            await new Promise((resolve) => setImmediate(resolve));
+            deepStrictEqual(calls, [
+                [IO_READ_SIZE, data.length],
+                [data.length, data.length],
+            ]);
+        } finally {
+            unlinkSync(filePath);
+        }
+    });
+
+    it('rejects with code cancelled when the token is cancelled', async () => {
+        const dir = mkdtempSync(join(tmpdir(), 'iscc-napi-'));
+        const filePath = join(dir, 'cancel.bin');
+        writeFileSync(filePath, Buffer.from('data'));
+        try {
+            const token = new CancelToken();
+            token.cancel();
+            ok(token.is_cancelled());
+            await rejects(
+                gen_sum_code_v0_with_progress(filePath, 64, false, false, undefined, token),
+                { code: 'cancelled' },
+            );
+        } finally {
+            unlinkSync(filePath);
+        }
+    });
+});
+
 // ── Algorithm constants ──────────────────────────────────────────────────────
 
 describe('META_TRIM_NAME', () => {
@@ -513,7 +608,7 @@ describe('encode_component', () => {
     it('throws on digest too short', () => {
         throws(
             () => encode_component(0, 0, 0, 64, Buffer.alloc(4)),
-            /digest length/
+            /truncated input/
         );
     });
 
@@ -694,3 +789,78 @@ describe('gen_sum_code_v0', () => {
         ok(result.units[1].startsWith('ISCC:'), 'units[1] (Instance-Code) should start with ISCC:');
     });
 });
+
+// ── gen_text_code_v0_granular ───────────────────────────────────────────────
+
+describe('gen_text_code_v0_granular', () => {
+    const text = 'Hello World, this is a granular text. '.repeat(50);
+
+    it('returns the same iscc as gen_text_code_v0', () => {
+        const result = gen_text_code_v0_granular(text);
+        strictEqual(result.iscc, gen_text_code_v0(text));
+    });
+
+    it('features cover the collapsed text and map to the input', () => {
+        const result = gen_text_code_v0_granular(text);
+        ok(result.features.length >= 1);
+        strictEqual(result.features[0].offset, 0);
+        const total = result.features.reduce((sum, f) => sum + f.size, 0);
+        strictEqual(total, result.characters);
+        for (const f of result.features) {
+            strictEqual(typeof f.simprint, 'number');
+            ok(f.source_offset + f.source_size <= text.length);
+        }
+    });
+});
+
+describe('gen_image_code_from_pixels', () => {
+    const width = 64;
+    const height = 48;
+    const gray = Buffer.alloc(width * height);
+    for (let i = 0; i < gray.length; i++) {
+        gray[i] = ((i % width) * 7 + Math.floor(i / width) * 3) % 256;
+    }
+    const rgba = Buffer.alloc(gray.length * 4);
+    gray.forEach((v, i) => rgba.set([v, v, v, 255], i * 4));
+
+    it('gray and opaque RGBA give the same code', () => {
+        const iscc = gen_image_code_from_pixels(width, height, 'L', gray);
+        assertMatch(iscc, /^ISCC:/);
+        strictEqual(gen_image_code_from_pixels(width, height, 'RGBA', rgba), iscc);
+    });
+
+    it('throws on unknown format or wrong buffer size', () => {
+        throws(() => gen_image_code_from_pixels(width, height, 'CMYK', rgba), /invalid input/);
+        throws(() => gen_image_code_from_pixels(width, height, 'RGB', rgba), /invalid input/);
+    });
+});
+
+describe('gen_audio_code_from_chromaprint', () => {
+    it('decodes compressed and JSON fpcalc output', () => {
+        const expected = gen_audio_code_v0([1, 0]);
+        deepStrictEqual(decode_chromaprint('AAAAAkEA'), [1, 0]);
+        strictEqual(gen_audio_code_from_chromaprint('FINGERPRINT=AAAAAkEA'), expected);
+        strictEqual(gen_audio_code_from_chromaprint('{"fingerprint": [1, 0]}'), expected);
+    });
+
+    it('throws on a malformed fingerprint', () => {
+        throws(() => decode_chromaprint('AQAA!'), /invalid input/);
+    });
+});
+
+describe('gen_video_code_from_signature_file', () => {
+    it('matches gen_video_code_v0 on the parsed frames', () => {
+        const frames = [0, 1, 2].map((f) => Array.from({ length: 380 }, (_, i) => (i * 7 + f) % 3));
+        const body = frames
+            .map((sig) => `<VideoFrame><FrameSignature>${sig.join(' ')}</FrameSignature></VideoFrame>`)
+            .join('');
+        const dir = mkdtempSync(join(tmpdir(), 'iscc-sig-'));
+        const file = join(dir, 'sig.xml');
+        writeFileSync(file, `<Mpeg7><MediaTimeUnit>25</MediaTimeUnit>${body}</Mpeg7>`);
+        try {
+            strictEqual(gen_video_code_from_signature_file(file), gen_video_code_v0(frames));
+        } finally {
+            unlinkSync(file);
+        }
+    });
+});
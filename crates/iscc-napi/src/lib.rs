@@ -4,9 +4,16 @@
 //! npm package. Each function is a thin wrapper around the corresponding
 //! `iscc_lib` API, converting napi types to Rust types and mapping errors.
 
-use napi::bindgen_prelude::Buffer;
+use napi::bindgen_prelude::{AsyncTask, Buffer, FnArgs};
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi::{Env, JsError, Status, Task};
 use napi_derive::napi;
 
+/// Map a core `IsccError` to a JS error with a stable `code` property.
+fn iscc_err(e: iscc_lib::IsccError) -> napi::Error<&'static str> {
+    napi::Error::new(e.code(), e.to_string())
+}
+
 // ── Algorithm constants ───────────────────────────────────────────────────────
 
 /// Maximum byte length for the name field after trimming.
@@ -42,9 +49,8 @@ pub fn encode_component(
     version: u8,
     bit_length: u32,
     digest: Buffer,
-) -> napi::Result<String> {
-    iscc_lib::encode_component(mtype, stype, version, bit_length, digest.as_ref())
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+) -> napi::Result<String, &'static str> {
+    iscc_lib::encode_component(mtype, stype, version, bit_length, digest.as_ref()).map_err(iscc_err)
 }
 
 /// Result of decoding an ISCC unit string.
@@ -67,9 +73,8 @@ pub struct IsccDecodeResult {
 /// Returns an object with `maintype`, `subtype`, `version`, `length`, and
 /// `digest` fields. Strips an optional "ISCC:" prefix before decoding.
 #[napi(js_name = "iscc_decode")]
-pub fn iscc_decode(iscc: String) -> napi::Result<IsccDecodeResult> {
-    let (mt, st, vs, li, digest) =
-        iscc_lib::iscc_decode(&iscc).map_err(|e| napi::Error::from_reason(e.to_string()))?;
+pub fn iscc_decode(iscc: String) -> napi::Result<IsccDecodeResult, &'static str> {
+    let (mt, st, vs, li, digest) = iscc_lib::iscc_decode(&iscc).map_err(iscc_err)?;
     Ok(IsccDecodeResult {
         maintype: mt,
         subtype: st,
@@ -84,8 +89,8 @@ pub fn iscc_decode(iscc: String) -> napi::Result<IsccDecodeResult> {
 /// Uses `application/ld+json` media type when the JSON contains an `@context`
 /// key, otherwise `application/json`.
 #[napi(js_name = "json_to_data_url")]
-pub fn json_to_data_url(json: String) -> napi::Result<String> {
-    iscc_lib::json_to_data_url(&json).map_err(|e| napi::Error::from_reason(e.to_string()))
+pub fn json_to_data_url(json: String) -> napi::Result<String, &'static str> {
+    iscc_lib::json_to_data_url(&json).map_err(iscc_err)
 }
 
 // ── Code generators ──────────────────────────────────────────────────────────
@@ -100,11 +105,11 @@ pub fn gen_meta_code_v0(
     description: Option<String>,
     meta: Option<String>,
     bits: Option<u32>,
-) -> napi::Result<String> {
+) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_meta_code_v0(&name, description.as_deref(), meta.as_deref(), bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a Text-Code from plain text content.
@@ -112,11 +117,69 @@ pub fn gen_meta_code_v0(
 /// Produces an ISCC Content-Code for text using MinHash-based
 /// similarity hashing.
 #[napi(js_name = "gen_text_code_v0")]
-pub fn gen_text_code_v0(text: String, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_text_code_v0(text: String, bits: Option<u32>) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_text_code_v0(&text, bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
+}
+
+/// One content-defined chunk of the collapsed text (character units).
+#[napi(object)]
+pub struct NapiTextFeature {
+    /// Character offset of the chunk in the collapsed text.
+    pub offset: i64,
+    /// Chunk length in characters of the collapsed text.
+    pub size: i64,
+    /// 32-bit SimHash over the chunk's character n-gram hashes.
+    pub simprint: u32,
+    /// Character offset of the chunk's source span in the original input.
+    #[napi(js_name = "source_offset")]
+    pub source_offset: i64,
+    /// Length in characters of the chunk's source span in the original input.
+    #[napi(js_name = "source_size")]
+    pub source_size: i64,
+}
+
+/// Result of generating a Text-Code with per-chunk features.
+#[napi(object)]
+pub struct NapiTextCodeResult {
+    /// ISCC code string.
+    pub iscc: String,
+    /// Character count after `text_collapse`.
+    pub characters: i64,
+    /// Content-defined chunks of the collapsed text in order.
+    pub features: Vec<NapiTextFeature>,
+}
+
+/// Generate a Text-Code with per-chunk features for locating shared passages.
+///
+/// Returns an object with `iscc`, `characters`, and `features` fields. The
+/// `iscc` value equals `gen_text_code_v0` for the same input.
+#[napi(js_name = "gen_text_code_v0_granular")]
+pub fn gen_text_code_v0_granular(
+    text: String,
+    bits: Option<u32>,
+) -> napi::Result<NapiTextCodeResult, &'static str> {
+    let bits = bits.unwrap_or(64);
+    let result = iscc_lib::gen_text_code_v0_granular(&text, bits).map_err(iscc_err)?;
+    let features = result
+        .features
+        .unwrap_or_default()
+        .into_iter()
+        .map(|f| NapiTextFeature {
+            offset: f.offset as i64,
+            size: f.size as i64,
+            simprint: f.simprint,
+            source_offset: f.source_offset as i64,
+            source_size: f.source_size as i64,
+        })
+        .collect();
+    Ok(NapiTextCodeResult {
+        iscc: result.iscc,
+        characters: result.characters as i64,
+        features,
+    })
 }
 
 /// Generate an Image-Code from pixel data.
@@ -124,11 +187,30 @@ pub fn gen_text_code_v0(text: String, bits: Option<u32>) -> napi::Result<String>
 /// Produces an ISCC Content-Code for images from 1024 grayscale pixels
 /// (32×32) using a DCT-based perceptual hash.
 #[napi(js_name = "gen_image_code_v0")]
-pub fn gen_image_code_v0(pixels: Buffer, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_image_code_v0(pixels: Buffer, bits: Option<u32>) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_image_code_v0(pixels.as_ref(), bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
+}
+
+/// Generate an Image-Code from a raw pixel buffer of any size.
+///
+/// `format` is a Pillow mode name (`"L"`, `"LA"`, `"RGB"` or `"RGBA"`). The
+/// pixels are normalized to 32×32 grayscale before hashing.
+#[napi(js_name = "gen_image_code_from_pixels")]
+pub fn gen_image_code_from_pixels(
+    width: u32,
+    height: u32,
+    format: String,
+    pixels: Buffer,
+    bits: Option<u32>,
+) -> napi::Result<String, &'static str> {
+    let bits = bits.unwrap_or(64);
+    let format = format.parse().map_err(iscc_err)?;
+    iscc_lib::gen_image_code_from_pixels(width, height, format, pixels.as_ref(), bits)
+        .map(|r| r.iscc)
+        .map_err(iscc_err)
 }
 
 /// Generate an Audio-Code from a Chromaprint feature vector.
@@ -136,11 +218,32 @@ pub fn gen_image_code_v0(pixels: Buffer, bits: Option<u32>) -> napi::Result<Stri
 /// Produces an ISCC Content-Code for audio from signed integer
 /// Chromaprint fingerprint features using multi-stage SimHash.
 #[napi(js_name = "gen_audio_code_v0")]
-pub fn gen_audio_code_v0(cv: Vec<i32>, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_audio_code_v0(cv: Vec<i32>, bits: Option<u32>) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_audio_code_v0(&cv, bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
+}
+
+/// Generate an Audio-Code from `fpcalc` output.
+///
+/// Accepts the compressed base64 fingerprint, comma-separated raw integers,
+/// or the complete plain or `-json` output of `fpcalc`.
+#[napi(js_name = "gen_audio_code_from_chromaprint")]
+pub fn gen_audio_code_from_chromaprint(
+    fp: String,
+    bits: Option<u32>,
+) -> napi::Result<String, &'static str> {
+    let bits = bits.unwrap_or(64);
+    iscc_lib::gen_audio_code_from_chromaprint(&fp, bits)
+        .map(|r| r.iscc)
+        .map_err(iscc_err)
+}
+
+/// Decode a compressed base64 Chromaprint fingerprint into its raw values.
+#[napi(js_name = "decode_chromaprint")]
+pub fn decode_chromaprint(fp: String) -> napi::Result<Vec<i32>, &'static str> {
+    iscc_lib::decode_chromaprint(&fp).map_err(iscc_err)
 }
 
 /// Generate a Video-Code from frame signature data.
@@ -148,11 +251,29 @@ pub fn gen_audio_code_v0(cv: Vec<i32>, bits: Option<u32>) -> napi::Result<String
 /// Produces an ISCC Content-Code for video from MPEG-7 frame
 /// signature vectors using WTA-Hash.
 #[napi(js_name = "gen_video_code_v0")]
-pub fn gen_video_code_v0(frame_sigs: Vec<Vec<i32>>, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_video_code_v0(
+    frame_sigs: Vec<Vec<i32>>,
+    bits: Option<u32>,
+) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_video_code_v0(&frame_sigs, bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
+}
+
+/// Generate a Video-Code from an FFmpeg `signature` filter output file.
+///
+/// Reads binary (`format=binary`) or XML (`format=xml`) MPEG-7 video
+/// signatures and hashes the per-frame fine signatures.
+#[napi(js_name = "gen_video_code_from_signature_file")]
+pub fn gen_video_code_from_signature_file(
+    path: String,
+    bits: Option<u32>,
+) -> napi::Result<String, &'static str> {
+    let bits = bits.unwrap_or(64);
+    iscc_lib::gen_video_code_from_signature_file(std::path::Path::new(&path), bits)
+        .map(|r| r.iscc)
+        .map_err(iscc_err)
 }
 
 /// Generate a Mixed-Code from multiple Content-Code strings.
@@ -160,12 +281,15 @@ pub fn gen_video_code_v0(frame_sigs: Vec<Vec<i32>>, bits: Option<u32>) -> napi::
 /// Produces a Mixed Content-Code by combining multiple ISCC Content-Codes
 /// of different types using SimHash.
 #[napi(js_name = "gen_mixed_code_v0")]
-pub fn gen_mixed_code_v0(codes: Vec<String>, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_mixed_code_v0(
+    codes: Vec<String>,
+    bits: Option<u32>,
+) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     let refs: Vec<&str> = codes.iter().map(|s| s.as_str()).collect();
     iscc_lib::gen_mixed_code_v0(&refs, bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a Data-Code from raw byte data.
@@ -173,11 +297,11 @@ pub fn gen_mixed_code_v0(codes: Vec<String>, bits: Option<u32>) -> napi::Result<
 /// Produces an ISCC Data-Code by splitting data into content-defined
 /// chunks and applying MinHash for similarity hashing.
 #[napi(js_name = "gen_data_code_v0")]
-pub fn gen_data_code_v0(data: Buffer, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_data_code_v0(data: Buffer, bits: Option<u32>) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_data_code_v0(data.as_ref(), bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate an Instance-Code from raw byte data.
@@ -185,11 +309,11 @@ pub fn gen_data_code_v0(data: Buffer, bits: Option<u32>) -> napi::Result<String>
 /// Produces an ISCC Instance-Code by hashing the complete byte stream
 /// with BLAKE3. Returns the ISCC string with "ISCC:" prefix.
 #[napi(js_name = "gen_instance_code_v0")]
-pub fn gen_instance_code_v0(data: Buffer, bits: Option<u32>) -> napi::Result<String> {
+pub fn gen_instance_code_v0(data: Buffer, bits: Option<u32>) -> napi::Result<String, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_instance_code_v0(data.as_ref(), bits)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a composite ISCC-CODE from individual unit codes.
@@ -197,12 +321,15 @@ pub fn gen_instance_code_v0(data: Buffer, bits: Option<u32>) -> napi::Result<Str
 /// Combines multiple ISCC unit codes into a single composite ISCC-CODE.
 /// Requires at least Data-Code and Instance-Code.
 #[napi(js_name = "gen_iscc_code_v0")]
-pub fn gen_iscc_code_v0(codes: Vec<String>, wide: Option<bool>) -> napi::Result<String> {
+pub fn gen_iscc_code_v0(
+    codes: Vec<String>,
+    wide: Option<bool>,
+) -> napi::Result<String, &'static str> {
     let wide = wide.unwrap_or(false);
     let refs: Vec<&str> = codes.iter().map(|s| s.as_str()).collect();
     iscc_lib::gen_iscc_code_v0(&refs, wide)
         .map(|r| r.iscc)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Result of generating a composite ISCC-CODE from a file in a single pass.
@@ -229,17 +356,131 @@ pub fn gen_sum_code_v0(
     bits: Option<u32>,
     wide: Option<bool>,
     add_units: Option<bool>,
-) -> napi::Result<NapiSumCodeResult> {
+) -> napi::Result<NapiSumCodeResult, &'static str> {
     let bits = bits.unwrap_or(64);
     let wide = wide.unwrap_or(false);
     let add_units = add_units.unwrap_or(false);
-    let result = iscc_lib::gen_sum_code_v0(std::path::Path::new(&path), bits, wide, add_units)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
-    Ok(NapiSumCodeResult {
-        iscc: result.iscc,
-        datahash: result.datahash,
-        filesize: result.filesize as i64,
-        units: result.units,
+    iscc_lib::gen_sum_code_v0(std::path::Path::new(&path), bits, wide, add_units)
+        .map(NapiSumCodeResult::from)
+        .map_err(iscc_err)
+}
+
+impl From<iscc_lib::SumCodeResult> for NapiSumCodeResult {
+    fn from(result: iscc_lib::SumCodeResult) -> Self {
+        Self {
+            iscc: result.iscc,
+            datahash: result.datahash,
+            filesize: result.filesize as i64,
+            units: result.units,
+        }
+    }
+}
+
+/// Progress callback invoked as `progress(done, total)` from the libuv pool.
+type ProgressFn = ThreadsafeFunction<FnArgs<(f64, f64)>, (), FnArgs<(f64, f64)>, Status, false>;
+
+/// Cancellation flag for `gen_sum_code_v0_with_progress`.
+#[napi(js_name = "CancelToken")]
+pub struct NapiCancelToken {
+    inner: iscc_lib::CancelToken,
+}
+
+impl Default for NapiCancelToken {
+    /// Create a new `NapiCancelToken` (delegates to `new()`).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[napi]
+impl NapiCancelToken {
+    /// Create a token that is not cancelled.
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: iscc_lib::CancelToken::new(),
+        }
+    }
+
+    /// Request cancellation of every operation using this token.
+    #[napi]
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Whether `cancel` has been called.
+    #[napi(js_name = "is_cancelled")]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
+
+/// Background task behind `gen_sum_code_v0_with_progress`.
+pub struct SumCodeTask {
+    path: String,
+    bits: u32,
+    wide: bool,
+    add_units: bool,
+    progress: Option<ProgressFn>,
+    cancel: iscc_lib::CancelToken,
+}
+
+impl Task for SumCodeTask {
+    type Output = iscc_lib::IsccResult<iscc_lib::SumCodeResult>;
+    type JsValue = NapiSumCodeResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let progress = self.progress.as_ref();
+        Ok(iscc_lib::gen_sum_code_with_progress(
+            std::path::Path::new(&self.path),
+            self.bits,
+            self.wide,
+            self.add_units,
+            |done, total| {
+                if let Some(progress) = progress {
+                    progress.call(
+                        FnArgs::from((done as f64, total as f64)),
+                        ThreadsafeFunctionCallMode::NonBlocking,
+                    );
+                }
+            },
+            Some(&self.cancel),
+        ))
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        // Rebuild the error on the JS side so the rejection keeps its `code`.
+        output
+            .map(NapiSumCodeResult::from)
+            .map_err(|e| JsError::from(iscc_err(e)).into_unknown(env).into())
+    }
+}
+
+/// Generate a composite ISCC-CODE from a file on a background thread.
+///
+/// Same result as `gen_sum_code_v0`, returned as a Promise. `progress` is
+/// called as `progress(done, total)` after each read block (`total` is 0
+/// when the size is unknown). Cancelling `cancel` rejects the Promise with
+/// code `cancelled`.
+#[napi(
+    js_name = "gen_sum_code_v0_with_progress",
+    ts_args_type = "path: string, bits?: number, wide?: boolean, add_units?: boolean, progress?: (done: number, total: number) => void, cancel?: CancelToken"
+)]
+pub fn gen_sum_code_v0_with_progress(
+    path: String,
+    bits: Option<u32>,
+    wide: Option<bool>,
+    add_units: Option<bool>,
+    progress: Option<ProgressFn>,
+    cancel: Option<&NapiCancelToken>,
+) -> AsyncTask<SumCodeTask> {
+    AsyncTask::new(SumCodeTask {
+        path,
+        bits: bits.unwrap_or(64),
+        wide: wide.unwrap_or(false),
+        add_units: add_units.unwrap_or(false),
+        progress,
+        cancel: cancel.map(|c| c.inner.clone()).unwrap_or_default(),
     })
 }
 
@@ -294,8 +535,8 @@ pub fn encode_base64(data: Buffer) -> String {
 /// The optional "ISCC:" prefix is stripped before decoding.
 /// Returns an array of base32-encoded ISCC-UNIT strings (without prefix).
 #[napi(js_name = "iscc_decompose")]
-pub fn iscc_decompose(iscc_code: String) -> napi::Result<Vec<String>> {
-    iscc_lib::iscc_decompose(&iscc_code).map_err(|e| napi::Error::from_reason(e.to_string()))
+pub fn iscc_decompose(iscc_code: String) -> napi::Result<Vec<String>, &'static str> {
+    iscc_lib::iscc_decompose(&iscc_code).map_err(iscc_err)
 }
 
 /// Run all conformance tests against vendored test vectors.
@@ -311,9 +552,8 @@ pub fn conformance_selftest() -> bool {
 /// Returns overlapping substrings of `width` Unicode characters, advancing
 /// by one character at a time. Throws if width is less than 2.
 #[napi(js_name = "sliding_window")]
-pub fn sliding_window(seq: String, width: u32) -> napi::Result<Vec<String>> {
-    iscc_lib::sliding_window(&seq, width as usize)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+pub fn sliding_window(seq: String, width: u32) -> napi::Result<Vec<String>, &'static str> {
+    iscc_lib::sliding_window(&seq, width as usize).map_err(iscc_err)
 }
 
 // ── Algorithm primitives ─────────────────────────────────────────────────────
@@ -324,10 +564,10 @@ pub fn sliding_window(seq: String, width: u32) -> napi::Result<Vec<String>> {
 /// when its frequency meets or exceeds half the input count. Returns 32
 /// zero bytes for empty input. Throws on mismatched digest lengths.
 #[napi(js_name = "alg_simhash")]
-pub fn alg_simhash(hash_digests: Vec<Buffer>) -> napi::Result<Buffer> {
+pub fn alg_simhash(hash_digests: Vec<Buffer>) -> napi::Result<Buffer, &'static str> {
     iscc_lib::alg_simhash(&hash_digests)
         .map(|v| v.into())
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Compute a 256-bit MinHash digest from 32-bit integer features.
@@ -349,10 +589,10 @@ pub fn alg_cdc_chunks(
     data: Buffer,
     utf32: bool,
     avg_chunk_size: Option<u32>,
-) -> napi::Result<Vec<Buffer>> {
+) -> napi::Result<Vec<Buffer>, &'static str> {
     let avg = avg_chunk_size.unwrap_or(1024);
     Ok(iscc_lib::alg_cdc_chunks(data.as_ref(), utf32, avg)
-        .map_err(|e| napi::Error::from_reason(e.to_string()))?
+        .map_err(iscc_err)?
         .into_iter()
         .map(Buffer::from)
         .collect())
@@ -363,11 +603,14 @@ pub fn alg_cdc_chunks(
 /// Returns raw bytes of length `bits / 8`. Default `bits` is 64.
 /// Throws if `frame_sigs` is empty.
 #[napi(js_name = "soft_hash_video_v0")]
-pub fn soft_hash_video_v0(frame_sigs: Vec<Vec<i32>>, bits: Option<u32>) -> napi::Result<Buffer> {
+pub fn soft_hash_video_v0(
+    frame_sigs: Vec<Vec<i32>>,
+    bits: Option<u32>,
+) -> napi::Result<Buffer, &'static str> {
     let bits = bits.unwrap_or(64);
     iscc_lib::soft_hash_video_v0(&frame_sigs, bits)
         .map(|r| r.into())
-        .map_err(|e| napi::Error::from_reason(e.to_string()))
+        .map_err(iscc_err)
 }
 
 // ── Streaming hashers ─────────────────────────────────────────────────────────
@@ -401,10 +644,10 @@ impl NapiDataHasher {
 
     /// Push data into the hasher.
     #[napi]
-    pub fn update(&mut self, data: Buffer) -> napi::Result<()> {
+    pub fn update(&mut self, data: Buffer) -> napi::Result<(), &'static str> {
         self.inner
             .as_mut()
-            .ok_or_else(|| napi::Error::from_reason("DataHasher already finalized"))
+            .ok_or_else(|| napi::Error::new("invalid_input", "DataHasher already finalized"))
             .map(|h| h.update(&data))
     }
 
@@ -413,15 +656,15 @@ impl NapiDataHasher {
     /// After calling `finalize`, subsequent calls to `update` or `finalize`
     /// will throw. Default `bits` is 64.
     #[napi(js_name = "finalize")]
-    pub fn finalize_code(&mut self, bits: Option<u32>) -> napi::Result<String> {
+    pub fn finalize_code(&mut self, bits: Option<u32>) -> napi::Result<String, &'static str> {
         let hasher = self
             .inner
             .take()
-            .ok_or_else(|| napi::Error::from_reason("DataHasher already finalized"))?;
+            .ok_or_else(|| napi::Error::new("invalid_input", "DataHasher already finalized"))?;
         hasher
             .finalize(bits.unwrap_or(64))
             .map(|r| r.iscc)
-            .map_err(|e| napi::Error::from_reason(e.to_string()))
+            .map_err(iscc_err)
     }
 }
 
@@ -454,10 +697,10 @@ impl NapiInstanceHasher {
 
     /// Push data into the hasher.
     #[napi]
-    pub fn update(&mut self, data: Buffer) -> napi::Result<()> {
+    pub fn update(&mut self, data: Buffer) -> napi::Result<(), &'static str> {
         self.inner
             .as_mut()
-            .ok_or_else(|| napi::Error::from_reason("InstanceHasher already finalized"))
+            .ok_or_else(|| napi::Error::new("invalid_input", "InstanceHasher already finalized"))
             .map(|h| h.update(&data))
     }
 
@@ -466,14 +709,76 @@ impl NapiInstanceHasher {
     /// After calling `finalize`, subsequent calls to `update` or `finalize`
     /// will throw. Default `bits` is 64.
     #[napi(js_name = "finalize")]
-    pub fn finalize_code(&mut self, bits: Option<u32>) -> napi::Result<String> {
+    pub fn finalize_code(&mut self, bits: Option<u32>) -> napi::Result<String, &'static str> {
         let hasher = self
             .inner
             .take()
-            .ok_or_else(|| napi::Error::from_reason("InstanceHasher already finalized"))?;
+            .ok_or_else(|| napi::Error::new("invalid_input", "InstanceHasher already finalized"))?;
         hasher
             .finalize(bits.unwrap_or(64))
             .map(|r| r.iscc)
-            .map_err(|e| napi::Error::from_reason(e.to_string()))
+            .map_err(iscc_err)
+    }
+}
+
+/// Streaming ISCC-SUM generator.
+///
+/// Feeds data to both a Data-Code and an Instance-Code hasher to produce
+/// results identical to `gen_sum_code_v0`. Follows the
+/// `new() → update() → finalize()` pattern.
+#[napi(js_name = "SumHasher")]
+pub struct NapiSumHasher {
+    inner: Option<iscc_lib::SumHasher>,
+}
+
+impl Default for NapiSumHasher {
+    /// Create a new `NapiSumHasher` (delegates to `new()`).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[napi]
+impl NapiSumHasher {
+    /// Create a new `SumHasher`.
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: Some(iscc_lib::SumHasher::new()),
+        }
+    }
+
+    /// Push data into the hasher.
+    #[napi]
+    pub fn update(&mut self, data: Buffer) -> napi::Result<(), &'static str> {
+        self.inner
+            .as_mut()
+            .ok_or_else(|| napi::Error::new("invalid_input", "SumHasher already finalized"))
+            .map(|h| h.update(&data))
+    }
+
+    /// Consume the hasher and produce an ISCC-SUM result.
+    ///
+    /// After calling `finalize`, subsequent calls to `update` or `finalize`
+    /// will throw. Defaults: `bits` 64, `wide` false, `add_units` false.
+    #[napi(js_name = "finalize")]
+    pub fn finalize_code(
+        &mut self,
+        bits: Option<u32>,
+        wide: Option<bool>,
+        add_units: Option<bool>,
+    ) -> napi::Result<NapiSumCodeResult, &'static str> {
+        let hasher = self
+            .inner
+            .take()
+            .ok_or_else(|| napi::Error::new("invalid_input", "SumHasher already finalized"))?;
+        hasher
+            .finalize(
+                bits.unwrap_or(64),
+                wide.unwrap_or(false),
+                add_units.unwrap_or(false),
+            )
+            .map(NapiSumCodeResult::from)
+            .map_err(iscc_err)
     }
 }
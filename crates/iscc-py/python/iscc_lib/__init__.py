@@ -5,7 +5,7 @@ from __future__ import annotations
 import enum
 import json as _json
 import os
-from collections.abc import Sequence
+from collections.abc import Callable, Sequence
 from importlib.metadata import version
 from types import SimpleNamespace
 from typing import BinaryIO
@@ -18,16 +18,33 @@ from iscc_lib._lowlevel import (
     META_TRIM_META as META_TRIM_META,
     META_TRIM_NAME as META_TRIM_NAME,
     TEXT_NGRAM_SIZE as TEXT_NGRAM_SIZE,
+    Base32Error as Base32Error,
+    CancelledError as CancelledError,
+    CancelToken as CancelToken,
     DataHasher as _DataHasher,
+    HeaderError as HeaderError,
     InstanceHasher as _InstanceHasher,
+    InvalidBitsError as InvalidBitsError,
+    InvalidInputError as InvalidInputError,
+    InvalidJsonError as InvalidJsonError,
+    IsccError as IsccError,
+    IsccIoError as IsccIoError,
+    MetaTooLargeError as MetaTooLargeError,
+    SumHasher as _SumHasher,
+    TruncatedError as TruncatedError,
+    UnsupportedError as UnsupportedError,
+    ValidationError as ValidationError,
     alg_cdc_chunks as alg_cdc_chunks,
     alg_minhash_256 as alg_minhash_256,
     alg_simhash as alg_simhash,
     conformance_selftest as conformance_selftest,
+    decode_chromaprint as decode_chromaprint,
     encode_base64 as encode_base64,
     encode_component as encode_component,
+    gen_audio_code_from_chromaprint as _gen_audio_code_from_chromaprint,
     gen_audio_code_v0 as _gen_audio_code_v0,
     gen_data_code_v0 as _gen_data_code_v0,
+    gen_image_code_from_pixels as _gen_image_code_from_pixels,
     gen_image_code_v0 as _gen_image_code_v0,
     gen_instance_code_v0 as _gen_instance_code_v0,
     gen_iscc_code_v0 as _gen_iscc_code_v0,
@@ -35,6 +52,7 @@ from iscc_lib._lowlevel import (
     gen_mixed_code_v0 as _gen_mixed_code_v0,
     gen_sum_code_v0 as _gen_sum_code_v0,
     gen_text_code_v0 as _gen_text_code_v0,
+    gen_video_code_from_signature_file as _gen_video_code_from_signature_file,
     gen_video_code_v0 as _gen_video_code_v0,
     iscc_decode as _iscc_decode,
     iscc_decompose as iscc_decompose,
@@ -135,6 +153,7 @@ class TextCodeResult(IsccResult):
 
     iscc: str
     characters: int
+    features: list[dict[str, int]]
 
 
 class ImageCodeResult(IsccResult):
@@ -208,9 +227,17 @@ def gen_meta_code_v0(
     return MetaCodeResult(_gen_meta_code_v0(name, description, meta, bits))
 
 
-def gen_text_code_v0(text: str, bits: int = 64) -> TextCodeResult:
-    """Generate an ISCC Text-Code from plain text content."""
-    return TextCodeResult(_gen_text_code_v0(text, bits))
+def gen_text_code_v0(
+    text: str, bits: int = 64, granular: bool = False
+) -> TextCodeResult:
+    """Generate an ISCC Text-Code from plain text content.
+
+    With ``granular=True`` the result also carries ``features``: one dict per
+    content-defined chunk of the collapsed text with its ``simprint``, its
+    ``offset``/``size`` in the collapsed text and its ``source_offset``/
+    ``source_size`` in the original input (all in characters).
+    """
+    return TextCodeResult(_gen_text_code_v0(text, bits, granular))
 
 
 def gen_image_code_v0(
@@ -222,11 +249,39 @@ def gen_image_code_v0(
     return ImageCodeResult(_gen_image_code_v0(pixels, bits))
 
 
+def gen_image_code_from_pixels(
+    width: int,
+    height: int,
+    format: str,
+    pixels: bytes | bytearray | memoryview,
+    bits: int = 64,
+) -> ImageCodeResult:
+    """Generate an ISCC Image-Code from a raw pixel buffer of any size.
+
+    ``format`` is a Pillow mode name (``"L"``, ``"LA"``, ``"RGB"``, ``"RGBA"``).
+    Any buffer works, e.g. a ``(height, width, channels)`` uint8 numpy array.
+    """
+    if not isinstance(pixels, bytes):
+        pixels = bytes(memoryview(pixels))
+    return ImageCodeResult(
+        _gen_image_code_from_pixels(width, height, format, pixels, bits)
+    )
+
+
 def gen_audio_code_v0(cv: list[int], bits: int = 64) -> AudioCodeResult:
     """Generate an ISCC Audio-Code from a Chromaprint feature vector."""
     return AudioCodeResult(_gen_audio_code_v0(cv, bits))
 
 
+def gen_audio_code_from_chromaprint(fp: str, bits: int = 64) -> AudioCodeResult:
+    """Generate an ISCC Audio-Code from ``fpcalc`` output.
+
+    Accepts the compressed fingerprint, raw integers (``fpcalc -raw``) or the
+    complete plain or ``-json`` output of ``fpcalc``.
+    """
+    return AudioCodeResult(_gen_audio_code_from_chromaprint(fp, bits))
+
+
 def gen_video_code_v0(
     frame_sigs: Sequence[Sequence[int]], bits: int = 64
 ) -> VideoCodeResult:
@@ -234,6 +289,16 @@ def gen_video_code_v0(
     return VideoCodeResult(_gen_video_code_v0(frame_sigs, bits))
 
 
+def gen_video_code_from_signature_file(
+    path: str | os.PathLike, bits: int = 64
+) -> VideoCodeResult:
+    """Generate an ISCC Video-Code from an FFmpeg ``signature`` filter output file.
+
+    Both ``format=binary`` and ``format=xml`` MPEG-7 signatures are accepted.
+    """
+    return VideoCodeResult(_gen_video_code_from_signature_file(os.fspath(path), bits))
+
+
 def gen_mixed_code_v0(codes: list[str], bits: int = 64) -> MixedCodeResult:
     """Generate an ISCC Mixed-Code from multiple Content-Code strings."""
     return MixedCodeResult(_gen_mixed_code_v0(codes, bits))
@@ -277,9 +342,18 @@ def gen_sum_code_v0(
     bits: int = 64,
     wide: bool = False,
     add_units: bool = False,
+    progress: Callable[[int, int], object] | None = None,
+    cancel: CancelToken | None = None,
 ) -> SumCodeResult:
-    """Generate Data-Code + Instance-Code + ISCC-CODE from a file path in a single pass."""
-    return SumCodeResult(_gen_sum_code_v0(os.fspath(path), bits, wide, add_units))
+    """Generate Data-Code + Instance-Code + ISCC-CODE from a file path in a single pass.
+
+    ``progress(done, total)`` is called after each read block (``total`` is 0
+    when the size is unknown). Calling ``cancel.cancel()`` from another thread
+    stops hashing with ``CancelledError``.
+    """
+    return SumCodeResult(
+        _gen_sum_code_v0(os.fspath(path), bits, wide, add_units, progress, cancel)
+    )
 
 
 # ── Streaming hashers ──────────────────────────────────────────────────────
@@ -345,6 +419,38 @@ class InstanceHasher:
         return InstanceCodeResult(self._inner.finalize(bits))
 
 
+class SumHasher:
+    """Streaming ISCC-SUM generator.
+
+    Feeds data to both a Data-Code and an Instance-Code hasher to produce
+    results identical to ``gen_sum_code_v0`` without needing a file path.
+    """
+
+    def __init__(
+        self, data: bytes | bytearray | memoryview | BinaryIO | None = None
+    ) -> None:
+        """Create a new SumHasher with optional initial data."""
+        self._inner = _SumHasher()
+        if data is not None:
+            self.update(data)
+
+    def update(self, data: bytes | bytearray | memoryview | BinaryIO) -> None:
+        """Push data into the hasher."""
+        if not isinstance(data, (bytes, bytearray, memoryview)):
+            while chunk := data.read(_CHUNK_SIZE):
+                self._inner.update(chunk)
+        else:
+            if not isinstance(data, bytes):
+                data = bytes(data)
+            self._inner.update(data)
+
+    def finalize(
+        self, bits: int = 64, wide: bool = False, add_units: bool = False
+    ) -> SumCodeResult:
+        """Consume the hasher and return an ISCC-SUM result."""
+        return SumCodeResult(self._inner.finalize(bits, wide, add_units))
+
+
 __all__ = [
     "__version__",
     "IO_READ_SIZE",
@@ -356,7 +462,20 @@ __all__ = [
     "TEXT_NGRAM_SIZE",
     "VS",
     "IsccResult",
+    "IsccError",
+    "InvalidInputError",
+    "IsccIoError",
+    "Base32Error",
+    "HeaderError",
+    "TruncatedError",
+    "InvalidBitsError",
+    "MetaTooLargeError",
+    "InvalidJsonError",
+    "UnsupportedError",
+    "ValidationError",
+    "CancelledError",
     "AudioCodeResult",
+    "CancelToken",
     "DataCodeResult",
     "DataHasher",
     "ImageCodeResult",
@@ -366,6 +485,7 @@ __all__ = [
     "MetaCodeResult",
     "MixedCodeResult",
     "SumCodeResult",
+    "SumHasher",
     "TextCodeResult",
     "VideoCodeResult",
     "alg_cdc_chunks",
@@ -373,10 +493,13 @@ __all__ = [
     "alg_simhash",
     "conformance_selftest",
     "core_opts",
+    "decode_chromaprint",
     "encode_base64",
     "encode_component",
+    "gen_audio_code_from_chromaprint",
     "gen_audio_code_v0",
     "gen_data_code_v0",
+    "gen_image_code_from_pixels",
     "gen_image_code_v0",
     "gen_instance_code_v0",
     "gen_iscc_code_v0",
@@ -384,6 +507,7 @@ __all__ = [
     "gen_mixed_code_v0",
     "gen_sum_code_v0",
     "gen_text_code_v0",
+    "gen_video_code_from_signature_file",
     "gen_video_code_v0",
     "iscc_decode",
     "iscc_decompose",
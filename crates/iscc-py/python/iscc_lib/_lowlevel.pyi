@@ -1,6 +1,6 @@
 """Type stubs for the native Rust extension module `iscc_lib._lowlevel`."""
 
-from collections.abc import Sequence
+from collections.abc import Callable, Sequence
 from typing import Any
 
 META_TRIM_NAME: int
@@ -18,6 +18,58 @@ IO_READ_SIZE: int
 TEXT_NGRAM_SIZE: int
 """Character n-gram width for text content features (13)."""
 
+class IsccError(ValueError):
+    """Base class for all errors raised by iscc_lib."""
+
+class InvalidInputError(IsccError):
+    """Invalid input."""
+
+class IsccIoError(IsccError):
+    """I/O failure."""
+
+class Base32Error(IsccError):
+    """Invalid base32 data."""
+
+class HeaderError(IsccError):
+    """Invalid ISCC header."""
+
+class TruncatedError(IsccError):
+    """Truncated ISCC body."""
+
+class InvalidBitsError(IsccError):
+    """Unsupported bit length."""
+
+class MetaTooLargeError(IsccError):
+    """Meta payload too large."""
+
+class InvalidJsonError(IsccError):
+    """Invalid JSON input."""
+
+class UnsupportedError(IsccError):
+    """Unsupported feature."""
+
+class ValidationError(IsccError):
+    """Invalid ISCC string."""
+
+class CancelledError(IsccError):
+    """Operation cancelled."""
+
+class CancelToken:
+    """Cancellation flag for ``gen_sum_code_v0``, safe to set from any thread."""
+
+    def __init__(self) -> None:
+        """Create a token that is not cancelled."""
+        ...
+
+    def cancel(self) -> None:
+        """Request cancellation of every operation using this token."""
+        ...
+
+    @property
+    def is_cancelled(self) -> bool:
+        """Whether ``cancel`` has been called."""
+        ...
+
 def encode_component(
     mtype: int, stype: int, version: int, bit_length: int, digest: bytes
 ) -> str:
@@ -181,7 +233,9 @@ def gen_meta_code_v0(
     """
     ...
 
-def gen_text_code_v0(text: str, bits: int = 64) -> dict[str, Any]:
+def gen_text_code_v0(
+    text: str, bits: int = 64, granular: bool = False
+) -> dict[str, Any]:
     """Generate an ISCC Text-Code from plain text content.
 
     Produces a Content-Code for text by collapsing the input, extracting
@@ -190,7 +244,10 @@ def gen_text_code_v0(text: str, bits: int = 64) -> dict[str, Any]:
 
     :param text: Plain text content to fingerprint.
     :param bits: Bit length of the code body (default 64).
-    :return: Dict with ``iscc`` and ``characters`` keys.
+    :param granular: If True, include per-chunk ``features``.
+    :return: Dict with ``iscc`` and ``characters`` keys, plus ``features``
+        (list of dicts with ``offset``, ``size``, ``simprint``,
+        ``source_offset`` and ``source_size``) when ``granular`` is True.
     """
     ...
 
@@ -206,6 +263,24 @@ def gen_image_code_v0(pixels: bytes, bits: int = 64) -> dict[str, Any]:
     """
     ...
 
+def gen_image_code_from_pixels(
+    width: int, height: int, format: str, pixels: bytes, bits: int = 64
+) -> dict[str, Any]:
+    """Generate an ISCC Image-Code from a raw pixel buffer of any size.
+
+    Flattens transparency onto white, trims a uniform border, converts to
+    grayscale and resizes to 32×32 like iscc-sdk before hashing.
+
+    :param width: Image width in pixels.
+    :param height: Image height in pixels.
+    :param format: Pillow mode name: ``"L"``, ``"LA"``, ``"RGB"`` or ``"RGBA"``.
+    :param pixels: Row-major pixel bytes (``width * height * channels``).
+    :param bits: Bit length of the code body (default 64).
+    :return: Dict with ``iscc`` key.
+    :raises InvalidInputError: On an unknown format or wrong buffer size.
+    """
+    ...
+
 def gen_audio_code_v0(cv: list[int], bits: int = 64) -> dict[str, Any]:
     """Generate an ISCC Audio-Code from a Chromaprint feature vector.
 
@@ -218,6 +293,26 @@ def gen_audio_code_v0(cv: list[int], bits: int = 64) -> dict[str, Any]:
     """
     ...
 
+def gen_audio_code_from_chromaprint(fp: str, bits: int = 64) -> dict[str, Any]:
+    """Generate an ISCC Audio-Code from ``fpcalc`` output.
+
+    :param fp: Compressed base64 fingerprint, comma-separated raw integers,
+        or the complete plain or ``-json`` output of ``fpcalc``.
+    :param bits: Bit length of the code body (default 64).
+    :return: Dict with ``iscc`` key.
+    :raises InvalidInputError: If the fingerprint cannot be decoded.
+    """
+    ...
+
+def decode_chromaprint(fp: str) -> list[int]:
+    """Decode a compressed base64 Chromaprint fingerprint into its raw values.
+
+    :param fp: Compressed fingerprint as printed by ``fpcalc``.
+    :return: Signed 32-bit fingerprint values.
+    :raises InvalidInputError: If the fingerprint is malformed.
+    """
+    ...
+
 def gen_video_code_v0(
     frame_sigs: Sequence[Sequence[int]], bits: int = 64
 ) -> dict[str, Any]:
@@ -233,6 +328,17 @@ def gen_video_code_v0(
     """
     ...
 
+def gen_video_code_from_signature_file(path: str, bits: int = 64) -> dict[str, Any]:
+    """Generate an ISCC Video-Code from an FFmpeg ``signature`` filter output file.
+
+    :param path: Path to a binary or XML MPEG-7 video signature.
+    :param bits: Bit length of the code body (default 64).
+    :return: Dict with ``iscc`` key.
+    :raises IsccIoError: If the file cannot be read.
+    :raises InvalidInputError: If the signature is malformed or has no frames.
+    """
+    ...
+
 def gen_mixed_code_v0(codes: list[str], bits: int = 64) -> dict[str, Any]:
     """Generate an ISCC Mixed-Code from multiple Content-Code strings.
 
@@ -324,7 +430,12 @@ def gen_iscc_code_v0(codes: list[str], wide: bool = False) -> dict[str, Any]:
     ...
 
 def gen_sum_code_v0(
-    path: str, bits: int = 64, wide: bool = False, add_units: bool = False
+    path: str,
+    bits: int = 64,
+    wide: bool = False,
+    add_units: bool = False,
+    progress: Callable[[int, int], object] | None = None,
+    cancel: CancelToken | None = None,
 ) -> dict[str, Any]:
     """Generate Data-Code + Instance-Code + ISCC-CODE from a file path in a single pass.
 
@@ -333,6 +444,11 @@ def gen_sum_code_v0(
     :param wide: Whether to produce a wide (256-bit) ISCC-CODE (default False).
     :param add_units: Whether to include individual Data-Code and Instance-Code
         ISCC strings in the result (default False).
+    :param progress: Called as ``progress(done, total)`` after each read block;
+        ``total`` is 0 when the size is unknown. Exceptions it raises stop
+        hashing and propagate.
+    :param cancel: Token checked before each read block.
+    :raises CancelledError: If ``cancel`` was cancelled before hashing finished.
     :return: Dict with ``iscc``, ``datahash``, ``filesize`` keys and optionally
         ``units`` (list of Data-Code and Instance-Code strings) when ``add_units``
         is True.
@@ -439,3 +555,35 @@ class InstanceHasher:
         :raises ValueError: If the hasher has already been finalized.
         """
         ...
+
+class SumHasher:
+    """Streaming ISCC-SUM generator backed by Rust.
+
+    Feeds data to both a Data-Code and an Instance-Code hasher to produce
+    results identical to ``gen_sum_code_v0``.
+    """
+
+    def __init__(self) -> None:
+        """Create a new SumHasher."""
+        ...
+
+    def update(self, data: bytes) -> None:
+        """Push data into the hasher.
+
+        :param data: Raw binary data to process.
+        :raises ValueError: If the hasher has already been finalized.
+        """
+        ...
+
+    def finalize(
+        self, bits: int = 64, wide: bool = False, add_units: bool = False
+    ) -> dict[str, Any]:
+        """Consume the hasher and produce an ISCC-SUM result dict.
+
+        :param bits: Bit length of the Data-Code and Instance-Code (default 64).
+        :param wide: Produce a 256-bit ISCC-CODE body (default False).
+        :param add_units: Include the unit ISCC strings under ``units``.
+        :return: Dict with ``iscc``, ``datahash``, ``filesize``, and optional ``units`` keys.
+        :raises ValueError: If the hasher has already been finalized.
+        """
+        ...
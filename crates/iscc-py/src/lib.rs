@@ -11,6 +11,69 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyList};
 
+pyo3::create_exception!(
+    _lowlevel,
+    IsccError,
+    PyValueError,
+    "Base class for all errors raised by iscc_lib."
+);
+pyo3::create_exception!(_lowlevel, InvalidInputError, IsccError, "Invalid input.");
+pyo3::create_exception!(_lowlevel, IsccIoError, IsccError, "I/O failure.");
+pyo3::create_exception!(_lowlevel, Base32Error, IsccError, "Invalid base32 data.");
+pyo3::create_exception!(_lowlevel, HeaderError, IsccError, "Invalid ISCC header.");
+pyo3::create_exception!(_lowlevel, TruncatedError, IsccError, "Truncated ISCC body.");
+pyo3::create_exception!(
+    _lowlevel,
+    InvalidBitsError,
+    IsccError,
+    "Unsupported bit length."
+);
+pyo3::create_exception!(
+    _lowlevel,
+    MetaTooLargeError,
+    IsccError,
+    "Meta payload too large."
+);
+pyo3::create_exception!(
+    _lowlevel,
+    InvalidJsonError,
+    IsccError,
+    "Invalid JSON input."
+);
+pyo3::create_exception!(
+    _lowlevel,
+    UnsupportedError,
+    IsccError,
+    "Unsupported feature."
+);
+pyo3::create_exception!(
+    _lowlevel,
+    ValidationError,
+    IsccError,
+    "Invalid ISCC string."
+);
+pyo3::create_exception!(_lowlevel, CancelledError, IsccError, "Operation cancelled.");
+
+/// Map a core `IsccError` to the matching Python exception subclass.
+fn iscc_err(e: iscc_lib::IsccError) -> PyErr {
+    use iscc_lib::IsccError as E;
+    let msg = e.to_string();
+    match e {
+        E::InvalidInput(_) => InvalidInputError::new_err(msg),
+        E::Io(_) => IsccIoError::new_err(msg),
+        E::Base32(_) => Base32Error::new_err(msg),
+        E::Header(_) => HeaderError::new_err(msg),
+        E::Truncated { .. } => TruncatedError::new_err(msg),
+        E::InvalidBits { .. } => InvalidBitsError::new_err(msg),
+        E::MetaTooLarge { .. } => MetaTooLargeError::new_err(msg),
+        E::InvalidJson(_) => InvalidJsonError::new_err(msg),
+        E::Unsupported(_) => UnsupportedError::new_err(msg),
+        E::Validation(_) => ValidationError::new_err(msg),
+        E::Cancelled => CancelledError::new_err(msg),
+        _ => IsccError::new_err(msg),
+    }
+}
+
 /// Convert a Python sequence to a PyList, passing lists through unchanged.
 fn to_pylist<'py>(py: Python<'py>, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
     if let Ok(list) = obj.downcast::<PyList>() {
@@ -113,8 +176,7 @@ fn gen_meta_code_v0(
     meta: Option<&str>,
     bits: u32,
 ) -> PyResult<PyObject> {
-    let r = iscc_lib::gen_meta_code_v0(name, description, meta, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_meta_code_v0(name, description, meta, bits).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     dict.set_item("name", r.name)?;
@@ -130,15 +192,33 @@ fn gen_meta_code_v0(
 
 /// Generate a Text-Code from plain text content.
 ///
-/// Returns a dict with keys: `iscc`, `characters`.
+/// Returns a dict with keys: `iscc`, `characters`, and `features` when
+/// `granular` is true.
 #[pyfunction]
-#[pyo3(signature = (text, bits=64))]
-fn gen_text_code_v0(py: Python<'_>, text: &str, bits: u32) -> PyResult<PyObject> {
-    let r =
-        iscc_lib::gen_text_code_v0(text, bits).map_err(|e| PyValueError::new_err(e.to_string()))?;
+#[pyo3(signature = (text, bits=64, granular=false))]
+fn gen_text_code_v0(py: Python<'_>, text: &str, bits: u32, granular: bool) -> PyResult<PyObject> {
+    let r = if granular {
+        iscc_lib::gen_text_code_v0_granular(text, bits)
+    } else {
+        iscc_lib::gen_text_code_v0(text, bits)
+    }
+    .map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     dict.set_item("characters", r.characters)?;
+    if let Some(features) = r.features {
+        let list = PyList::empty(py);
+        for f in features {
+            let item = PyDict::new(py);
+            item.set_item("offset", f.offset)?;
+            item.set_item("size", f.size)?;
+            item.set_item("simprint", f.simprint)?;
+            item.set_item("source_offset", f.source_offset)?;
+            item.set_item("source_size", f.source_size)?;
+            list.append(item)?;
+        }
+        dict.set_item("features", list)?;
+    }
     Ok(dict.into())
 }
 
@@ -148,8 +228,29 @@ fn gen_text_code_v0(py: Python<'_>, text: &str, bits: u32) -> PyResult<PyObject>
 #[pyfunction]
 #[pyo3(signature = (pixels, bits=64))]
 fn gen_image_code_v0(py: Python<'_>, pixels: &[u8], bits: u32) -> PyResult<PyObject> {
-    let r = iscc_lib::gen_image_code_v0(pixels, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_image_code_v0(pixels, bits).map_err(iscc_err)?;
+    let dict = PyDict::new(py);
+    dict.set_item("iscc", r.iscc)?;
+    Ok(dict.into())
+}
+
+/// Generate an Image-Code from a raw pixel buffer of any size.
+///
+/// `format` is a Pillow mode name (`"L"`, `"LA"`, `"RGB"` or `"RGBA"`).
+/// Returns a dict with key: `iscc`.
+#[pyfunction]
+#[pyo3(signature = (width, height, format, pixels, bits=64))]
+fn gen_image_code_from_pixels(
+    py: Python<'_>,
+    width: u32,
+    height: u32,
+    format: &str,
+    pixels: &[u8],
+    bits: u32,
+) -> PyResult<PyObject> {
+    let format = format.parse().map_err(iscc_err)?;
+    let r = iscc_lib::gen_image_code_from_pixels(width, height, format, pixels, bits)
+        .map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     Ok(dict.into())
@@ -161,13 +262,31 @@ fn gen_image_code_v0(py: Python<'_>, pixels: &[u8], bits: u32) -> PyResult<PyObj
 #[pyfunction]
 #[pyo3(signature = (cv, bits=64))]
 fn gen_audio_code_v0(py: Python<'_>, cv: Vec<i32>, bits: u32) -> PyResult<PyObject> {
-    let r =
-        iscc_lib::gen_audio_code_v0(&cv, bits).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_audio_code_v0(&cv, bits).map_err(iscc_err)?;
+    let dict = PyDict::new(py);
+    dict.set_item("iscc", r.iscc)?;
+    Ok(dict.into())
+}
+
+/// Generate an Audio-Code from `fpcalc` output.
+///
+/// Accepts the compressed fingerprint, raw integers, or the full plain or
+/// JSON output of `fpcalc`. Returns a dict with key: `iscc`.
+#[pyfunction]
+#[pyo3(signature = (fp, bits=64))]
+fn gen_audio_code_from_chromaprint(py: Python<'_>, fp: &str, bits: u32) -> PyResult<PyObject> {
+    let r = iscc_lib::gen_audio_code_from_chromaprint(fp, bits).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     Ok(dict.into())
 }
 
+/// Decode a compressed Chromaprint fingerprint into its raw values.
+#[pyfunction]
+fn decode_chromaprint(fp: &str) -> PyResult<Vec<i32>> {
+    iscc_lib::decode_chromaprint(fp).map_err(iscc_err)
+}
+
 /// Generate a Video-Code from frame signature data.
 ///
 /// Uses direct CPython C API for fast extraction from nested Python lists.
@@ -181,8 +300,20 @@ fn gen_video_code_v0(
 ) -> PyResult<PyObject> {
     let (flat, frame_len) = extract_frame_sigs(py, &frame_sigs)?;
     let frame_slices: Vec<&[i32]> = flat.chunks_exact(frame_len).collect();
-    let r = iscc_lib::gen_video_code_v0(&frame_slices, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_video_code_v0(&frame_slices, bits).map_err(iscc_err)?;
+    let dict = PyDict::new(py);
+    dict.set_item("iscc", r.iscc)?;
+    Ok(dict.into())
+}
+
+/// Generate a Video-Code from an FFmpeg `signature` filter output file.
+///
+/// Reads binary or XML MPEG-7 signatures. Returns a dict with key: `iscc`.
+#[pyfunction]
+#[pyo3(signature = (path, bits=64))]
+fn gen_video_code_from_signature_file(py: Python<'_>, path: &str, bits: u32) -> PyResult<PyObject> {
+    let r = iscc_lib::gen_video_code_from_signature_file(std::path::Path::new(path), bits)
+        .map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     Ok(dict.into())
@@ -205,8 +336,7 @@ fn gen_video_code_v0_flat(
 ) -> PyResult<PyObject> {
     let frames = flat_bytes_to_frames(data, num_frames, frame_len)?;
     let frame_refs: Vec<&[i32]> = frames.iter().map(|f| f.as_slice()).collect();
-    let r = iscc_lib::gen_video_code_v0(&frame_refs, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_video_code_v0(&frame_refs, bits).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     Ok(dict.into())
@@ -226,8 +356,7 @@ fn soft_hash_video_v0_flat(
 ) -> PyResult<PyObject> {
     let frames = flat_bytes_to_frames(data, num_frames, frame_len)?;
     let frame_refs: Vec<&[i32]> = frames.iter().map(|f| f.as_slice()).collect();
-    let result = iscc_lib::soft_hash_video_v0(&frame_refs, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = iscc_lib::soft_hash_video_v0(&frame_refs, bits).map_err(iscc_err)?;
     Ok(PyBytes::new(py, &result).into())
 }
 
@@ -276,8 +405,7 @@ fn flat_bytes_to_frames(
 #[pyo3(signature = (codes, bits=64))]
 fn gen_mixed_code_v0(py: Python<'_>, codes: Vec<String>, bits: u32) -> PyResult<PyObject> {
     let refs: Vec<&str> = codes.iter().map(|s| s.as_str()).collect();
-    let r = iscc_lib::gen_mixed_code_v0(&refs, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_mixed_code_v0(&refs, bits).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     dict.set_item("parts", r.parts)?;
@@ -290,8 +418,7 @@ fn gen_mixed_code_v0(py: Python<'_>, codes: Vec<String>, bits: u32) -> PyResult<
 #[pyfunction]
 #[pyo3(signature = (data, bits=64))]
 fn gen_data_code_v0(py: Python<'_>, data: &[u8], bits: u32) -> PyResult<PyObject> {
-    let r =
-        iscc_lib::gen_data_code_v0(data, bits).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_data_code_v0(data, bits).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     Ok(dict.into())
@@ -303,8 +430,7 @@ fn gen_data_code_v0(py: Python<'_>, data: &[u8], bits: u32) -> PyResult<PyObject
 #[pyfunction]
 #[pyo3(signature = (data, bits=64))]
 fn gen_instance_code_v0(py: Python<'_>, data: &[u8], bits: u32) -> PyResult<PyObject> {
-    let r = iscc_lib::gen_instance_code_v0(data, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_instance_code_v0(data, bits).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     dict.set_item("datahash", r.datahash)?;
@@ -319,8 +445,7 @@ fn gen_instance_code_v0(py: Python<'_>, data: &[u8], bits: u32) -> PyResult<PyOb
 #[pyo3(signature = (codes, wide=false))]
 fn gen_iscc_code_v0(py: Python<'_>, codes: Vec<String>, wide: bool) -> PyResult<PyObject> {
     let refs: Vec<&str> = codes.iter().map(|s| s.as_str()).collect();
-    let r = iscc_lib::gen_iscc_code_v0(&refs, wide)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let r = iscc_lib::gen_iscc_code_v0(&refs, wide).map_err(iscc_err)?;
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     Ok(dict.into())
@@ -330,17 +455,69 @@ fn gen_iscc_code_v0(py: Python<'_>, codes: Vec<String>, wide: bool) -> PyResult<
 ///
 /// Returns a dict with keys: `iscc`, `datahash`, `filesize`, and optionally
 /// `units` (list of Data-Code and Instance-Code strings) when `add_units` is true.
+///
+/// With `progress` or `cancel` set, the file is hashed with the GIL released.
+/// `progress(done, total)` is called after each read block and `cancel` is
+/// checked before each one. An exception raised by `progress` stops hashing
+/// and is re-raised.
 #[pyfunction]
-#[pyo3(signature = (path, bits=64, wide=false, add_units=false))]
+#[pyo3(signature = (path, bits=64, wide=false, add_units=false, progress=None, cancel=None))]
 fn gen_sum_code_v0(
     py: Python<'_>,
     path: &str,
     bits: u32,
     wide: bool,
     add_units: bool,
+    progress: Option<PyObject>,
+    cancel: Option<PyRef<'_, PyCancelToken>>,
 ) -> PyResult<PyObject> {
-    let r = iscc_lib::gen_sum_code_v0(std::path::Path::new(path), bits, wide, add_units)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let path = std::path::Path::new(path);
+    if progress.is_none() && cancel.is_none() {
+        let r = iscc_lib::gen_sum_code_v0(path, bits, wide, add_units).map_err(iscc_err)?;
+        return sum_result_dict(py, r);
+    }
+    let caller = cancel.map(|c| c.inner.clone());
+    // A separate token lets a failing callback stop hashing without
+    // cancelling the caller's token; the caller's cancellation is forwarded.
+    let token = iscc_lib::CancelToken::new();
+    let forward = |token: &iscc_lib::CancelToken| {
+        if caller
+            .as_ref()
+            .is_some_and(iscc_lib::CancelToken::is_cancelled)
+        {
+            token.cancel();
+        }
+    };
+    forward(&token);
+    let mut callback_err: Option<PyErr> = None;
+    let result = py.allow_threads(|| {
+        iscc_lib::gen_sum_code_with_progress(
+            path,
+            bits,
+            wide,
+            add_units,
+            |done, total| {
+                forward(&token);
+                let Some(callback) = &progress else { return };
+                if callback_err.is_some() {
+                    return;
+                }
+                if let Err(e) = Python::with_gil(|py| callback.call1(py, (done, total))) {
+                    callback_err = Some(e);
+                    token.cancel();
+                }
+            },
+            Some(&token),
+        )
+    });
+    if let Some(e) = callback_err {
+        return Err(e);
+    }
+    sum_result_dict(py, result.map_err(iscc_err)?)
+}
+
+/// Convert an ISCC-SUM result into the dict shape returned to Python.
+fn sum_result_dict(py: Python<'_>, r: iscc_lib::SumCodeResult) -> PyResult<PyObject> {
     let dict = PyDict::new(py);
     dict.set_item("iscc", r.iscc)?;
     dict.set_item("datahash", r.datahash)?;
@@ -413,7 +590,7 @@ fn encode_base64(data: &[u8]) -> String {
 #[pyfunction]
 #[pyo3(signature = (iscc_code))]
 fn iscc_decompose(iscc_code: &str) -> PyResult<Vec<String>> {
-    iscc_lib::iscc_decompose(iscc_code).map_err(|e| PyValueError::new_err(e.to_string()))
+    iscc_lib::iscc_decompose(iscc_code).map_err(iscc_err)
 }
 
 /// Encode raw digest components into a base32 ISCC unit string.
@@ -429,8 +606,7 @@ fn encode_component(
     bit_length: u32,
     digest: &[u8],
 ) -> PyResult<String> {
-    iscc_lib::encode_component(mtype, stype, version, bit_length, digest)
-        .map_err(|e| PyValueError::new_err(e.to_string()))
+    iscc_lib::encode_component(mtype, stype, version, bit_length, digest).map_err(iscc_err)
 }
 
 /// Decode an ISCC unit string into header components and raw digest.
@@ -440,8 +616,7 @@ fn encode_component(
 #[pyfunction]
 #[pyo3(signature = (iscc))]
 fn iscc_decode(py: Python<'_>, iscc: &str) -> PyResult<PyObject> {
-    let (mt, st, vs, li, digest) =
-        iscc_lib::iscc_decode(iscc).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let (mt, st, vs, li, digest) = iscc_lib::iscc_decode(iscc).map_err(iscc_err)?;
     Ok((mt, st, vs, li, PyBytes::new(py, &digest))
         .into_pyobject(py)?
         .into())
@@ -454,7 +629,7 @@ fn iscc_decode(py: Python<'_>, iscc: &str) -> PyResult<PyObject> {
 #[pyfunction]
 #[pyo3(signature = (json))]
 fn json_to_data_url(json: &str) -> PyResult<String> {
-    iscc_lib::json_to_data_url(json).map_err(|e| PyValueError::new_err(e.to_string()))
+    iscc_lib::json_to_data_url(json).map_err(iscc_err)
 }
 
 /// Generate sliding window n-grams from a string.
@@ -464,7 +639,7 @@ fn json_to_data_url(json: &str) -> PyResult<String> {
 #[pyfunction]
 #[pyo3(signature = (seq, width))]
 fn sliding_window(seq: &str, width: usize) -> PyResult<Vec<String>> {
-    iscc_lib::sliding_window(seq, width).map_err(|e| PyValueError::new_err(e.to_string()))
+    iscc_lib::sliding_window(seq, width).map_err(iscc_err)
 }
 
 /// Compute a SimHash from a sequence of equal-length hash digests.
@@ -474,7 +649,7 @@ fn sliding_window(seq: &str, width: usize) -> PyResult<Vec<String>> {
 /// zero bytes for empty input. Raises `ValueError` on mismatched digest lengths.
 #[pyfunction]
 fn alg_simhash(hash_digests: Vec<Vec<u8>>) -> PyResult<Vec<u8>> {
-    iscc_lib::alg_simhash(&hash_digests).map_err(|e| PyValueError::new_err(e.to_string()))
+    iscc_lib::alg_simhash(&hash_digests).map_err(iscc_err)
 }
 
 /// Compute a 256-bit MinHash digest from 32-bit integer features.
@@ -494,7 +669,7 @@ fn alg_minhash_256(features: Vec<u32>) -> Vec<u8> {
 #[pyo3(signature = (data, utf32, avg_chunk_size=1024))]
 fn alg_cdc_chunks(data: &[u8], utf32: bool, avg_chunk_size: u32) -> PyResult<Vec<Vec<u8>>> {
     Ok(iscc_lib::alg_cdc_chunks(data, utf32, avg_chunk_size)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?
+        .map_err(iscc_err)?
         .into_iter()
         .map(|c| c.to_vec())
         .collect())
@@ -513,8 +688,7 @@ fn soft_hash_video_v0(
 ) -> PyResult<PyObject> {
     let (flat, frame_len) = extract_frame_sigs(py, &frame_sigs)?;
     let frame_slices: Vec<&[i32]> = flat.chunks_exact(frame_len).collect();
-    let result = iscc_lib::soft_hash_video_v0(&frame_slices, bits)
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = iscc_lib::soft_hash_video_v0(&frame_slices, bits).map_err(iscc_err)?;
     Ok(PyBytes::new(py, &result).into())
 }
 
@@ -553,9 +727,7 @@ impl PyDataHasher {
             .inner
             .take()
             .ok_or_else(|| PyValueError::new_err("DataHasher already finalized"))?;
-        let r = hasher
-            .finalize(bits)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let r = hasher.finalize(bits).map_err(iscc_err)?;
         let dict = PyDict::new(py);
         dict.set_item("iscc", r.iscc)?;
         Ok(dict.into())
@@ -597,9 +769,7 @@ impl PyInstanceHasher {
             .inner
             .take()
             .ok_or_else(|| PyValueError::new_err("InstanceHasher already finalized"))?;
-        let r = hasher
-            .finalize(bits)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let r = hasher.finalize(bits).map_err(iscc_err)?;
         let dict = PyDict::new(py);
         dict.set_item("iscc", r.iscc)?;
         dict.set_item("datahash", r.datahash)?;
@@ -608,14 +778,92 @@ impl PyInstanceHasher {
     }
 }
 
+/// Streaming ISCC-SUM generator.
+///
+/// Feeds data to both a Data-Code and an Instance-Code hasher to produce
+/// results identical to `gen_sum_code_v0`.
+#[pyclass(name = "SumHasher")]
+struct PySumHasher {
+    inner: Option<iscc_lib::SumHasher>,
+}
+
+#[pymethods]
+impl PySumHasher {
+    /// Create a new `SumHasher`.
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: Some(iscc_lib::SumHasher::new()),
+        }
+    }
+
+    /// Push data into the hasher.
+    fn update(&mut self, data: &[u8]) -> PyResult<()> {
+        self.inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("SumHasher already finalized"))?
+            .update(data);
+        Ok(())
+    }
+
+    /// Consume the hasher and produce an ISCC-SUM result dict.
+    #[pyo3(signature = (bits=64, wide=false, add_units=false))]
+    fn finalize(
+        &mut self,
+        py: Python<'_>,
+        bits: u32,
+        wide: bool,
+        add_units: bool,
+    ) -> PyResult<PyObject> {
+        let hasher = self
+            .inner
+            .take()
+            .ok_or_else(|| PyValueError::new_err("SumHasher already finalized"))?;
+        let r = hasher.finalize(bits, wide, add_units).map_err(iscc_err)?;
+        sum_result_dict(py, r)
+    }
+}
+
+/// Cancellation flag for `gen_sum_code_v0`, safe to set from any thread.
+#[pyclass(name = "CancelToken", frozen)]
+struct PyCancelToken {
+    inner: iscc_lib::CancelToken,
+}
+
+#[pymethods]
+impl PyCancelToken {
+    /// Create a token that is not cancelled.
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: iscc_lib::CancelToken::new(),
+        }
+    }
+
+    /// Request cancellation of every operation using this token.
+    fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Whether `cancel` has been called.
+    #[getter]
+    fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
+
 /// Python module `iscc_lib._lowlevel` backed by Rust.
 #[pymodule(name = "_lowlevel")]
 fn iscc_lowlevel(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(gen_meta_code_v0, m)?)?;
     m.add_function(wrap_pyfunction!(gen_text_code_v0, m)?)?;
     m.add_function(wrap_pyfunction!(gen_image_code_v0, m)?)?;
+    m.add_function(wrap_pyfunction!(gen_image_code_from_pixels, m)?)?;
     m.add_function(wrap_pyfunction!(gen_audio_code_v0, m)?)?;
+    m.add_function(wrap_pyfunction!(gen_audio_code_from_chromaprint, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_chromaprint, m)?)?;
     m.add_function(wrap_pyfunction!(gen_video_code_v0, m)?)?;
+    m.add_function(wrap_pyfunction!(gen_video_code_from_signature_file, m)?)?;
     m.add_function(wrap_pyfunction!(gen_mixed_code_v0, m)?)?;
     m.add_function(wrap_pyfunction!(gen_data_code_v0, m)?)?;
     m.add_function(wrap_pyfunction!(gen_instance_code_v0, m)?)?;
@@ -645,5 +893,20 @@ fn iscc_lowlevel(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(soft_hash_video_v0_flat, m)?)?;
     m.add_class::<PyDataHasher>()?;
     m.add_class::<PyInstanceHasher>()?;
+    m.add_class::<PySumHasher>()?;
+    m.add_class::<PyCancelToken>()?;
+    let py = m.py();
+    m.add("IsccError", py.get_type::<IsccError>())?;
+    m.add("InvalidInputError", py.get_type::<InvalidInputError>())?;
+    m.add("IsccIoError", py.get_type::<IsccIoError>())?;
+    m.add("Base32Error", py.get_type::<Base32Error>())?;
+    m.add("HeaderError", py.get_type::<HeaderError>())?;
+    m.add("TruncatedError", py.get_type::<TruncatedError>())?;
+    m.add("InvalidBitsError", py.get_type::<InvalidBitsError>())?;
+    m.add("MetaTooLargeError", py.get_type::<MetaTooLargeError>())?;
+    m.add("InvalidJsonError", py.get_type::<InvalidJsonError>())?;
+    m.add("UnsupportedError", py.get_type::<UnsupportedError>())?;
+    m.add("ValidationError", py.get_type::<ValidationError>())?;
+    m.add("CancelledError", py.get_type::<CancelledError>())?;
     Ok(())
 }
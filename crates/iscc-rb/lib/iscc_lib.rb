@@ -17,6 +17,12 @@ rescue LoadError
 end
 
 module IsccLib
+  # Raised for errors reported by the native library (a RuntimeError subclass).
+  # `code` is a stable identifier such as "truncated", "invalid_bits" or "io".
+  class Error
+    attr_reader :code
+  end
+
   # Base result class providing both Hash-style and attribute-style access.
   class Result < Hash
     # Allow attribute-style access for hash keys (e.g., result.iscc).
@@ -214,4 +220,35 @@ module IsccLib
       InstanceCodeResult[_finalize(bits)]
     end
   end
+
+  # Streaming ISCC-SUM generator (reopens native class).
+  #
+  # Feeds data to both a Data-Code and an Instance-Code hasher to produce
+  # results identical to gen_sum_code_v0 without a file path.
+  #
+  # @example
+  #   hasher = IsccLib::SumHasher.new
+  #   hasher.update(chunk1).update(chunk2)
+  #   result = hasher.finalize(bits: 64, add_units: true)
+  class SumHasher
+    # Push binary data into the hasher.
+    #
+    # @param data [String] binary data
+    # @return [self] for method chaining
+    def update(data)
+      _update(data)
+      self
+    end
+
+    # Consume the hasher and produce an ISCC-SUM result.
+    #
+    # @param bits [Integer] bit length (default: 64)
+    # @param wide [Boolean] 256-bit ISCC-CODE body (default: false)
+    # @param add_units [Boolean] include unit ISCC strings (default: false)
+    # @return [SumCodeResult] hash with iscc, datahash, filesize, and optionally units
+    # @raise [RuntimeError] if called more than once
+    def finalize(bits: 64, wide: false, add_units: false)
+      SumCodeResult[_finalize(bits, wide, add_units)]
+    end
+  end
 end
@@ -13,16 +13,34 @@
 //! - `json_to_data_url`, `conformance_selftest`
 //! - `sliding_window`, `alg_simhash`, `alg_minhash_256`, `alg_cdc_chunks`,
 //!   `soft_hash_video_v0`
-//! - `DataHasher`, `InstanceHasher` (streaming classes)
+//! - `DataHasher`, `InstanceHasher`, `SumHasher` (streaming classes)
 //! - Constants: META_TRIM_NAME, META_TRIM_DESCRIPTION, META_TRIM_META,
 //!   IO_READ_SIZE, TEXT_NGRAM_SIZE
 
-use magnus::{Error, RArray, RHash, RString, Ruby, TryConvert, function, method, prelude::*};
+use magnus::value::Lazy;
+use magnus::{
+    Error, ExceptionClass, RArray, RHash, RModule, RString, Ruby, TryConvert, Value, function,
+    method, prelude::*,
+};
 use std::cell::RefCell;
 
-/// Map an `IsccError` to a Magnus `RuntimeError`.
+/// `IsccLib::Error` (a `RuntimeError` subclass) raised for core `IsccError`s.
+static ISCC_ERROR: Lazy<ExceptionClass> = Lazy::new(|ruby| {
+    ruby.class_object()
+        .const_get::<_, RModule>("IsccLib")
+        .and_then(|module| module.const_get("Error"))
+        .expect("IsccLib::Error is defined in init")
+});
+
+/// Map an `IsccError` to `IsccLib::Error` with the stable code in `@code`.
 fn to_magnus_err(e: iscc_lib::IsccError) -> Error {
-    Error::new(magnus::exception::runtime_error(), e.to_string())
+    let raise = || -> Result<Error, Error> {
+        let ruby = Ruby::get().expect("called from a Ruby thread");
+        let exception = ruby.get_inner(&ISCC_ERROR).new_instance((e.to_string(),))?;
+        let _: Value = exception.funcall("instance_variable_set", ("@code", e.code()))?;
+        Ok(exception.into())
+    };
+    raise().unwrap_or_else(|err| err)
 }
 
 /// Generate a Meta-Code from name and optional metadata.
@@ -176,6 +194,11 @@ fn gen_iscc_code_v0(codes: Vec<String>, wide: bool) -> Result<RHash, Error> {
 fn gen_sum_code_v0(path: String, bits: u32, wide: bool, add_units: bool) -> Result<RHash, Error> {
     let r = iscc_lib::gen_sum_code_v0(std::path::Path::new(&path), bits, wide, add_units)
         .map_err(to_magnus_err)?;
+    sum_result_hash(r)
+}
+
+/// Convert an ISCC-SUM result into the Hash shape returned to Ruby.
+fn sum_result_hash(r: iscc_lib::SumCodeResult) -> Result<RHash, Error> {
     let ruby = Ruby::get().expect("called from Ruby");
     let hash = ruby.hash_new();
     hash.aset("iscc", r.iscc)?;
@@ -287,7 +310,7 @@ fn conformance_selftest() -> bool {
 /// Generate sliding window n-grams from a string.
 ///
 /// Returns overlapping substrings of `width` Unicode characters.
-/// Raises `RuntimeError` if `width < 2`.
+/// Raises `IsccLib::Error` if `width < 2`.
 fn sliding_window(seq: String, width: usize) -> Result<Vec<String>, Error> {
     iscc_lib::sliding_window(&seq, width).map_err(to_magnus_err)
 }
@@ -295,7 +318,7 @@ fn sliding_window(seq: String, width: usize) -> Result<Vec<String>, Error> {
 /// Compute a SimHash from a sequence of equal-length hash digests.
 ///
 /// Accepts a Ruby Array of binary Strings, returns a binary String.
-/// Raises `RuntimeError` on mismatched digest lengths.
+/// Raises `IsccLib::Error` on mismatched digest lengths.
 fn alg_simhash(hash_digests: RArray) -> Result<RString, Error> {
     let digests: Vec<Vec<u8>> = hash_digests
         .into_iter()
@@ -461,10 +484,64 @@ impl RbInstanceHasher {
     }
 }
 
+/// Streaming ISCC-SUM generator for Ruby.
+///
+/// Wraps `iscc_lib::SumHasher` with `RefCell<Option<...>>` for one-shot
+/// finalize semantics.
+#[magnus::wrap(class = "IsccLib::SumHasher")]
+struct RbSumHasher {
+    inner: RefCell<Option<iscc_lib::SumHasher>>,
+}
+
+impl RbSumHasher {
+    /// Create a new `RbSumHasher`.
+    fn rb_new() -> Self {
+        Self {
+            inner: RefCell::new(Some(iscc_lib::SumHasher::new())),
+        }
+    }
+
+    /// Push binary data into the hasher.
+    ///
+    /// Raises `RuntimeError` if called after `finalize`.
+    fn update(&self, data: RString) -> Result<(), Error> {
+        let mut inner = self.inner.borrow_mut();
+        let hasher = inner.as_mut().ok_or_else(|| {
+            Error::new(
+                magnus::exception::runtime_error(),
+                "SumHasher already finalized",
+            )
+        })?;
+        // Safety: the slice is passed directly to a pure Rust function
+        // and not held across any Ruby API calls.
+        let bytes = unsafe { data.as_slice() };
+        hasher.update(bytes);
+        Ok(())
+    }
+
+    /// Consume the hasher and produce an ISCC-SUM result hash.
+    ///
+    /// Returns an `RHash` with keys `"iscc"`, `"datahash"`, `"filesize"`, and
+    /// optionally `"units"`. Raises `RuntimeError` if called more than once.
+    fn finalize(&self, bits: u32, wide: bool, add_units: bool) -> Result<RHash, Error> {
+        let hasher = self.inner.borrow_mut().take().ok_or_else(|| {
+            Error::new(
+                magnus::exception::runtime_error(),
+                "SumHasher already finalized",
+            )
+        })?;
+        let r = hasher
+            .finalize(bits, wide, add_units)
+            .map_err(to_magnus_err)?;
+        sum_result_hash(r)
+    }
+}
+
 /// Initialize the IsccLib Ruby module with all bridge functions and constants.
 #[magnus::init]
 fn init(ruby: &Ruby) -> Result<(), Error> {
     let module = ruby.define_module("IsccLib")?;
+    module.define_error("Error", ruby.exception_runtime_error())?;
 
     // Gen functions (prefixed with _ for Ruby wrapper layer)
     module.define_module_function("_gen_meta_code_v0", function!(gen_meta_code_v0, 4))?;
@@ -510,6 +587,11 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     instance_hasher.define_method("_update", method!(RbInstanceHasher::update, 1))?;
     instance_hasher.define_method("_finalize", method!(RbInstanceHasher::finalize, 1))?;
 
+    let sum_hasher = module.define_class("SumHasher", ruby.class_object())?;
+    sum_hasher.define_singleton_method("new", function!(RbSumHasher::rb_new, 0))?;
+    sum_hasher.define_method("_update", method!(RbSumHasher::update, 1))?;
+    sum_hasher.define_method("_finalize", method!(RbSumHasher::finalize, 3))?;
+
     // Constants
     module.const_set("META_TRIM_NAME", iscc_lib::META_TRIM_NAME)?;
     module.const_set("META_TRIM_DESCRIPTION", iscc_lib::META_TRIM_DESCRIPTION)?;
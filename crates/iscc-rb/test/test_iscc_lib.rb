@@ -1,6 +1,6 @@
 # frozen_string_literal: true
 
-# Tests for DataHasher and InstanceHasher streaming types.
+# Tests for DataHasher, InstanceHasher, and SumHasher streaming types.
 # Verifies the new → update → finalize interface and equivalence with one-shot functions.
 
 require "test_helper"
@@ -150,3 +150,27 @@ class TestInstanceHasher < Minitest::Test
     assert_equal data.bytesize, result.filesize
   end
 end
+
+class TestSumHasher < Minitest::Test
+  def test_streaming_matches_oneshot
+    require "tempfile"
+    data = ("Streaming sum hash" * 100).b
+    Tempfile.create("iscc-rb-sum") do |f|
+      f.binmode
+      f.write(data)
+      f.flush
+      oneshot = IsccLib.gen_sum_code_v0(f.path, add_units: true)
+      result = IsccLib::SumHasher.new.update(data[0, 700]).update(data[700..])
+        .finalize(add_units: true)
+      assert_kind_of IsccLib::SumCodeResult, result
+      assert_equal oneshot, result
+    end
+  end
+
+  def test_double_finalize_error
+    hasher = IsccLib::SumHasher.new
+    hasher.finalize
+    assert_raises(RuntimeError) { hasher.finalize }
+    assert_raises(RuntimeError) { hasher.update("more".b) }
+  end
+end
@@ -80,6 +80,11 @@ class TestSmoke < Minitest::Test
     assert_raises(RuntimeError) { IsccLib.iscc_decompose("INVALID") }
   end
 
+  def test_error_code
+    err = assert_raises(IsccLib::Error) { IsccLib.json_to_data_url("{not json") }
+    assert_equal "invalid_json", err.code
+  end
+
   def test_encode_component
     digest = ("\x00" * 8).b
     result = IsccLib.encode_component(0, 0, 0, 64, digest)
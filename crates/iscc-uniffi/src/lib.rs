@@ -4,7 +4,7 @@
 //! types (`String`, `Vec<u8>`) because UniFFI requires values, not borrowed references.
 //! Constants are exposed as getter functions (UniFFI doesn't support `const` exports).
 //!
-//! Streaming types (`DataHasher`, `InstanceHasher`) use `Mutex<Option<Inner>>` for
+//! Streaming types (`DataHasher`, `InstanceHasher`, `SumHasher`) use `Mutex<Option<Inner>>` for
 //! thread-safe one-shot finalization as UniFFI Objects.
 
 uniffi::setup_scaffolding!();
@@ -15,21 +15,122 @@ use std::sync::Mutex;
 // Error type
 // ---------------------------------------------------------------------------
 
-/// UniFFI-compatible error type wrapping `iscc_lib::IsccError`.
+/// UniFFI-compatible error type mirroring `iscc_lib::IsccError`.
+///
+/// Each variant carries the formatted message in `msg`; variants with
+/// structured data in the core expose those fields as well.
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum IsccUniError {
-    /// ISCC operation error with a descriptive message.
+    /// Other ISCC operation error with a descriptive message.
     #[error("{msg}")]
     IsccError {
         /// Error description.
         msg: String,
     },
+    /// Invalid input parameters or data.
+    #[error("{msg}")]
+    InvalidInput {
+        /// Error description.
+        msg: String,
+    },
+    /// I/O failure while reading input.
+    #[error("{msg}")]
+    Io {
+        /// Error description.
+        msg: String,
+    },
+    /// Malformed base32 data.
+    #[error("{msg}")]
+    Base32 {
+        /// Error description.
+        msg: String,
+    },
+    /// Invalid ISCC header field.
+    #[error("{msg}")]
+    Header {
+        /// Error description.
+        msg: String,
+    },
+    /// Input shorter than its header declares.
+    #[error("{msg}")]
+    Truncated {
+        /// Number of bytes required.
+        expected: u64,
+        /// Number of bytes available.
+        actual: u64,
+        /// Error description.
+        msg: String,
+    },
+    /// Unsupported bit length.
+    #[error("{msg}")]
+    InvalidBits {
+        /// Requested bit length.
+        bits: u32,
+        /// Description of the accepted values.
+        expected: String,
+        /// Error description.
+        msg: String,
+    },
+    /// Meta payload exceeds the size limit.
+    #[error("{msg}")]
+    MetaTooLarge {
+        /// Payload size in bytes.
+        size: u64,
+        /// Maximum allowed size in bytes.
+        limit: u64,
+        /// Error description.
+        msg: String,
+    },
+    /// Malformed JSON input.
+    #[error("{msg}")]
+    InvalidJson {
+        /// Error description.
+        msg: String,
+    },
+    /// Valid but unsupported input.
+    #[error("{msg}")]
+    Unsupported {
+        /// Error description.
+        msg: String,
+    },
+    /// ISCC string failed validation.
+    #[error("{msg}")]
+    Validation {
+        /// Error description.
+        msg: String,
+    },
 }
 
 impl From<iscc_lib::IsccError> for IsccUniError {
-    /// Convert an `iscc_lib::IsccError` into `IsccUniError`.
+    /// Convert an `iscc_lib::IsccError` into the matching `IsccUniError` variant.
     fn from(e: iscc_lib::IsccError) -> Self {
-        IsccUniError::IsccError { msg: e.to_string() }
+        use iscc_lib::IsccError as E;
+        let msg = e.to_string();
+        match e {
+            E::InvalidInput(_) => IsccUniError::InvalidInput { msg },
+            E::Io(_) => IsccUniError::Io { msg },
+            E::Base32(_) => IsccUniError::Base32 { msg },
+            E::Header(_) => IsccUniError::Header { msg },
+            E::Truncated { expected, actual } => IsccUniError::Truncated {
+                expected: expected as u64,
+                actual: actual as u64,
+                msg,
+            },
+            E::InvalidBits { bits, expected } => IsccUniError::InvalidBits {
+                bits,
+                expected,
+                msg,
+            },
+            E::MetaTooLarge { size, limit } => IsccUniError::MetaTooLarge {
+                size: size as u64,
+                limit: limit as u64,
+                msg,
+            },
+            E::InvalidJson(_) => IsccUniError::InvalidJson { msg },
+            E::Unsupported(_) => IsccUniError::Unsupported { msg },
+            E::Validation(_) => IsccUniError::Validation { msg },
+            _ => IsccUniError::IsccError { msg },
+        }
     }
 }
 
@@ -280,12 +381,18 @@ pub fn gen_sum_code_v0(
     add_units: bool,
 ) -> Result<SumCodeResult, IsccUniError> {
     let result = iscc_lib::gen_sum_code_v0(std::path::Path::new(&path), bits, wide, add_units)?;
-    Ok(SumCodeResult {
-        iscc: result.iscc,
-        datahash: result.datahash,
-        filesize: result.filesize,
-        units: result.units,
-    })
+    Ok(result.into())
+}
+
+impl From<iscc_lib::SumCodeResult> for SumCodeResult {
+    fn from(result: iscc_lib::SumCodeResult) -> Self {
+        SumCodeResult {
+            iscc: result.iscc,
+            datahash: result.datahash,
+            filesize: result.filesize,
+            units: result.units,
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -453,7 +560,7 @@ impl DataHasher {
                 hasher.update(&data);
                 Ok(())
             }
-            None => Err(IsccUniError::IsccError {
+            None => Err(IsccUniError::InvalidInput {
                 msg: "DataHasher already finalized".into(),
             }),
         }
@@ -462,7 +569,7 @@ impl DataHasher {
     /// Consume the inner hasher and produce a Data-Code result.
     pub fn finalize(&self, bits: u32) -> Result<DataCodeResult, IsccUniError> {
         let mut guard = self.inner.lock().unwrap();
-        let hasher = guard.take().ok_or(IsccUniError::IsccError {
+        let hasher = guard.take().ok_or(IsccUniError::InvalidInput {
             msg: "DataHasher already finalized".into(),
         })?;
         let result = hasher.finalize(bits)?;
@@ -505,7 +612,7 @@ impl InstanceHasher {
                 hasher.update(&data);
                 Ok(())
             }
-            None => Err(IsccUniError::IsccError {
+            None => Err(IsccUniError::InvalidInput {
                 msg: "InstanceHasher already finalized".into(),
             }),
         }
@@ -514,7 +621,7 @@ impl InstanceHasher {
     /// Consume the inner hasher and produce an Instance-Code result.
     pub fn finalize(&self, bits: u32) -> Result<InstanceCodeResult, IsccUniError> {
         let mut guard = self.inner.lock().unwrap();
-        let hasher = guard.take().ok_or(IsccUniError::IsccError {
+        let hasher = guard.take().ok_or(IsccUniError::InvalidInput {
             msg: "InstanceHasher already finalized".into(),
         })?;
         let result = hasher.finalize(bits)?;
@@ -526,6 +633,62 @@ impl InstanceHasher {
     }
 }
 
+/// Streaming ISCC-SUM generator.
+///
+/// Feeds data to both a Data-Code and an Instance-Code hasher and composes
+/// them on `finalize()`, matching `gen_sum_code_v0` without a file path.
+#[derive(uniffi::Object)]
+pub struct SumHasher {
+    /// Inner hasher behind a mutex for thread-safe one-shot finalization.
+    inner: Mutex<Option<iscc_lib::SumHasher>>,
+}
+
+impl Default for SumHasher {
+    /// Create a new `SumHasher` (delegates to `new()`).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[uniffi::export]
+impl SumHasher {
+    /// Create a new `SumHasher`.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        SumHasher {
+            inner: Mutex::new(Some(iscc_lib::SumHasher::new())),
+        }
+    }
+
+    /// Push data into the hasher.
+    pub fn update(&self, data: Vec<u8>) -> Result<(), IsccUniError> {
+        let mut guard = self.inner.lock().unwrap();
+        match guard.as_mut() {
+            Some(hasher) => {
+                hasher.update(&data);
+                Ok(())
+            }
+            None => Err(IsccUniError::InvalidInput {
+                msg: "SumHasher already finalized".into(),
+            }),
+        }
+    }
+
+    /// Consume the inner hasher and produce an ISCC-SUM result.
+    pub fn finalize(
+        &self,
+        bits: u32,
+        wide: bool,
+        add_units: bool,
+    ) -> Result<SumCodeResult, IsccUniError> {
+        let mut guard = self.inner.lock().unwrap();
+        let hasher = guard.take().ok_or(IsccUniError::InvalidInput {
+            msg: "SumHasher already finalized".into(),
+        })?;
+        Ok(hasher.finalize(bits, wide, add_units)?.into())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Unit tests
 // ---------------------------------------------------------------------------
@@ -682,12 +845,35 @@ mod tests {
         assert!(err.to_string().contains("already finalized"));
     }
 
+    #[test]
+    fn test_sum_hasher_streaming() {
+        let hasher = SumHasher::new();
+        hasher.update(b"hello ".to_vec()).unwrap();
+        hasher.update(b"world".to_vec()).unwrap();
+        let streaming = hasher.finalize(64, false, true).unwrap();
+
+        let data = gen_data_code_v0(b"hello world".to_vec(), 64).unwrap();
+        let instance = gen_instance_code_v0(b"hello world".to_vec(), 64).unwrap();
+        assert_eq!(streaming.datahash, instance.datahash);
+        assert_eq!(streaming.units, Some(vec![data.iscc, instance.iscc]));
+        let err = hasher.finalize(64, false, false).unwrap_err();
+        assert!(err.to_string().contains("already finalized"));
+    }
+
     #[test]
     fn test_json_to_data_url() {
         let result = json_to_data_url(r#"{"key":"value"}"#.into()).unwrap();
         assert!(result.starts_with("data:"));
     }
 
+    #[test]
+    fn test_structured_errors() {
+        let err = json_to_data_url("{not json".into()).unwrap_err();
+        assert!(matches!(err, IsccUniError::InvalidJson { .. }));
+        let err = gen_image_code_v0(vec![0u8; 1024], 512).unwrap_err();
+        assert!(matches!(err, IsccUniError::InvalidBits { bits: 512, .. }));
+    }
+
     #[test]
     fn test_alg_simhash() {
         let digests = vec![vec![0u8; 4], vec![255u8; 4]];
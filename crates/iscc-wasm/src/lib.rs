@@ -3,10 +3,28 @@
 //! This module provides browser-compatible WASM bindings for the `iscc-lib`
 //! core. Each function is a thin wrapper around the corresponding `iscc_lib`
 //! API, converting wasm-bindgen types to Rust types and mapping errors to
-//! `JsError`.
+//! JS `Error` objects carrying a stable `code` property.
 
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Reflect, js_name = set)]
+    fn reflect_set(target: &JsValue, key: &JsValue, value: &JsValue) -> bool;
+}
+
+/// Create a JS `Error` with `message` and a `code` property.
+fn js_error(code: &str, message: &str) -> JsValue {
+    let err: JsValue = JsError::new(message).into();
+    reflect_set(&err, &JsValue::from_str("code"), &JsValue::from_str(code));
+    err
+}
+
+/// Map a core `IsccError` to a JS `Error` with its stable `code`.
+fn iscc_err(e: iscc_lib::IsccError) -> JsValue {
+    js_error(e.code(), &e.to_string())
+}
+
 // ── Constants ────────────────────────────────────────────────────────────────
 
 /// Maximum byte length for the name field after trimming.
@@ -49,11 +67,11 @@ pub fn gen_meta_code_v0(
     description: Option<String>,
     meta: Option<String>,
     bits: Option<u32>,
-) -> Result<String, JsError> {
+) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_meta_code_v0(name, description.as_deref(), meta.as_deref(), bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a Text-Code from plain text content.
@@ -61,11 +79,11 @@ pub fn gen_meta_code_v0(
 /// Produces an ISCC Content-Code for text using MinHash-based
 /// similarity hashing.
 #[wasm_bindgen]
-pub fn gen_text_code_v0(text: &str, bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_text_code_v0(text: &str, bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_text_code_v0(text, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate an Image-Code from pixel data.
@@ -73,11 +91,30 @@ pub fn gen_text_code_v0(text: &str, bits: Option<u32>) -> Result<String, JsError
 /// Produces an ISCC Content-Code for images from 1024 grayscale pixels
 /// (32x32) using a DCT-based perceptual hash.
 #[wasm_bindgen]
-pub fn gen_image_code_v0(pixels: &[u8], bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_image_code_v0(pixels: &[u8], bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_image_code_v0(pixels, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
+}
+
+/// Generate an Image-Code from a raw pixel buffer of any size.
+///
+/// `format` is a Pillow mode name (`"L"`, `"LA"`, `"RGB"` or `"RGBA"`), so
+/// canvas `ImageData.data` can be passed as-is with `"RGBA"`.
+#[wasm_bindgen]
+pub fn gen_image_code_from_pixels(
+    width: u32,
+    height: u32,
+    format: &str,
+    pixels: &[u8],
+    bits: Option<u32>,
+) -> Result<String, JsValue> {
+    let bits = bits.unwrap_or(64);
+    let format = format.parse().map_err(iscc_err)?;
+    iscc_lib::gen_image_code_from_pixels(width, height, format, pixels, bits)
+        .map(|r| r.iscc)
+        .map_err(iscc_err)
 }
 
 /// Generate an Audio-Code from a Chromaprint feature vector.
@@ -85,11 +122,51 @@ pub fn gen_image_code_v0(pixels: &[u8], bits: Option<u32>) -> Result<String, JsE
 /// Produces an ISCC Content-Code for audio from signed integer
 /// Chromaprint fingerprint features using multi-stage SimHash.
 #[wasm_bindgen]
-pub fn gen_audio_code_v0(cv: Vec<i32>, bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_audio_code_v0(cv: Vec<i32>, bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_audio_code_v0(&cv, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
+}
+
+/// Generate an Audio-Code from mono 16-bit PCM samples.
+///
+/// Computes the Chromaprint fingerprint in-process, so no libchromaprint is
+/// needed. Samples at rates other than 11025 Hz are resampled first.
+#[wasm_bindgen]
+pub fn gen_audio_code_from_pcm(
+    samples: &[i16],
+    sample_rate: u32,
+    bits: Option<u32>,
+) -> Result<String, JsValue> {
+    let bits = bits.unwrap_or(64);
+    iscc_lib::gen_audio_code_from_pcm(samples, sample_rate, bits)
+        .map(|r| r.iscc)
+        .map_err(iscc_err)
+}
+
+/// Compute the Chromaprint fingerprint of mono 16-bit PCM samples.
+#[wasm_bindgen]
+pub fn chromaprint_from_pcm(samples: &[i16], sample_rate: u32) -> Result<Vec<i32>, JsValue> {
+    iscc_lib::chromaprint_from_pcm(samples, sample_rate).map_err(iscc_err)
+}
+
+/// Generate an Audio-Code from `fpcalc` output.
+///
+/// Accepts the compressed base64 fingerprint, comma-separated raw integers,
+/// or the complete plain or `-json` output of `fpcalc`.
+#[wasm_bindgen]
+pub fn gen_audio_code_from_chromaprint(fp: &str, bits: Option<u32>) -> Result<String, JsValue> {
+    let bits = bits.unwrap_or(64);
+    iscc_lib::gen_audio_code_from_chromaprint(fp, bits)
+        .map(|r| r.iscc)
+        .map_err(iscc_err)
+}
+
+/// Decode a compressed base64 Chromaprint fingerprint into its raw values.
+#[wasm_bindgen]
+pub fn decode_chromaprint(fp: &str) -> Result<Vec<i32>, JsValue> {
+    iscc_lib::decode_chromaprint(fp).map_err(iscc_err)
 }
 
 /// Generate a Video-Code from frame signature data.
@@ -97,13 +174,13 @@ pub fn gen_audio_code_v0(cv: Vec<i32>, bits: Option<u32>) -> Result<String, JsEr
 /// Produces an ISCC Content-Code for video from MPEG-7 frame
 /// signature vectors using WTA-Hash. Accepts a JS array of arrays of i32.
 #[wasm_bindgen]
-pub fn gen_video_code_v0(frame_sigs: JsValue, bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_video_code_v0(frame_sigs: JsValue, bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
-    let frame_sigs: Vec<Vec<i32>> =
-        serde_wasm_bindgen::from_value(frame_sigs).map_err(|e| JsError::new(&e.to_string()))?;
+    let frame_sigs: Vec<Vec<i32>> = serde_wasm_bindgen::from_value(frame_sigs)
+        .map_err(|e| js_error("invalid_input", &e.to_string()))?;
     iscc_lib::gen_video_code_v0(&frame_sigs, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a Mixed-Code from multiple Content-Code strings.
@@ -111,14 +188,14 @@ pub fn gen_video_code_v0(frame_sigs: JsValue, bits: Option<u32>) -> Result<Strin
 /// Produces a Mixed Content-Code by combining multiple ISCC Content-Codes
 /// of different types using SimHash. Accepts a JS array of strings.
 #[wasm_bindgen]
-pub fn gen_mixed_code_v0(codes: JsValue, bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_mixed_code_v0(codes: JsValue, bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
-    let codes: Vec<String> =
-        serde_wasm_bindgen::from_value(codes).map_err(|e| JsError::new(&e.to_string()))?;
+    let codes: Vec<String> = serde_wasm_bindgen::from_value(codes)
+        .map_err(|e| js_error("invalid_input", &e.to_string()))?;
     let refs: Vec<&str> = codes.iter().map(|s| s.as_str()).collect();
     iscc_lib::gen_mixed_code_v0(&refs, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a Data-Code from raw byte data.
@@ -126,11 +203,11 @@ pub fn gen_mixed_code_v0(codes: JsValue, bits: Option<u32>) -> Result<String, Js
 /// Produces an ISCC Data-Code by splitting data into content-defined
 /// chunks and applying MinHash for similarity hashing.
 #[wasm_bindgen]
-pub fn gen_data_code_v0(data: &[u8], bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_data_code_v0(data: &[u8], bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_data_code_v0(data, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate an Instance-Code from raw byte data.
@@ -138,11 +215,11 @@ pub fn gen_data_code_v0(data: &[u8], bits: Option<u32>) -> Result<String, JsErro
 /// Produces an ISCC Instance-Code by hashing the complete byte stream
 /// with BLAKE3. Returns the ISCC string with "ISCC:" prefix.
 #[wasm_bindgen]
-pub fn gen_instance_code_v0(data: &[u8], bits: Option<u32>) -> Result<String, JsError> {
+pub fn gen_instance_code_v0(data: &[u8], bits: Option<u32>) -> Result<String, JsValue> {
     let bits = bits.unwrap_or(64);
     iscc_lib::gen_instance_code_v0(data, bits)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Generate a composite ISCC-CODE from individual unit codes.
@@ -150,14 +227,14 @@ pub fn gen_instance_code_v0(data: &[u8], bits: Option<u32>) -> Result<String, Js
 /// Combines multiple ISCC unit codes into a single composite ISCC-CODE.
 /// Requires at least Data-Code and Instance-Code. Accepts a JS array of strings.
 #[wasm_bindgen]
-pub fn gen_iscc_code_v0(codes: JsValue, wide: Option<bool>) -> Result<String, JsError> {
+pub fn gen_iscc_code_v0(codes: JsValue, wide: Option<bool>) -> Result<String, JsValue> {
     let wide = wide.unwrap_or(false);
-    let codes: Vec<String> =
-        serde_wasm_bindgen::from_value(codes).map_err(|e| JsError::new(&e.to_string()))?;
+    let codes: Vec<String> = serde_wasm_bindgen::from_value(codes)
+        .map_err(|e| js_error("invalid_input", &e.to_string()))?;
     let refs: Vec<&str> = codes.iter().map(|s| s.as_str()).collect();
     iscc_lib::gen_iscc_code_v0(&refs, wide)
         .map(|r| r.iscc)
-        .map_err(|e| JsError::new(&e.to_string()))
+        .map_err(iscc_err)
 }
 
 /// Result of [`gen_sum_code_v0`], containing the composite ISCC-CODE, data hash, and file size.
@@ -175,9 +252,8 @@ pub struct WasmSumCodeResult {
 
 /// Generate a composite ISCC-CODE from raw byte data in a single pass.
 ///
-/// Feeds both `DataHasher` (CDC/MinHash) and `InstanceHasher` (BLAKE3) from
-/// the same byte slice, then composes the final ISCC-CODE via
-/// `gen_iscc_code_v0`. WASM-compatible alternative to the file-based core API.
+/// Feeds the byte slice to a `SumHasher` (Data-Code and Instance-Code in one
+/// pass). WASM-compatible alternative to the file-based core API.
 ///
 /// When `add_units` is `true`, the result includes the individual Data-Code
 /// and Instance-Code ISCC strings.
@@ -187,40 +263,28 @@ pub fn gen_sum_code_v0(
     bits: Option<u32>,
     wide: Option<bool>,
     add_units: Option<bool>,
-) -> Result<WasmSumCodeResult, JsError> {
+) -> Result<WasmSumCodeResult, JsValue> {
     let bits = bits.unwrap_or(64);
     let wide = wide.unwrap_or(false);
     let add_units = add_units.unwrap_or(false);
 
-    let mut data_hasher = iscc_lib::DataHasher::new();
-    let mut instance_hasher = iscc_lib::InstanceHasher::new();
-
-    data_hasher.update(data);
-    instance_hasher.update(data);
-
-    let data_result = data_hasher
-        .finalize(bits)
-        .map_err(|e| JsError::new(&e.to_string()))?;
-    let instance_result = instance_hasher
-        .finalize(bits)
-        .map_err(|e| JsError::new(&e.to_string()))?;
-
-    // Borrow strings for gen_iscc_code_v0 before potentially moving them into units.
-    let iscc_result = iscc_lib::gen_iscc_code_v0(&[&data_result.iscc, &instance_result.iscc], wide)
-        .map_err(|e| JsError::new(&e.to_string()))?;
-
-    let units = if add_units {
-        Some(vec![data_result.iscc, instance_result.iscc])
-    } else {
-        None
-    };
-
-    Ok(WasmSumCodeResult {
-        iscc: iscc_result.iscc,
-        datahash: instance_result.datahash,
-        filesize: instance_result.filesize as f64,
-        units,
-    })
+    let mut hasher = iscc_lib::SumHasher::new();
+    hasher.update(data);
+    hasher
+        .finalize(bits, wide, add_units)
+        .map(WasmSumCodeResult::from)
+        .map_err(iscc_err)
+}
+
+impl From<iscc_lib::SumCodeResult> for WasmSumCodeResult {
+    fn from(r: iscc_lib::SumCodeResult) -> Self {
+        Self {
+            iscc: r.iscc,
+            datahash: r.datahash,
+            filesize: r.filesize as f64,
+            units: r.units,
+        }
+    }
 }
 
 // ── Text utilities ──────────────────────────────────────────────────────────
@@ -269,8 +333,8 @@ pub fn text_collapse(text: &str) -> String {
 /// Uses `application/ld+json` media type when the JSON contains an `@context`
 /// key, otherwise `application/json`.
 #[wasm_bindgen]
-pub fn json_to_data_url(json: &str) -> Result<String, JsError> {
-    iscc_lib::json_to_data_url(json).map_err(|e| JsError::new(&e.to_string()))
+pub fn json_to_data_url(json: &str) -> Result<String, JsValue> {
+    iscc_lib::json_to_data_url(json).map_err(iscc_err)
 }
 
 /// Encode bytes as base64url (RFC 4648 §5, no padding).
@@ -294,9 +358,8 @@ pub fn encode_component(
     version: u8,
     bit_length: u32,
     digest: &[u8],
-) -> Result<String, JsError> {
-    iscc_lib::encode_component(mtype, stype, version, bit_length, digest)
-        .map_err(|e| JsError::new(&e.to_string()))
+) -> Result<String, JsValue> {
+    iscc_lib::encode_component(mtype, stype, version, bit_length, digest).map_err(iscc_err)
 }
 
 /// Result of decoding an ISCC unit string.
@@ -319,9 +382,8 @@ pub struct IsccDecodeResult {
 /// Returns an object with `maintype`, `subtype`, `version`, `length`, and
 /// `digest` fields. Strips an optional "ISCC:" prefix before decoding.
 #[wasm_bindgen]
-pub fn iscc_decode(iscc: &str) -> Result<IsccDecodeResult, JsError> {
-    let (mt, st, vs, li, digest) =
-        iscc_lib::iscc_decode(iscc).map_err(|e| JsError::new(&e.to_string()))?;
+pub fn iscc_decode(iscc: &str) -> Result<IsccDecodeResult, JsValue> {
+    let (mt, st, vs, li, digest) = iscc_lib::iscc_decode(iscc).map_err(iscc_err)?;
     Ok(IsccDecodeResult {
         maintype: mt,
         subtype: st,
@@ -337,8 +399,8 @@ pub fn iscc_decode(iscc: &str) -> Result<IsccDecodeResult, JsError> {
 /// The optional "ISCC:" prefix is stripped before decoding.
 /// Returns an array of base32-encoded ISCC-UNIT strings (without prefix).
 #[wasm_bindgen]
-pub fn iscc_decompose(iscc_code: &str) -> Result<Vec<String>, JsError> {
-    iscc_lib::iscc_decompose(iscc_code).map_err(|e| JsError::new(&e.to_string()))
+pub fn iscc_decompose(iscc_code: &str) -> Result<Vec<String>, JsValue> {
+    iscc_lib::iscc_decompose(iscc_code).map_err(iscc_err)
 }
 
 // ── Conformance ─────────────────────────────────────────────────────────────
@@ -359,8 +421,8 @@ pub fn conformance_selftest() -> bool {
 /// Returns overlapping substrings of `width` Unicode characters, advancing
 /// by one character at a time. Throws if width is less than 2.
 #[wasm_bindgen]
-pub fn sliding_window(seq: &str, width: u32) -> Result<Vec<String>, JsError> {
-    iscc_lib::sliding_window(seq, width as usize).map_err(|e| JsError::new(&e.to_string()))
+pub fn sliding_window(seq: &str, width: u32) -> Result<Vec<String>, JsValue> {
+    iscc_lib::sliding_window(seq, width as usize).map_err(iscc_err)
 }
 
 // ── Algorithm primitives ─────────────────────────────────────────────────────
@@ -371,10 +433,10 @@ pub fn sliding_window(seq: &str, width: u32) -> Result<Vec<String>, JsError> {
 /// hash whose length matches the input digest length. Returns 32 zero bytes
 /// for empty input. Throws on mismatched digest lengths.
 #[wasm_bindgen]
-pub fn alg_simhash(hash_digests: JsValue) -> Result<Vec<u8>, JsError> {
-    let digests: Vec<Vec<u8>> =
-        serde_wasm_bindgen::from_value(hash_digests).map_err(|e| JsError::new(&e.to_string()))?;
-    iscc_lib::alg_simhash(&digests).map_err(|e| JsError::new(&e.to_string()))
+pub fn alg_simhash(hash_digests: JsValue) -> Result<Vec<u8>, JsValue> {
+    let digests: Vec<Vec<u8>> = serde_wasm_bindgen::from_value(hash_digests)
+        .map_err(|e| js_error("invalid_input", &e.to_string()))?;
+    iscc_lib::alg_simhash(&digests).map_err(iscc_err)
 }
 
 /// Compute a 256-bit MinHash digest from 32-bit integer features.
@@ -396,14 +458,14 @@ pub fn alg_cdc_chunks(
     data: &[u8],
     utf32: bool,
     avg_chunk_size: Option<u32>,
-) -> Result<JsValue, JsError> {
+) -> Result<JsValue, JsValue> {
     let avg = avg_chunk_size.unwrap_or(1024);
     let chunks: Vec<Vec<u8>> = iscc_lib::alg_cdc_chunks(data, utf32, avg)
-        .map_err(|e| JsError::new(&e.to_string()))?
+        .map_err(iscc_err)?
         .iter()
         .map(|c| c.to_vec())
         .collect();
-    serde_wasm_bindgen::to_value(&chunks).map_err(|e| JsError::new(&e.to_string()))
+    Ok(serde_wasm_bindgen::to_value(&chunks)?)
 }
 
 /// Compute a similarity-preserving hash from video frame signatures.
@@ -411,11 +473,11 @@ pub fn alg_cdc_chunks(
 /// Accepts a JS array of arrays of `i32`. Returns raw bytes of length
 /// `bits / 8`. Default `bits` is 64. Throws if `frame_sigs` is empty.
 #[wasm_bindgen]
-pub fn soft_hash_video_v0(frame_sigs: JsValue, bits: Option<u32>) -> Result<Vec<u8>, JsError> {
+pub fn soft_hash_video_v0(frame_sigs: JsValue, bits: Option<u32>) -> Result<Vec<u8>, JsValue> {
     let bits = bits.unwrap_or(64);
-    let frame_sigs: Vec<Vec<i32>> =
-        serde_wasm_bindgen::from_value(frame_sigs).map_err(|e| JsError::new(&e.to_string()))?;
-    iscc_lib::soft_hash_video_v0(&frame_sigs, bits).map_err(|e| JsError::new(&e.to_string()))
+    let frame_sigs: Vec<Vec<i32>> = serde_wasm_bindgen::from_value(frame_sigs)
+        .map_err(|e| js_error("invalid_input", &e.to_string()))?;
+    iscc_lib::soft_hash_video_v0(&frame_sigs, bits).map_err(iscc_err)
 }
 
 // ── Streaming hashers ─────────────────────────────────────────────────────────
@@ -448,10 +510,10 @@ impl DataHasher {
     }
 
     /// Push data into the hasher.
-    pub fn update(&mut self, data: &[u8]) -> Result<(), JsError> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), JsValue> {
         self.inner
             .as_mut()
-            .ok_or_else(|| JsError::new("DataHasher already finalized"))
+            .ok_or_else(|| js_error("invalid_input", "DataHasher already finalized"))
             .map(|h| h.update(data))
     }
 
@@ -459,15 +521,15 @@ impl DataHasher {
     ///
     /// After calling `finalize`, subsequent calls to `update` or `finalize`
     /// will throw. Default `bits` is 64.
-    pub fn finalize(&mut self, bits: Option<u32>) -> Result<String, JsError> {
+    pub fn finalize(&mut self, bits: Option<u32>) -> Result<String, JsValue> {
         let hasher = self
             .inner
             .take()
-            .ok_or_else(|| JsError::new("DataHasher already finalized"))?;
+            .ok_or_else(|| js_error("invalid_input", "DataHasher already finalized"))?;
         hasher
             .finalize(bits.unwrap_or(64))
             .map(|r| r.iscc)
-            .map_err(|e| JsError::new(&e.to_string()))
+            .map_err(iscc_err)
     }
 }
 
@@ -499,10 +561,10 @@ impl InstanceHasher {
     }
 
     /// Push data into the hasher.
-    pub fn update(&mut self, data: &[u8]) -> Result<(), JsError> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), JsValue> {
         self.inner
             .as_mut()
-            .ok_or_else(|| JsError::new("InstanceHasher already finalized"))
+            .ok_or_else(|| js_error("invalid_input", "InstanceHasher already finalized"))
             .map(|h| h.update(data))
     }
 
@@ -510,14 +572,74 @@ impl InstanceHasher {
     ///
     /// After calling `finalize`, subsequent calls to `update` or `finalize`
     /// will throw. Default `bits` is 64.
-    pub fn finalize(&mut self, bits: Option<u32>) -> Result<String, JsError> {
+    pub fn finalize(&mut self, bits: Option<u32>) -> Result<String, JsValue> {
         let hasher = self
             .inner
             .take()
-            .ok_or_else(|| JsError::new("InstanceHasher already finalized"))?;
+            .ok_or_else(|| js_error("invalid_input", "InstanceHasher already finalized"))?;
         hasher
             .finalize(bits.unwrap_or(64))
             .map(|r| r.iscc)
-            .map_err(|e| JsError::new(&e.to_string()))
+            .map_err(iscc_err)
+    }
+}
+
+/// Streaming ISCC-SUM generator.
+///
+/// Feeds data to both a Data-Code and an Instance-Code hasher to produce
+/// results identical to `gen_sum_code_v0`. Follows the
+/// `new() → update() → finalize()` pattern.
+#[wasm_bindgen]
+pub struct SumHasher {
+    inner: Option<iscc_lib::SumHasher>,
+}
+
+impl Default for SumHasher {
+    /// Create a new `SumHasher` (delegates to `new()`).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl SumHasher {
+    /// Create a new `SumHasher`.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: Some(iscc_lib::SumHasher::new()),
+        }
+    }
+
+    /// Push data into the hasher.
+    pub fn update(&mut self, data: &[u8]) -> Result<(), JsValue> {
+        self.inner
+            .as_mut()
+            .ok_or_else(|| js_error("invalid_input", "SumHasher already finalized"))
+            .map(|h| h.update(data))
+    }
+
+    /// Consume the hasher and produce an ISCC-SUM result.
+    ///
+    /// After calling `finalize`, subsequent calls to `update` or `finalize`
+    /// will throw. Defaults: `bits` 64, `wide` false, `add_units` false.
+    pub fn finalize(
+        &mut self,
+        bits: Option<u32>,
+        wide: Option<bool>,
+        add_units: Option<bool>,
+    ) -> Result<WasmSumCodeResult, JsValue> {
+        let hasher = self
+            .inner
+            .take()
+            .ok_or_else(|| js_error("invalid_input", "SumHasher already finalized"))?;
+        hasher
+            .finalize(
+                bits.unwrap_or(64),
+                wide.unwrap_or(false),
+                add_units.unwrap_or(false),
+            )
+            .map(WasmSumCodeResult::from)
+            .map_err(iscc_err)
     }
 }
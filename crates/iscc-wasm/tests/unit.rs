@@ -572,6 +572,31 @@ fn test_instance_hasher_default_bits() {
     assert_eq!(result_none, result_64, "None bits should equal explicit 64");
 }
 
+// ── SumHasher ───────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn test_sum_hasher_matches_gen_function() {
+    let data = b"The quick brown fox jumps over the lazy dog";
+    let mut sh = iscc_wasm::SumHasher::new();
+    sh.update(&data[..10]).unwrap();
+    sh.update(&data[10..]).unwrap();
+    let streaming = sh.finalize(Some(128), Some(true), Some(true)).unwrap();
+    let oneshot = iscc_wasm::gen_sum_code_v0(data, Some(128), Some(true), Some(true)).unwrap();
+    assert_eq!(streaming.iscc, oneshot.iscc);
+    assert_eq!(streaming.datahash, oneshot.datahash);
+    assert_eq!(streaming.filesize, oneshot.filesize);
+    assert_eq!(streaming.units, oneshot.units);
+}
+
+#[wasm_bindgen_test]
+fn test_sum_hasher_finalize_once() {
+    let mut sh = iscc_wasm::SumHasher::new();
+    sh.update(b"test data").unwrap();
+    let _result = sh.finalize(None, None, None).unwrap();
+    assert!(sh.finalize(None, None, None).is_err());
+    assert!(sh.update(b"more data").is_err());
+}
+
 // ── Constants ──────────────────────────────────────────────────────────────
 
 #[wasm_bindgen_test]
@@ -709,3 +734,49 @@ fn test_json_to_data_url_error_on_invalid_json() {
     let result = iscc_wasm::json_to_data_url("not json {{{");
     assert!(result.is_err(), "invalid JSON should error");
 }
+
+// ── gen_image_code_from_pixels ─────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn test_gen_image_code_from_pixels_rgba_matches_gray() {
+    let (width, height) = (64u32, 48u32);
+    let gray: Vec<u8> = (0..width * height)
+        .map(|i| ((i % width) * 7 + (i / width) * 3) as u8)
+        .collect();
+    let rgba: Vec<u8> = gray.iter().flat_map(|&v| [v, v, v, 255]).collect();
+    let expected = iscc_wasm::gen_image_code_from_pixels(width, height, "L", &gray, None).unwrap();
+    let result = iscc_wasm::gen_image_code_from_pixels(width, height, "RGBA", &rgba, None).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[wasm_bindgen_test]
+fn test_gen_image_code_from_pixels_error_on_unknown_format() {
+    let result = iscc_wasm::gen_image_code_from_pixels(2, 2, "CMYK", &[0; 16], None);
+    assert!(result.is_err(), "unknown format should error");
+}
+
+// ── gen_audio_code_from_chromaprint ────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn test_gen_audio_code_from_chromaprint_matches_raw() {
+    assert_eq!(
+        iscc_wasm::decode_chromaprint("AAAAAkEA").unwrap(),
+        vec![1, 0]
+    );
+    let expected = iscc_wasm::gen_audio_code_v0(vec![1, 0], None).unwrap();
+    let result = iscc_wasm::gen_audio_code_from_chromaprint("AAAAAkEA", None).unwrap();
+    assert_eq!(result, expected);
+}
+
+// ── gen_audio_code_from_pcm ────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn test_gen_audio_code_from_pcm_matches_fingerprint() {
+    let samples: Vec<i16> = (0..11025 * 4)
+        .map(|i| ((i as f64 * 0.25).sin() * 8000.0) as i16)
+        .collect();
+    let cv = iscc_wasm::chromaprint_from_pcm(&samples, 11025).unwrap();
+    let expected = iscc_wasm::gen_audio_code_v0(cv, None).unwrap();
+    let result = iscc_wasm::gen_audio_code_from_pcm(&samples, 11025, None).unwrap();
+    assert_eq!(result, expected);
+}
@@ -32,7 +32,14 @@ namespace iscc {
 /// Exception thrown when a C FFI call fails.
 class IsccError : public std::runtime_error {
 public:
-    explicit IsccError(const std::string& msg) : std::runtime_error(msg) {}
+    explicit IsccError(const std::string& msg, std::string code = "")
+        : std::runtime_error(msg), code_(std::move(code)) {}
+
+    /// Stable machine-readable error code (e.g. "truncated", "io"), or empty.
+    const std::string& code() const noexcept { return code_; }
+
+private:
+    std::string code_;
 };
 
 namespace detail {
@@ -55,7 +62,8 @@ inline const int32_t* safe_data(const std::vector<int32_t>& v) {
 inline void check_error() {
     const char* err = iscc_last_error();
     if (err) {
-        throw IsccError(err);
+        const char* code = iscc_last_error_code();
+        throw IsccError(err, code ? code : "");
     }
 }
 
@@ -555,14 +563,14 @@ inline IsccCodeResult gen_iscc_code_v0(const std::vector<std::string>& codes,
     return IsccCodeResult{s.to_string()};
 }
 
-/// Generate a composite ISCC-CODE from a file path (Data-Code + Instance-Code).
-inline SumCodeResult gen_sum_code_v0(const std::string& path, uint32_t bits = 64,
-                                      bool wide = false, bool add_units = false) {
-    iscc_IsccSumCodeResult sr = iscc_gen_sum_code_v0(path.c_str(), bits, wide, add_units);
+namespace detail {
+
+/// Convert and free a C ISCC-SUM result, throwing on failure.
+inline SumCodeResult take_sum_result(iscc_IsccSumCodeResult sr, const char* what) {
     if (!sr.ok) {
         iscc_free_sum_code_result(sr);
-        detail::check_error();
-        throw IsccError("gen_sum_code_v0 failed");
+        check_error();
+        throw IsccError(what);
     }
     try {
         SumCodeResult result;
@@ -582,6 +590,15 @@ inline SumCodeResult gen_sum_code_v0(const std::string& path, uint32_t bits = 64
     }
 }
 
+} // namespace detail
+
+/// Generate a composite ISCC-CODE from a file path (Data-Code + Instance-Code).
+inline SumCodeResult gen_sum_code_v0(const std::string& path, uint32_t bits = 64,
+                                      bool wide = false, bool add_units = false) {
+    return detail::take_sum_result(iscc_gen_sum_code_v0(path.c_str(), bits, wide, add_units),
+                                   "gen_sum_code_v0 failed");
+}
+
 // ---------------------------------------------------------------------------
 // Conformance
 // ---------------------------------------------------------------------------
@@ -695,4 +712,53 @@ public:
     }
 };
 
+/// Streaming ISCC-SUM hasher (Data-Code + Instance-Code). RAII, move-only.
+class SumHasher {
+    iscc_FfiSumHasher* handle_;
+
+public:
+    /// Create a new streaming ISCC-SUM hasher.
+    SumHasher() : handle_(iscc_sum_hasher_new()) {
+        detail::check_ptr(handle_);
+    }
+
+    ~SumHasher() {
+        if (handle_) {
+            iscc_sum_hasher_free(handle_);
+        }
+    }
+
+    // Move-only
+    SumHasher(SumHasher&& o) noexcept : handle_(std::exchange(o.handle_, nullptr)) {}
+    SumHasher& operator=(SumHasher&& o) noexcept {
+        if (this != &o) {
+            if (handle_) iscc_sum_hasher_free(handle_);
+            handle_ = std::exchange(o.handle_, nullptr);
+        }
+        return *this;
+    }
+    SumHasher(const SumHasher&) = delete;
+    SumHasher& operator=(const SumHasher&) = delete;
+
+    /// Push data into the hasher.
+    void update(const uint8_t* data, size_t len) {
+        bool ok = iscc_sum_hasher_update(handle_, data, len);
+        if (!ok) {
+            detail::check_error();
+            throw IsccError("SumHasher update failed");
+        }
+    }
+
+    /// Push data into the hasher from a vector.
+    void update(const std::vector<uint8_t>& data) {
+        update(data.data(), data.size());
+    }
+
+    /// Finalize and return the ISCC-SUM result.
+    SumCodeResult finalize(uint32_t bits = 64, bool wide = false, bool add_units = false) {
+        return detail::take_sum_result(iscc_sum_hasher_finalize(handle_, bits, wide, add_units),
+                                       "SumHasher finalize failed");
+    }
+};
+
 } // namespace iscc
@@ -391,6 +391,24 @@ int main() {
                        "gen_audio_code_v0 empty vector");
     }
 
+    // 36. SumHasher matches gen_sum_code_v0
+    {
+        auto tmppath = (std::filesystem::temp_directory_path() / "iscc_cpp_test_sum_hasher.bin").string();
+        {
+            std::ofstream f(tmppath, std::ios::binary);
+            f.write("Hello World", 11);
+        }
+        auto expected = iscc::gen_sum_code_v0(tmppath, 64, false, true);
+        iscc::SumHasher sh;
+        sh.update(reinterpret_cast<const uint8_t*>("Hello"), 5);
+        sh.update(reinterpret_cast<const uint8_t*>(" World"), 6);
+        auto r = sh.finalize(64, false, true);
+        assert_str_eq(r.iscc, expected.iscc, "SumHasher matches gen_sum_code_v0");
+        assert_eq(r.filesize, 11, "SumHasher filesize == 11");
+        assert_true(r.units == expected.units, "SumHasher units match");
+        std::filesystem::remove(tmppath);
+    }
+
     // Summary
     std::printf("\n%d passed, %d failed\n", tests_passed, tests_failed);
     return tests_failed > 0 ? 1 : 0;
@@ -385,6 +385,30 @@ public class SmokeTests
         Assert.Equal(expected.Iscc, result.Iscc);
     }
 
+    [Fact]
+    public void SumHasher_MatchesGenSumCodeV0()
+    {
+        byte[] data = "Hello World"u8.ToArray();
+        string tempFile = Path.GetTempFileName();
+        try
+        {
+            File.WriteAllBytes(tempFile, data);
+            var expected = IsccLib.GenSumCodeV0(tempFile, addUnits: true);
+            using var hasher = new IsccSumHasher();
+            hasher.Update(data.AsSpan(0, 5));
+            hasher.Update(data.AsSpan(5));
+            var result = hasher.Finalize(addUnits: true);
+            Assert.Equal(expected.Iscc, result.Iscc);
+            Assert.Equal(expected.Datahash, result.Datahash);
+            Assert.Equal(expected.Filesize, result.Filesize);
+            Assert.Equal(expected.Units, result.Units);
+        }
+        finally
+        {
+            File.Delete(tempFile);
+        }
+    }
+
     [Fact]
     public void DataHasher_DisposeIsIdempotent()
     {
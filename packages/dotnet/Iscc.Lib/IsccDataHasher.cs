@@ -35,7 +35,7 @@ public sealed class IsccDataHasher : IDisposable
                     (FfiDataHasher*)(void*)_handle.DangerousGetHandle(),
                     pData, (nuint)data.Length);
                 if (!ok)
-                    throw new IsccException(IsccLib.GetLastError());
+                    throw new IsccException(IsccLib.GetLastError(), IsccLib.GetLastErrorCode());
             }
         }
     }
@@ -7,4 +7,13 @@ public class IsccException : Exception
 {
     /// <summary>Create a new IsccException with the specified error message.</summary>
     public IsccException(string message) : base(message) { }
+
+    /// <summary>Create a new IsccException with an error message and stable error code.</summary>
+    public IsccException(string message, string? code) : base(message)
+    {
+        Code = code;
+    }
+
+    /// <summary>Stable machine-readable error code (e.g. "truncated", "io"), if known.</summary>
+    public string? Code { get; }
 }
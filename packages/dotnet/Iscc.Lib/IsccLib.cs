@@ -339,30 +339,35 @@ public static partial class IsccLib
             {
                 result = NativeMethods.iscc_gen_sum_code_v0(pPath, bits, wide, addUnits);
             }
+            return ConsumeSumCodeResult(result);
+        }
+    }
 
-            try
-            {
-                if (!result.ok)
-                    throw new IsccException(GetLastError());
-
-                string iscc = Marshal.PtrToStringUTF8((IntPtr)result.iscc) ?? string.Empty;
-                string datahash = Marshal.PtrToStringUTF8((IntPtr)result.datahash) ?? string.Empty;
+    /// <summary>Marshal a native ISCC-SUM result to managed, free it, or throw on failure.</summary>
+    internal static unsafe SumCodeResult ConsumeSumCodeResult(IsccSumCodeResult result)
+    {
+        try
+        {
+            if (!result.ok)
+                throw new IsccException(GetLastError(), GetLastErrorCode());
 
-                string[]? units = null;
-                if (result.units != null)
-                {
-                    List<string> unitsList = new();
-                    for (int i = 0; result.units[i] != null; i++)
-                        unitsList.Add(Marshal.PtrToStringUTF8((IntPtr)result.units[i])!);
-                    units = unitsList.ToArray();
-                }
+            string iscc = Marshal.PtrToStringUTF8((IntPtr)result.iscc) ?? string.Empty;
+            string datahash = Marshal.PtrToStringUTF8((IntPtr)result.datahash) ?? string.Empty;
 
-                return new SumCodeResult(iscc, datahash, result.filesize, units);
-            }
-            finally
+            string[]? units = null;
+            if (result.units != null)
             {
-                NativeMethods.iscc_free_sum_code_result(result);
+                List<string> unitsList = new();
+                for (int i = 0; result.units[i] != null; i++)
+                    unitsList.Add(Marshal.PtrToStringUTF8((IntPtr)result.units[i])!);
+                units = unitsList.ToArray();
             }
+
+            return new SumCodeResult(iscc, datahash, result.filesize, units);
+        }
+        finally
+        {
+            NativeMethods.iscc_free_sum_code_result(result);
         }
     }
 
@@ -421,7 +426,7 @@ public static partial class IsccLib
             try
             {
                 if (!result.ok)
-                    throw new IsccException(GetLastError());
+                    throw new IsccException(GetLastError(), GetLastErrorCode());
 
                 byte[] digestBytes = new Span<byte>(
                     result.digest.data, (int)result.digest.len).ToArray();
@@ -629,7 +634,7 @@ public static partial class IsccLib
     internal static unsafe string ConsumeNativeString(byte* ptr)
     {
         if (ptr is null)
-            throw new IsccException(GetLastError());
+            throw new IsccException(GetLastError(), GetLastErrorCode());
         string result = Marshal.PtrToStringUTF8((IntPtr)ptr) ?? string.Empty;
         NativeMethods.iscc_free_string(ptr);
         return result;
@@ -639,7 +644,7 @@ public static partial class IsccLib
     private static unsafe byte[] ConsumeByteBuffer(IsccByteBuffer buf)
     {
         if (buf.data is null)
-            throw new IsccException(GetLastError());
+            throw new IsccException(GetLastError(), GetLastErrorCode());
         try
         {
             return new Span<byte>(buf.data, (int)buf.len).ToArray();
@@ -654,7 +659,7 @@ public static partial class IsccLib
     private static unsafe byte[][] ConsumeByteBufferArray(IsccByteBufferArray arr)
     {
         if (arr.buffers is null)
-            throw new IsccException(GetLastError());
+            throw new IsccException(GetLastError(), GetLastErrorCode());
         try
         {
             byte[][] result = new byte[(int)arr.count][];
@@ -675,7 +680,7 @@ public static partial class IsccLib
     private static unsafe string[] ConsumeNativeStringArray(byte** arr)
     {
         if (arr is null)
-            throw new IsccException(GetLastError());
+            throw new IsccException(GetLastError(), GetLastErrorCode());
         try
         {
             var list = new List<string>();
@@ -697,4 +702,11 @@ public static partial class IsccLib
             return "Unknown ISCC error";
         return Marshal.PtrToStringUTF8((IntPtr)err) ?? "Unknown ISCC error";
     }
+
+    /// <summary>Read the stable code of the last error from the native library.</summary>
+    internal static unsafe string? GetLastErrorCode()
+    {
+        byte* code = NativeMethods.iscc_last_error_code();
+        return code is null ? null : Marshal.PtrToStringUTF8((IntPtr)code);
+    }
 }
@@ -0,0 +1,89 @@
+// Streaming ISCC-SUM hasher — wraps the native FfiSumHasher via SafeHandle for safe disposal.
+
+using System.Runtime.InteropServices;
+
+namespace Iscc.Lib;
+
+/// <summary>Streaming hasher for generating ISCC-SUMs (Data-Code + Instance-Code) incrementally.</summary>
+/// <remarks>This type is not thread-safe. Do not call methods from multiple threads concurrently.</remarks>
+public sealed class IsccSumHasher : IDisposable
+{
+    private readonly SumHasherHandle _handle;
+    private bool _finalized;
+
+    /// <summary>Create a new streaming ISCC-SUM hasher.</summary>
+    public IsccSumHasher()
+    {
+        unsafe
+        {
+            _handle = new SumHasherHandle(NativeMethods.iscc_sum_hasher_new());
+        }
+    }
+
+    /// <summary>Feed data into the hasher. May be called multiple times before Finalize.</summary>
+    public void Update(ReadOnlySpan<byte> data)
+    {
+        ObjectDisposedException.ThrowIf(_handle.IsInvalid || _handle.IsClosed, this);
+        if (_finalized)
+            throw new InvalidOperationException("Hasher already finalized");
+
+        unsafe
+        {
+            fixed (byte* pData = data)
+            {
+                bool ok = NativeMethods.iscc_sum_hasher_update(
+                    (FfiSumHasher*)(void*)_handle.DangerousGetHandle(),
+                    pData, (nuint)data.Length);
+                if (!ok)
+                    throw new IsccException(IsccLib.GetLastError(), IsccLib.GetLastErrorCode());
+            }
+        }
+    }
+
+    /// <summary>Finalize the hasher and return the ISCC-SUM result.</summary>
+    public SumCodeResult Finalize(uint bits = 64, bool wide = false, bool addUnits = false)
+    {
+        ObjectDisposedException.ThrowIf(_handle.IsInvalid || _handle.IsClosed, this);
+        if (_finalized)
+            throw new InvalidOperationException("Hasher already finalized");
+
+        _finalized = true;
+        unsafe
+        {
+            IsccSumCodeResult result = NativeMethods.iscc_sum_hasher_finalize(
+                (FfiSumHasher*)(void*)_handle.DangerousGetHandle(), bits, wide, addUnits);
+            return IsccLib.ConsumeSumCodeResult(result);
+        }
+    }
+
+    /// <summary>Release the native hasher resources.</summary>
+    public void Dispose()
+    {
+        _handle.Dispose();
+    }
+
+    /// <summary>SafeHandle wrapper for the opaque FfiSumHasher pointer.</summary>
+    private sealed class SumHasherHandle : SafeHandle
+    {
+        /// <summary>Wrap a native FfiSumHasher pointer for safe disposal.</summary>
+        public unsafe SumHasherHandle(FfiSumHasher* ptr)
+            : base(IntPtr.Zero, ownsHandle: true)
+        {
+            SetHandle((IntPtr)ptr);
+        }
+
+        /// <summary>Whether the handle is invalid (null pointer).</summary>
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        /// <summary>Free the native FfiSumHasher.</summary>
+        protected override bool ReleaseHandle()
+        {
+            unsafe
+            {
+                NativeMethods.iscc_sum_hasher_free(
+                    (FfiSumHasher*)(void*)handle);
+            }
+            return true;
+        }
+    }
+}
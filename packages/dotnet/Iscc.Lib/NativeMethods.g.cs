@@ -412,8 +412,8 @@ namespace Iscc.Lib
         /// <summary>
         ///  Generate a composite ISCC-CODE from a file path (Data-Code + Instance-Code).
         ///
-        ///  Single-pass file I/O feeds both DataHasher and InstanceHasher, then composes
-        ///  the ISCC-CODE internally.
+        ///  Single-pass file I/O feeds a SumHasher (DataHasher and InstanceHasher),
+        ///  then composes the ISCC-CODE internally.
         ///
         ///  # Parameters
         ///
@@ -435,13 +435,15 @@ namespace Iscc.Lib
         internal static extern IsccSumCodeResult iscc_gen_sum_code_v0(byte* path, uint bits, [MarshalAs(UnmanagedType.U1)] bool wide, [MarshalAs(UnmanagedType.U1)] bool add_units);
 
         /// <summary>
-        ///  Free an `IsccSumCodeResult` previously returned by `iscc_gen_sum_code_v0`.
+        ///  Free an `IsccSumCodeResult` previously returned by `iscc_gen_sum_code_v0`
+        ///  or `iscc_sum_hasher_finalize`.
         ///
         ///  Releases the `iscc`, `datahash` strings, and `units` array. No-op for NULL pointers.
         ///
         ///  # Safety
         ///
-        ///  `result` must be a value returned by `iscc_gen_sum_code_v0`.
+        ///  `result` must be a value returned by `iscc_gen_sum_code_v0` or
+        ///  `iscc_sum_hasher_finalize`.
         ///  Each result must only be freed once.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "iscc_free_sum_code_result", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
@@ -726,6 +728,123 @@ namespace Iscc.Lib
         [DllImport(__DllName, EntryPoint = "iscc_instance_hasher_free", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern void iscc_instance_hasher_free(FfiInstanceHasher* hasher);
 
+        /// <summary>
+        ///  Create a new streaming ISCC-SUM hasher.
+        ///
+        ///  Returns an opaque pointer. The caller must eventually call
+        ///  `iscc_sum_hasher_free()` to release the memory.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_sum_hasher_new", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern FfiSumHasher* iscc_sum_hasher_new();
+
+        /// <summary>
+        ///  Push data into a streaming SumHasher.
+        ///
+        ///  Returns `true` on success, `false` on error (e.g., already finalized
+        ///  or NULL pointer). Check `iscc_last_error()` for the error message.
+        ///
+        ///  # Safety
+        ///
+        ///  - `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+        ///  - `data` must point to at least `data_len` valid bytes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_sum_hasher_update", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static extern bool iscc_sum_hasher_update(FfiSumHasher* hasher, byte* data, nuint data_len);
+
+        /// <summary>
+        ///  Finalize a streaming SumHasher and return an ISCC-SUM result.
+        ///
+        ///  Consumes the inner hasher state. After this call, subsequent `update`
+        ///  or `finalize` calls will fail. The caller must still call
+        ///  `iscc_sum_hasher_free()` to release the wrapper, and
+        ///  `iscc_free_sum_code_result()` to release the returned result.
+        ///
+        ///  # Safety
+        ///
+        ///  `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_sum_hasher_finalize", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern IsccSumCodeResult iscc_sum_hasher_finalize(FfiSumHasher* hasher, uint bits, [MarshalAs(UnmanagedType.U1)] bool wide, [MarshalAs(UnmanagedType.U1)] bool add_units);
+
+        /// <summary>
+        ///  Free a SumHasher previously created by `iscc_sum_hasher_new()`.
+        ///
+        ///  NULL is a no-op. Each pointer must be freed exactly once.
+        ///
+        ///  # Safety
+        ///
+        ///  `hasher` must be a valid pointer from `iscc_sum_hasher_new()`, or NULL.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_sum_hasher_free", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void iscc_sum_hasher_free(FfiSumHasher* hasher);
+
+        /// <summary>
+        ///  Open an index file written by `iscc_lib` for querying.
+        ///
+        ///  The file is memory-mapped where supported. Returns an opaque pointer,
+        ///  or `NULL` on error (check `iscc_last_error()`). The caller must
+        ///  eventually call `iscc_index_file_free()` to release it.
+        ///
+        ///  # Safety
+        ///
+        ///  `path` must point to a valid null-terminated UTF-8 string, or be null.
+        ///
+        ///  The file is mapped, so it must not be truncated or modified in place
+        ///  until the handle is freed. Appending segments and compacting (which
+        ///  replaces the file via rename) are fine, except the first append after
+        ///  a crashed one, which truncates the torn tail.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_index_file_open", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern FfiIndexFile* iscc_index_file_open(byte* path);
+
+        /// <summary>
+        ///  Return the number of live units in an opened index file.
+        ///
+        ///  Returns 0 for a NULL handle.
+        ///
+        ///  # Safety
+        ///
+        ///  `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_index_file_len", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern nuint iscc_index_file_len(FfiIndexFile* index);
+
+        /// <summary>
+        ///  Return the `k` units nearest to an ISCC-UNIT query.
+        ///
+        ///  # Safety
+        ///
+        ///  - `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+        ///  - `query` must point to a valid null-terminated UTF-8 string, or be null.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_index_file_knn", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern IsccIndexMatchArray iscc_index_file_knn(FfiIndexFile* index, byte* query, nuint k);
+
+        /// <summary>
+        ///  Return all units within Hamming distance `max_distance` of an ISCC-UNIT
+        ///  query.
+        ///
+        ///  # Safety
+        ///
+        ///  - `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+        ///  - `query` must point to a valid null-terminated UTF-8 string, or be null.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_index_file_radius", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern IsccIndexMatchArray iscc_index_file_radius(FfiIndexFile* index, byte* query, uint max_distance);
+
+        /// <summary>
+        ///  Free an index file previously opened by `iscc_index_file_open()`.
+        ///
+        ///  NULL is a no-op. Each pointer must be freed exactly once.
+        ///
+        ///  # Safety
+        ///
+        ///  `index` must be a valid pointer from `iscc_index_file_open()`, or NULL.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_index_file_free", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void iscc_index_file_free(FfiIndexFile* index);
+
         /// <summary>
         ///  Free a string previously returned by any `iscc_gen_*` function.
         ///
@@ -780,6 +899,20 @@ namespace Iscc.Lib
         [DllImport(__DllName, EntryPoint = "iscc_free_byte_buffer_array", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern void iscc_free_byte_buffer_array(IsccByteBufferArray arr);
 
+        /// <summary>
+        ///  Free a match array returned by `iscc_index_file_knn` or
+        ///  `iscc_index_file_radius`.
+        ///
+        ///  No-op if `arr.matches` is `NULL`.
+        ///
+        ///  # Safety
+        ///
+        ///  `arr` must be a value returned by an index file query.
+        ///  Each array must only be freed once.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_free_index_match_array", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void iscc_free_index_match_array(IsccIndexMatchArray arr);
+
         /// <summary>
         ///  Return the last error message from the current thread.
         ///
@@ -792,6 +925,18 @@ namespace Iscc.Lib
         [DllImport(__DllName, EntryPoint = "iscc_last_error", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern byte* iscc_last_error();
 
+        /// <summary>
+        ///  Return the stable code of the last error from the current thread.
+        ///
+        ///  Codes match `IsccError::code()` in the Rust core (e.g. `"truncated"`,
+        ///  `"invalid_bits"`, `"io"`). Returns `NULL` if no error has occurred.
+        ///  Same lifetime rules as `iscc_last_error()`.
+        ///
+        ///  The returned pointer must NOT be freed by the caller.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "iscc_last_error_code", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern byte* iscc_last_error_code();
+
 
     }
 
@@ -924,6 +1069,71 @@ namespace Iscc.Lib
     {
     }
 
+    /// <summary>
+    ///  Opaque FFI wrapper around `iscc_lib::SumHasher`.
+    ///
+    ///  Enforces finalize-once semantics via `Option&lt;Inner&gt;`. Not `#[repr(C)]` —
+    ///  C callers interact only through function pointers.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct FfiSumHasher
+    {
+    }
+
+    /// <summary>
+    ///  Opaque handle to an opened `iscc_lib::IndexFile`.
+    ///
+    ///  Not `#[repr(C)]` — C callers interact only through function pointers.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct FfiIndexFile
+    {
+    }
+
+    /// <summary>
+    ///  One match of an index file query.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct IsccIndexMatch
+    {
+        /// <summary>
+        ///  External ID the unit was stored under.
+        /// </summary>
+        public ulong id;
+        /// <summary>
+        ///  Hamming distance to the query.
+        /// </summary>
+        public uint distance;
+        /// <summary>
+        ///  Number of digest bits compared.
+        /// </summary>
+        public uint bits;
+    }
+
+    /// <summary>
+    ///  Array of index matches sorted by distance, then ID.
+    ///
+    ///  On success, `ok` is `true` and `matches` points to `count` elements
+    ///  (`NULL` if `count` is 0). On error, `ok` is `false`. Callers must free
+    ///  with `iscc_free_index_match_array()`.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct IsccIndexMatchArray
+    {
+        /// <summary>
+        ///  Whether the query succeeded.
+        /// </summary>
+        [MarshalAs(UnmanagedType.U1)] public bool ok;
+        /// <summary>
+        ///  Pointer to the matches.
+        /// </summary>
+        public IsccIndexMatch* matches;
+        /// <summary>
+        ///  Number of matches.
+        /// </summary>
+        public nuint count;
+    }
+
 
 
 }
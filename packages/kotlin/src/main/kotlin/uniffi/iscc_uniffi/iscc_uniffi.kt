@@ -704,10 +704,16 @@ internal object IntegrityCheckingUniffiLib {
     ): Short
     external fun uniffi_iscc_uniffi_checksum_method_instancehasher_update(
     ): Short
+    external fun uniffi_iscc_uniffi_checksum_method_sumhasher_finalize(
+    ): Short
+    external fun uniffi_iscc_uniffi_checksum_method_sumhasher_update(
+    ): Short
     external fun uniffi_iscc_uniffi_checksum_constructor_datahasher_new(
     ): Short
     external fun uniffi_iscc_uniffi_checksum_constructor_instancehasher_new(
     ): Short
+    external fun uniffi_iscc_uniffi_checksum_constructor_sumhasher_new(
+    ): Short
     external fun ffi_iscc_uniffi_uniffi_contract_version(
     ): Int
 
@@ -746,6 +752,16 @@ external fun uniffi_iscc_uniffi_fn_method_instancehasher_finalize(`ptr`: Long,`b
 ): RustBuffer.ByValue
 external fun uniffi_iscc_uniffi_fn_method_instancehasher_update(`ptr`: Long,`data`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus,
 ): Unit
+external fun uniffi_iscc_uniffi_fn_clone_sumhasher(`handle`: Long,uniffi_out_err: UniffiRustCallStatus,
+): Long
+external fun uniffi_iscc_uniffi_fn_free_sumhasher(`handle`: Long,uniffi_out_err: UniffiRustCallStatus,
+): Unit
+external fun uniffi_iscc_uniffi_fn_constructor_sumhasher_new(uniffi_out_err: UniffiRustCallStatus,
+): Long
+external fun uniffi_iscc_uniffi_fn_method_sumhasher_finalize(`ptr`: Long,`bits`: Int,`wide`: Byte,`addUnits`: Byte,uniffi_out_err: UniffiRustCallStatus,
+): RustBuffer.ByValue
+external fun uniffi_iscc_uniffi_fn_method_sumhasher_update(`ptr`: Long,`data`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus,
+): Unit
 external fun uniffi_iscc_uniffi_fn_func_alg_cdc_chunks(`data`: RustBuffer.ByValue,`utf32`: Byte,`avgChunkSize`: Int,uniffi_out_err: UniffiRustCallStatus,
 ): RustBuffer.ByValue
 external fun uniffi_iscc_uniffi_fn_func_alg_minhash_256(`features`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus,
@@ -1027,12 +1043,21 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_iscc_uniffi_checksum_method_instancehasher_update() != 51907.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_iscc_uniffi_checksum_method_sumhasher_finalize() != 57715.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_iscc_uniffi_checksum_method_sumhasher_update() != 13068.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_iscc_uniffi_checksum_constructor_datahasher_new() != 61879.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_iscc_uniffi_checksum_constructor_instancehasher_new() != 15676.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_iscc_uniffi_checksum_constructor_sumhasher_new() != 31912.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
 }
 
 /**
@@ -1784,12 +1809,313 @@ public object FfiConverterTypeDataHasher: FfiConverter<DataHasher, Long> {
  * Incrementally hashes data with BLAKE3 to produce an Instance-Code.
  * Call `update()` with data chunks, then `finalize()` to produce the code.
  */
-public interface InstanceHasherInterface {
+public interface InstanceHasherInterface {
+
+    /**
+     * Consume the inner hasher and produce an Instance-Code result.
+     */
+    fun `finalize`(`bits`: kotlin.UInt): InstanceCodeResult
+
+    /**
+     * Push data into the hasher.
+     */
+    fun `update`(`data`: kotlin.ByteArray)
+
+    companion object
+}
+
+/**
+ * Streaming Instance-Code generator.
+ *
+ * Incrementally hashes data with BLAKE3 to produce an Instance-Code.
+ * Call `update()` with data chunks, then `finalize()` to produce the code.
+ */
+open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    /**
+     * Create a new `InstanceHasher`.
+     */
+    constructor() :
+        this(UniffiWithHandle,
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_iscc_uniffi_fn_constructor_instancehasher_new(
+
+        _status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_iscc_uniffi_fn_free_instancehasher(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_iscc_uniffi_fn_clone_instancehasher(handle, status)
+        }
+    }
+
+
+    /**
+     * Consume the inner hasher and produce an Instance-Code result.
+     */
+    @Throws(IsccUniException::class)override fun `finalize`(`bits`: kotlin.UInt): InstanceCodeResult {
+            return FfiConverterTypeInstanceCodeResult.lift(
+    callWithHandle {
+    uniffiRustCallWithError(IsccUniException) { _status ->
+    UniffiLib.uniffi_iscc_uniffi_fn_method_instancehasher_finalize(
+        it,
+        FfiConverterUInt.lower(`bits`),_status)
+}
+    }
+    )
+    }
+
+
+
+    /**
+     * Push data into the hasher.
+     */
+    @Throws(IsccUniException::class)override fun `update`(`data`: kotlin.ByteArray)
+        =
+    callWithHandle {
+    uniffiRustCallWithError(IsccUniException) { _status ->
+    UniffiLib.uniffi_iscc_uniffi_fn_method_instancehasher_update(
+        it,
+        FfiConverterByteArray.lower(`data`),_status)
+}
+    }
+
+
+
+
+
+
+
+
+
+
+    /**
+     * @suppress
+     */
+    companion object
+
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeInstanceHasher: FfiConverter<InstanceHasher, Long> {
+    override fun lower(value: InstanceHasher): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): InstanceHasher {
+        return InstanceHasher(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): InstanceHasher {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: InstanceHasher) = 8UL
+
+    override fun write(value: InstanceHasher, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+/**
+ * Streaming ISCC-SUM generator.
+ *
+ * Feeds data to both a Data-Code and an Instance-Code hasher and composes
+ * them on `finalize()`, matching `gen_sum_code_v0` without a file path.
+ */
+public interface SumHasherInterface {
 
     /**
-     * Consume the inner hasher and produce an Instance-Code result.
+     * Consume the inner hasher and produce an ISCC-SUM result.
      */
-    fun `finalize`(`bits`: kotlin.UInt): InstanceCodeResult
+    fun `finalize`(`bits`: kotlin.UInt, `wide`: kotlin.Boolean, `addUnits`: kotlin.Boolean): SumCodeResult
 
     /**
      * Push data into the hasher.
@@ -1800,12 +2126,12 @@ public interface InstanceHasherInterface {
 }
 
 /**
- * Streaming Instance-Code generator.
+ * Streaming ISCC-SUM generator.
  *
- * Incrementally hashes data with BLAKE3 to produce an Instance-Code.
- * Call `update()` with data chunks, then `finalize()` to produce the code.
+ * Feeds data to both a Data-Code and an Instance-Code hasher and composes
+ * them on `finalize()`, matching `gen_sum_code_v0` without a file path.
  */
-open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
+open class SumHasher: Disposable, AutoCloseable, SumHasherInterface
 {
 
     @Suppress("UNUSED_PARAMETER")
@@ -1830,12 +2156,12 @@ open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
         this.cleanable = null
     }
     /**
-     * Create a new `InstanceHasher`.
+     * Create a new `SumHasher`.
      */
     constructor() :
         this(UniffiWithHandle,
     uniffiRustCall() { _status ->
-    UniffiLib.uniffi_iscc_uniffi_fn_constructor_instancehasher_new(
+    UniffiLib.uniffi_iscc_uniffi_fn_constructor_sumhasher_new(
 
         _status)
 }
@@ -1895,7 +2221,7 @@ open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
                 return;
             }
             uniffiRustCall { status ->
-                UniffiLib.uniffi_iscc_uniffi_fn_free_instancehasher(handle, status)
+                UniffiLib.uniffi_iscc_uniffi_fn_free_sumhasher(handle, status)
             }
         }
     }
@@ -1908,21 +2234,21 @@ open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
             throw InternalException("uniffiCloneHandle() called on NoHandle object");
         }
         return uniffiRustCall() { status ->
-            UniffiLib.uniffi_iscc_uniffi_fn_clone_instancehasher(handle, status)
+            UniffiLib.uniffi_iscc_uniffi_fn_clone_sumhasher(handle, status)
         }
     }
 
 
     /**
-     * Consume the inner hasher and produce an Instance-Code result.
+     * Consume the inner hasher and produce an ISCC-SUM result.
      */
-    @Throws(IsccUniException::class)override fun `finalize`(`bits`: kotlin.UInt): InstanceCodeResult {
-            return FfiConverterTypeInstanceCodeResult.lift(
+    @Throws(IsccUniException::class)override fun `finalize`(`bits`: kotlin.UInt, `wide`: kotlin.Boolean, `addUnits`: kotlin.Boolean): SumCodeResult {
+            return FfiConverterTypeSumCodeResult.lift(
     callWithHandle {
     uniffiRustCallWithError(IsccUniException) { _status ->
-    UniffiLib.uniffi_iscc_uniffi_fn_method_instancehasher_finalize(
+    UniffiLib.uniffi_iscc_uniffi_fn_method_sumhasher_finalize(
         it,
-        FfiConverterUInt.lower(`bits`),_status)
+        FfiConverterUInt.lower(`bits`),FfiConverterBoolean.lower(`wide`),FfiConverterBoolean.lower(`addUnits`),_status)
 }
     }
     )
@@ -1937,7 +2263,7 @@ open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
         =
     callWithHandle {
     uniffiRustCallWithError(IsccUniException) { _status ->
-    UniffiLib.uniffi_iscc_uniffi_fn_method_instancehasher_update(
+    UniffiLib.uniffi_iscc_uniffi_fn_method_sumhasher_update(
         it,
         FfiConverterByteArray.lower(`data`),_status)
 }
@@ -1963,22 +2289,22 @@ open class InstanceHasher: Disposable, AutoCloseable, InstanceHasherInterface
 /**
  * @suppress
  */
-public object FfiConverterTypeInstanceHasher: FfiConverter<InstanceHasher, Long> {
-    override fun lower(value: InstanceHasher): Long {
+public object FfiConverterTypeSumHasher: FfiConverter<SumHasher, Long> {
+    override fun lower(value: SumHasher): Long {
         return value.uniffiCloneHandle()
     }
 
-    override fun lift(value: Long): InstanceHasher {
-        return InstanceHasher(UniffiWithHandle, value)
+    override fun lift(value: Long): SumHasher {
+        return SumHasher(UniffiWithHandle, value)
     }
 
-    override fun read(buf: ByteBuffer): InstanceHasher {
+    override fun read(buf: ByteBuffer): SumHasher {
         return lift(buf.getLong())
     }
 
-    override fun allocationSize(value: InstanceHasher) = 8UL
+    override fun allocationSize(value: SumHasher) = 8UL
 
-    override fun write(value: InstanceHasher, buf: ByteBuffer) {
+    override fun write(value: SumHasher, buf: ByteBuffer) {
         buf.putLong(lower(value))
     }
 }
@@ -2537,12 +2863,15 @@ public object FfiConverterTypeVideoCodeResult: FfiConverterRustBuffer<VideoCodeR
 
 
 /**
- * UniFFI-compatible error type wrapping `iscc_lib::IsccError`.
+ * UniFFI-compatible error type mirroring `iscc_lib::IsccError`.
+ *
+ * Each variant carries the formatted message in `msg`; variants with
+ * structured data in the core expose those fields as well.
  */
 sealed class IsccUniException: kotlin.Exception() {
 
     /**
-     * ISCC operation error with a descriptive message.
+     * Other ISCC operation error with a descriptive message.
      */
     class IsccException(
 
@@ -2555,6 +2884,176 @@ sealed class IsccUniException: kotlin.Exception() {
             get() = "msg=${ `msg` }"
     }
 
+    /**
+     * Invalid input parameters or data.
+     */
+    class InvalidInput(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
+    /**
+     * I/O failure while reading input.
+     */
+    class Io(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
+    /**
+     * Malformed base32 data.
+     */
+    class Base32(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
+    /**
+     * Invalid ISCC header field.
+     */
+    class Header(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
+    /**
+     * Input shorter than its header declares.
+     */
+    class Truncated(
+
+        /**
+         * Number of bytes required.
+         */
+        val `expected`: kotlin.ULong,
+
+        /**
+         * Number of bytes available.
+         */
+        val `actual`: kotlin.ULong,
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "expected=${ `expected` }, actual=${ `actual` }, msg=${ `msg` }"
+    }
+
+    /**
+     * Unsupported bit length.
+     */
+    class InvalidBits(
+
+        /**
+         * Requested bit length.
+         */
+        val `bits`: kotlin.UInt,
+
+        /**
+         * Description of the accepted values.
+         */
+        val `expected`: kotlin.String,
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "bits=${ `bits` }, expected=${ `expected` }, msg=${ `msg` }"
+    }
+
+    /**
+     * Meta payload exceeds the size limit.
+     */
+    class MetaTooLarge(
+
+        /**
+         * Payload size in bytes.
+         */
+        val `size`: kotlin.ULong,
+
+        /**
+         * Maximum allowed size in bytes.
+         */
+        val `limit`: kotlin.ULong,
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "size=${ `size` }, limit=${ `limit` }, msg=${ `msg` }"
+    }
+
+    /**
+     * Malformed JSON input.
+     */
+    class InvalidJson(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
+    /**
+     * Valid but unsupported input.
+     */
+    class Unsupported(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
+    /**
+     * ISCC string failed validation.
+     */
+    class Validation(
+
+        /**
+         * Error description.
+         */
+        val `msg`: kotlin.String
+        ) : IsccUniException() {
+        override val message
+            get() = "msg=${ `msg` }"
+    }
+
 
 
 
@@ -2577,6 +3076,42 @@ public object FfiConverterTypeIsccUniError : FfiConverterRustBuffer<IsccUniExcep
             1 -> IsccUniException.IsccException(
                 FfiConverterString.read(buf),
                 )
+            2 -> IsccUniException.InvalidInput(
+                FfiConverterString.read(buf),
+                )
+            3 -> IsccUniException.Io(
+                FfiConverterString.read(buf),
+                )
+            4 -> IsccUniException.Base32(
+                FfiConverterString.read(buf),
+                )
+            5 -> IsccUniException.Header(
+                FfiConverterString.read(buf),
+                )
+            6 -> IsccUniException.Truncated(
+                FfiConverterULong.read(buf),
+                FfiConverterULong.read(buf),
+                FfiConverterString.read(buf),
+                )
+            7 -> IsccUniException.InvalidBits(
+                FfiConverterUInt.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
+                )
+            8 -> IsccUniException.MetaTooLarge(
+                FfiConverterULong.read(buf),
+                FfiConverterULong.read(buf),
+                FfiConverterString.read(buf),
+                )
+            9 -> IsccUniException.InvalidJson(
+                FfiConverterString.read(buf),
+                )
+            10 -> IsccUniException.Unsupported(
+                FfiConverterString.read(buf),
+                )
+            11 -> IsccUniException.Validation(
+                FfiConverterString.read(buf),
+                )
             else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
     }
@@ -2588,6 +3123,62 @@ public object FfiConverterTypeIsccUniError : FfiConverterRustBuffer<IsccUniExcep
                 4UL
                 + FfiConverterString.allocationSize(value.`msg`)
             )
+            is IsccUniException.InvalidInput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.Io -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.Base32 -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.Header -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.Truncated -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterULong.allocationSize(value.`expected`)
+                + FfiConverterULong.allocationSize(value.`actual`)
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.InvalidBits -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`bits`)
+                + FfiConverterString.allocationSize(value.`expected`)
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.MetaTooLarge -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterULong.allocationSize(value.`size`)
+                + FfiConverterULong.allocationSize(value.`limit`)
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.InvalidJson -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.Unsupported -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
+            is IsccUniException.Validation -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`msg`)
+            )
         }
     }
 
@@ -2598,6 +3189,62 @@ public object FfiConverterTypeIsccUniError : FfiConverterRustBuffer<IsccUniExcep
                 FfiConverterString.write(value.`msg`, buf)
                 Unit
             }
+            is IsccUniException.InvalidInput -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.Io -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.Base32 -> {
+                buf.putInt(4)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.Header -> {
+                buf.putInt(5)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.Truncated -> {
+                buf.putInt(6)
+                FfiConverterULong.write(value.`expected`, buf)
+                FfiConverterULong.write(value.`actual`, buf)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.InvalidBits -> {
+                buf.putInt(7)
+                FfiConverterUInt.write(value.`bits`, buf)
+                FfiConverterString.write(value.`expected`, buf)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.MetaTooLarge -> {
+                buf.putInt(8)
+                FfiConverterULong.write(value.`size`, buf)
+                FfiConverterULong.write(value.`limit`, buf)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.InvalidJson -> {
+                buf.putInt(9)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.Unsupported -> {
+                buf.putInt(10)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
+            is IsccUniException.Validation -> {
+                buf.putInt(11)
+                FfiConverterString.write(value.`msg`, buf)
+                Unit
+            }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
 
@@ -3212,3 +3859,6 @@ public object FfiConverterSequenceSequenceInt: FfiConverterRustBuffer<List<List<
 }
     )
     }
+
+
+
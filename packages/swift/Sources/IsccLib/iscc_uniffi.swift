@@ -886,6 +886,169 @@ public func FfiConverterTypeInstanceHasher_lower(_ value: InstanceHasher) -> UIn
 
 
 
+
+
+/**
+ * Streaming ISCC-SUM generator.
+ *
+ * Feeds data to both a Data-Code and an Instance-Code hasher and composes
+ * them on `finalize()`, matching `gen_sum_code_v0` without a file path.
+ */
+public protocol SumHasherProtocol: AnyObject, Sendable {
+
+    /**
+     * Consume the inner hasher and produce an ISCC-SUM result.
+     */
+    func finalize(bits: UInt32, wide: Bool, addUnits: Bool) throws  -> SumCodeResult
+
+    /**
+     * Push data into the hasher.
+     */
+    func update(data: Data) throws
+
+}
+/**
+ * Streaming ISCC-SUM generator.
+ *
+ * Feeds data to both a Data-Code and an Instance-Code hasher and composes
+ * them on `finalize()`, matching `gen_sum_code_v0` without a file path.
+ */
+open class SumHasher: SumHasherProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_iscc_uniffi_fn_clone_sumhasher(self.handle, $0) }
+    }
+    /**
+     * Create a new `SumHasher`.
+     */
+public convenience init() {
+    let handle =
+        try! rustCall() {
+    uniffi_iscc_uniffi_fn_constructor_sumhasher_new($0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_iscc_uniffi_fn_free_sumhasher(handle, $0) }
+    }
+
+
+
+
+    /**
+     * Consume the inner hasher and produce an ISCC-SUM result.
+     */
+open func finalize(bits: UInt32, wide: Bool, addUnits: Bool)throws  -> SumCodeResult  {
+    return try  FfiConverterTypeSumCodeResult_lift(try rustCallWithError(FfiConverterTypeIsccUniError_lift) {
+    uniffi_iscc_uniffi_fn_method_sumhasher_finalize(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(bits),
+        FfiConverterBool.lower(wide),
+        FfiConverterBool.lower(addUnits),$0
+    )
+})
+}
+
+    /**
+     * Push data into the hasher.
+     */
+open func update(data: Data)throws   {try rustCallWithError(FfiConverterTypeIsccUniError_lift) {
+    uniffi_iscc_uniffi_fn_method_sumhasher_update(
+            self.uniffiCloneHandle(),
+        FfiConverterData.lower(data),$0
+    )
+}
+}
+
+
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSumHasher: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = SumHasher
+
+    public static func lift(_ handle: UInt64) throws -> SumHasher {
+        return SumHasher(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: SumHasher) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SumHasher {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: SumHasher, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSumHasher_lift(_ handle: UInt64) throws -> SumHasher {
+    return try FfiConverterTypeSumHasher.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSumHasher_lower(_ value: SumHasher) -> UInt64 {
+    return FfiConverterTypeSumHasher.lower(value)
+}
+
+
+
+
 /**
  * Result of `gen_audio_code_v0`.
  */
@@ -1686,20 +1849,121 @@ public func FfiConverterTypeVideoCodeResult_lower(_ value: VideoCodeResult) -> R
 
 
 /**
- * UniFFI-compatible error type wrapping `iscc_lib::IsccError`.
+ * UniFFI-compatible error type mirroring `iscc_lib::IsccError`.
+ *
+ * Each variant carries the formatted message in `msg`; variants with
+ * structured data in the core expose those fields as well.
  */
 public enum IsccUniError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
 
 
 
     /**
-     * ISCC operation error with a descriptive message.
+     * Other ISCC operation error with a descriptive message.
      */
     case IsccError(
         /**
          * Error description.
          */msg: String
     )
+    /**
+     * Invalid input parameters or data.
+     */
+    case InvalidInput(
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * I/O failure while reading input.
+     */
+    case Io(
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Malformed base32 data.
+     */
+    case Base32(
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Invalid ISCC header field.
+     */
+    case Header(
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Input shorter than its header declares.
+     */
+    case Truncated(
+        /**
+         * Number of bytes required.
+         */expected: UInt64,
+        /**
+         * Number of bytes available.
+         */actual: UInt64,
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Unsupported bit length.
+     */
+    case InvalidBits(
+        /**
+         * Requested bit length.
+         */bits: UInt32,
+        /**
+         * Description of the accepted values.
+         */expected: String,
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Meta payload exceeds the size limit.
+     */
+    case MetaTooLarge(
+        /**
+         * Payload size in bytes.
+         */size: UInt64,
+        /**
+         * Maximum allowed size in bytes.
+         */limit: UInt64,
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Malformed JSON input.
+     */
+    case InvalidJson(
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * Valid but unsupported input.
+     */
+    case Unsupported(
+        /**
+         * Error description.
+         */msg: String
+    )
+    /**
+     * ISCC string failed validation.
+     */
+    case Validation(
+        /**
+         * Error description.
+         */msg: String
+    )
 
 
 
@@ -1732,6 +1996,42 @@ public struct FfiConverterTypeIsccUniError: FfiConverterRustBuffer {
         case 1: return .IsccError(
             msg: try FfiConverterString.read(from: &buf)
             )
+        case 2: return .InvalidInput(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .Io(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .Base32(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .Header(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .Truncated(
+            expected: try FfiConverterUInt64.read(from: &buf),
+            actual: try FfiConverterUInt64.read(from: &buf),
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .InvalidBits(
+            bits: try FfiConverterUInt32.read(from: &buf),
+            expected: try FfiConverterString.read(from: &buf),
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 8: return .MetaTooLarge(
+            size: try FfiConverterUInt64.read(from: &buf),
+            limit: try FfiConverterUInt64.read(from: &buf),
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 9: return .InvalidJson(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 10: return .Unsupported(
+            msg: try FfiConverterString.read(from: &buf)
+            )
+        case 11: return .Validation(
+            msg: try FfiConverterString.read(from: &buf)
+            )
 
          default: throw UniffiInternalError.unexpectedEnumCase
         }
@@ -1748,6 +2048,62 @@ public struct FfiConverterTypeIsccUniError: FfiConverterRustBuffer {
             writeInt(&buf, Int32(1))
             FfiConverterString.write(msg, into: &buf)
 
+
+        case let .InvalidInput(msg):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .Io(msg):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .Base32(msg):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .Header(msg):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .Truncated(expected,actual,msg):
+            writeInt(&buf, Int32(6))
+            FfiConverterUInt64.write(expected, into: &buf)
+            FfiConverterUInt64.write(actual, into: &buf)
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .InvalidBits(bits,expected,msg):
+            writeInt(&buf, Int32(7))
+            FfiConverterUInt32.write(bits, into: &buf)
+            FfiConverterString.write(expected, into: &buf)
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .MetaTooLarge(size,limit,msg):
+            writeInt(&buf, Int32(8))
+            FfiConverterUInt64.write(size, into: &buf)
+            FfiConverterUInt64.write(limit, into: &buf)
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .InvalidJson(msg):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .Unsupported(msg):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(msg, into: &buf)
+
+
+        case let .Validation(msg):
+            writeInt(&buf, Int32(11))
+            FfiConverterString.write(msg, into: &buf)
+
         }
     }
 }
@@ -2374,12 +2730,21 @@ private let initializationResult: InitializationResult = {
     if (uniffi_iscc_uniffi_checksum_method_instancehasher_update() != 51907) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_iscc_uniffi_checksum_method_sumhasher_finalize() != 57715) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_iscc_uniffi_checksum_method_sumhasher_update() != 13068) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_iscc_uniffi_checksum_constructor_datahasher_new() != 61879) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_iscc_uniffi_checksum_constructor_instancehasher_new() != 15676) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_iscc_uniffi_checksum_constructor_sumhasher_new() != 31912) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
     return InitializationResult.ok
 }()
@@ -2397,4 +2762,4 @@ public func uniffiEnsureIsccUniffiInitialized() {
     }
 }
 
-// swiftlint:enable all
+// swiftlint:enable all
\ No newline at end of file
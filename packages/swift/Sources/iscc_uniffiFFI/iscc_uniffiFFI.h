@@ -295,6 +295,32 @@ RustBuffer uniffi_iscc_uniffi_fn_method_instancehasher_finalize(uint64_t ptr, ui
 void uniffi_iscc_uniffi_fn_method_instancehasher_update(uint64_t ptr, RustBuffer data, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_CLONE_SUMHASHER
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_CLONE_SUMHASHER
+uint64_t uniffi_iscc_uniffi_fn_clone_sumhasher(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_FREE_SUMHASHER
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_FREE_SUMHASHER
+void uniffi_iscc_uniffi_fn_free_sumhasher(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_CONSTRUCTOR_SUMHASHER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_CONSTRUCTOR_SUMHASHER_NEW
+uint64_t uniffi_iscc_uniffi_fn_constructor_sumhasher_new(RustCallStatus *_Nonnull out_status
+
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_METHOD_SUMHASHER_FINALIZE
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_METHOD_SUMHASHER_FINALIZE
+RustBuffer uniffi_iscc_uniffi_fn_method_sumhasher_finalize(uint64_t ptr, uint32_t bits, int8_t wide, int8_t add_units, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_METHOD_SUMHASHER_UPDATE
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_METHOD_SUMHASHER_UPDATE
+void uniffi_iscc_uniffi_fn_method_sumhasher_update(uint64_t ptr, RustBuffer data, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_FUNC_ALG_CDC_CHUNKS
 #define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_FN_FUNC_ALG_CDC_CHUNKS
 RustBuffer uniffi_iscc_uniffi_fn_func_alg_cdc_chunks(RustBuffer data, int8_t utf32, uint32_t avg_chunk_size, RustCallStatus *_Nonnull out_status
@@ -913,6 +939,18 @@ uint16_t uniffi_iscc_uniffi_checksum_method_instancehasher_finalize(void
 #define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_METHOD_INSTANCEHASHER_UPDATE
 uint16_t uniffi_iscc_uniffi_checksum_method_instancehasher_update(void
 
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_METHOD_SUMHASHER_FINALIZE
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_METHOD_SUMHASHER_FINALIZE
+uint16_t uniffi_iscc_uniffi_checksum_method_sumhasher_finalize(void
+
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_METHOD_SUMHASHER_UPDATE
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_METHOD_SUMHASHER_UPDATE
+uint16_t uniffi_iscc_uniffi_checksum_method_sumhasher_update(void
+
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_CONSTRUCTOR_DATAHASHER_NEW
@@ -925,6 +963,12 @@ uint16_t uniffi_iscc_uniffi_checksum_constructor_datahasher_new(void
 #define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_CONSTRUCTOR_INSTANCEHASHER_NEW
 uint16_t uniffi_iscc_uniffi_checksum_constructor_instancehasher_new(void
 
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_CONSTRUCTOR_SUMHASHER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ISCC_UNIFFI_CHECKSUM_CONSTRUCTOR_SUMHASHER_NEW
+uint16_t uniffi_iscc_uniffi_checksum_constructor_sumhasher_new(void
+
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_ISCC_UNIFFI_UNIFFI_CONTRACT_VERSION
@@ -933,3 +977,4 @@ uint32_t ffi_iscc_uniffi_uniffi_contract_version(void
 
 );
 #endif
+
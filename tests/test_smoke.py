@@ -266,3 +266,116 @@ def test_gen_sum_code_v0_units_attribute_access(tmp_path):
     assert isinstance(result.units, list)
     assert len(result.units) == 2
     assert all(u.startswith("ISCC:") for u in result.units)
+
+
+def test_structured_errors_subclass_value_error():
+    """Verify core errors map to IsccError subclasses that remain ValueErrors."""
+    with pytest.raises(iscc_lib.InvalidJsonError):
+        gen_meta_code_v0("Title", meta="{not json")
+    with pytest.raises(iscc_lib.InvalidBitsError):
+        iscc_lib.gen_image_code_v0(bytes(1024), bits=512)
+    with pytest.raises(iscc_lib.IsccError):
+        iscc_lib.iscc_decode("ISCC:!!!")
+    with pytest.raises(ValueError):
+        iscc_lib.iscc_decode("ISCC:!!!")
+    assert issubclass(iscc_lib.TruncatedError, iscc_lib.IsccError)
+
+
+def test_gen_sum_code_v0_missing_file_raises_io_error(tmp_path):
+    """Verify unreadable paths raise IsccIoError."""
+    with pytest.raises(iscc_lib.IsccIoError):
+        gen_sum_code_v0(tmp_path / "missing.bin")
+
+
+def test_gen_sum_code_v0_progress(tmp_path):
+    """Verify progress reports cumulative bytes up to the file size."""
+    data = bytes(range(256)) * 2000
+    file = tmp_path / "progress.bin"
+    file.write_bytes(data)
+    calls = []
+    result = gen_sum_code_v0(file, progress=lambda done, total: calls.append((done, total)))
+    assert result.iscc == gen_sum_code_v0(file).iscc
+    assert calls
+    assert calls[-1] == (len(data), len(data))
+    assert all(total == len(data) for _, total in calls)
+
+
+def test_gen_sum_code_v0_cancelled(tmp_path):
+    """Verify a cancelled token raises CancelledError without touching the token."""
+    file = tmp_path / "cancel.bin"
+    file.write_bytes(b"x" * 1000)
+    token = iscc_lib.CancelToken()
+    token.cancel()
+    assert token.is_cancelled
+    with pytest.raises(iscc_lib.CancelledError):
+        gen_sum_code_v0(file, cancel=token)
+
+
+def test_gen_sum_code_v0_progress_exception_propagates(tmp_path):
+    """Verify an exception raised by the progress callback stops hashing."""
+    file = tmp_path / "abort.bin"
+    file.write_bytes(b"x" * 1000)
+    token = iscc_lib.CancelToken()
+
+    def abort(done, total):
+        raise KeyboardInterrupt
+
+    with pytest.raises(KeyboardInterrupt):
+        gen_sum_code_v0(file, progress=abort, cancel=token)
+    assert not token.is_cancelled
+
+
+def test_gen_text_code_v0_granular_features():
+    """Verify granular mode adds chunk features that map back to the input."""
+    text = "Hello World, this is a granular text. " * 50
+    plain = iscc_lib.gen_text_code_v0(text)
+    result = iscc_lib.gen_text_code_v0(text, granular=True)
+    assert result.iscc == plain.iscc
+    assert "features" not in plain
+    assert result.features
+    first = result.features[0]
+    assert set(first) == {"offset", "size", "simprint", "source_offset", "source_size"}
+    assert first["offset"] == 0
+    assert sum(f["size"] for f in result.features) == result.characters
+    assert first["source_offset"] + first["source_size"] <= len(text)
+
+
+def test_gen_image_code_from_pixels():
+    """Verify raw buffers of any size and mode normalize to the same code."""
+    gray = bytes((x * 7 + y * 3) % 256 for y in range(48) for x in range(64))
+    rgb = bytes(v for v in gray for _ in range(3))
+    expected = iscc_lib.gen_image_code_from_pixels(64, 48, "L", gray)
+    assert expected.iscc.startswith("ISCC:")
+    assert iscc_lib.gen_image_code_from_pixels(64, 48, "RGB", bytearray(rgb)) == expected
+    assert iscc_lib.gen_image_code_from_pixels(64, 48, "rgb", memoryview(rgb)) == expected
+    with pytest.raises(iscc_lib.InvalidInputError):
+        iscc_lib.gen_image_code_from_pixels(64, 48, "CMYK", rgb)
+    with pytest.raises(iscc_lib.InvalidInputError):
+        iscc_lib.gen_image_code_from_pixels(64, 48, "RGBA", rgb)
+
+
+def test_gen_audio_code_from_chromaprint():
+    """Verify fpcalc output in any form yields the same Audio-Code."""
+    expected = iscc_lib.gen_audio_code_v0([1, 0]).iscc
+    assert iscc_lib.decode_chromaprint("AAAAAkEA") == [1, 0]
+    assert iscc_lib.gen_audio_code_from_chromaprint("AAAAAkEA").iscc == expected
+    fpcalc = json.dumps({"duration": 1.0, "fingerprint": [1, 0]})
+    assert iscc_lib.gen_audio_code_from_chromaprint(fpcalc).iscc == expected
+    with pytest.raises(iscc_lib.InvalidInputError):
+        iscc_lib.decode_chromaprint("AQAA!")
+
+
+def test_gen_video_code_from_signature_file(tmp_path):
+    """Verify an FFmpeg XML signature yields the same code as its frame vectors."""
+    frames = [[(i * 7 + f) % 3 for i in range(380)] for f in range(3)]
+    body = "".join(
+        f"<VideoFrame><MediaTimeOfFrame>{f}</MediaTimeOfFrame>"
+        f"<FrameSignature>{' '.join(map(str, sig))}</FrameSignature></VideoFrame>"
+        for f, sig in enumerate(frames)
+    )
+    file = tmp_path / "sig.xml"
+    file.write_text(f"<Mpeg7><MediaTimeUnit>25</MediaTimeUnit>{body}</Mpeg7>")
+    result = iscc_lib.gen_video_code_from_signature_file(file)
+    assert result.iscc == iscc_lib.gen_video_code_v0(frames).iscc
+    with pytest.raises(iscc_lib.IsccIoError):
+        iscc_lib.gen_video_code_from_signature_file(tmp_path / "missing.bin")
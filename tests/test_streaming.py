@@ -1,4 +1,4 @@
-"""Tests for soft_hash_video_v0, DataHasher, and InstanceHasher Python bindings."""
+"""Tests for soft_hash_video_v0, DataHasher, InstanceHasher, and SumHasher Python bindings."""
 
 import io
 import json
@@ -11,8 +11,11 @@ from iscc_lib import (
     DataHasher,
     InstanceCodeResult,
     InstanceHasher,
+    SumCodeResult,
+    SumHasher,
     gen_data_code_v0,
     gen_instance_code_v0,
+    gen_sum_code_v0,
     soft_hash_video_v0,
 )
 
@@ -327,6 +330,35 @@ def test_instance_hasher_conformance(tc):
     assert result2["filesize"] == outputs["filesize"]
 
 
+# ── SumHasher ───────────────────────────────────────────────────────────────
+
+
+def test_sum_hasher_matches_gen_sum_code_v0(tmp_path):
+    """Verify streamed SumHasher matches gen_sum_code_v0 on the same file."""
+    data = bytes(range(256)) * 400
+    file = tmp_path / "sum.bin"
+    file.write_bytes(data)
+    sh = SumHasher()
+    for i in range(0, len(data), 1000):
+        sh.update(data[i : i + 1000])
+    result = sh.finalize(bits=128, wide=True, add_units=True)
+    expected = gen_sum_code_v0(file, bits=128, wide=True, add_units=True)
+    assert isinstance(result, SumCodeResult)
+    assert result == expected
+
+
+def test_sum_hasher_binaryio_and_finalize_once():
+    """Verify SumHasher(data=BytesIO(...)) works and finalizes only once."""
+    data = b"sum hasher stream data"
+    sh = SumHasher(data=io.BytesIO(data))
+    result = sh.finalize()
+    assert result["filesize"] == len(data)
+    assert result["datahash"] == gen_instance_code_v0(data)["datahash"]
+    assert "units" not in result
+    with pytest.raises(ValueError, match="already finalized"):
+        sh.finalize()
+
+
 # ── Bytes-like input tests ─────────────────────────────────────────────────
 
 